@@ -0,0 +1,60 @@
+use codespan::Files;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+
+use ddl::lexer::{Lexer, SURFACE_KEYWORDS};
+use ddl::{binary, core, surface};
+
+const EDID_DDL: &str = include_str!("fixtures/edid.ddl");
+const EDID_FIXTURE: &[u8] = include_bytes!(
+    "../../../experiments/rust-prototype-v1/examples/formats/edid/tests/fixtures/mbp_2017_built_in_retina.bin"
+);
+
+fn check_module(files: &Files, file_id: codespan::FileId) -> core::Module {
+    let mut diagnostics = Vec::new();
+    let lexer = Lexer::new(files, file_id, &SURFACE_KEYWORDS);
+    let surface_module = surface::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+    let core_module =
+        surface::elaborate::elaborate_module(&surface_module, &mut |d| diagnostics.push(d));
+    core::validate::validate_module(&core_module, &mut |d| diagnostics.push(d));
+    assert!(diagnostics.is_empty(), "unexpected diagnostics");
+    core_module
+}
+
+fn check(c: &mut Criterion) {
+    let mut files = Files::new();
+    let file_id = files.add("edid.ddl", EDID_DDL);
+
+    c.bench_function("check edid", |b| b.iter(|| check_module(&files, file_id)));
+}
+
+fn read(c: &mut Criterion) {
+    let mut files = Files::new();
+    let file_id = files.add("edid.ddl", EDID_DDL);
+    let module = check_module(&files, file_id);
+    let scope = ddl_rt::ReadScope::new(EDID_FIXTURE);
+
+    c.bench_function("read edid", |b| {
+        b.iter(|| binary::read::read_module_item(&module, "Edid", &mut scope.reader()).unwrap())
+    });
+}
+
+fn eval(c: &mut Criterion) {
+    let span = codespan::Span::initial();
+    let term = (0..1000).fold(core::Term::U8Type(span), |term, _| {
+        core::Term::BoolElim(
+            span,
+            Arc::new(core::Term::BoolConst(span, true)),
+            Arc::new(core::Term::Ann(
+                Arc::new(term),
+                Arc::new(core::Term::Universe(span, core::Universe::Format)),
+            )),
+            Arc::new(core::Term::U16BeType(span)),
+        )
+    });
+
+    c.bench_function("eval nested", |b| b.iter(|| core::semantics::eval(&term)));
+}
+
+criterion_group!(benches, check, read, eval);
+criterion_main!(benches);
@@ -0,0 +1,3 @@
+//! Benchmarks for the binary data description language.
+//!
+//! Run them from this directory with `cargo bench`.
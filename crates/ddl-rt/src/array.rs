@@ -0,0 +1,34 @@
+//! Sequences of values.
+
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use crate::{Format, FormatReader, ReadEofError, ReadError, ReadFormat};
+
+/// Marker type for a count, formatted as `L`, followed by that many values
+/// formatted as `T`.
+///
+/// Every value is assumed to occupy at least one byte, so counts that exceed
+/// the number of remaining bytes are rejected before anything is allocated.
+pub struct LengthPrefixed<L, T>(PhantomData<(L, T)>);
+
+impl<L: Format, T: Format> Format for LengthPrefixed<L, T> {
+    type Host = Vec<T::Host>;
+}
+
+impl<'data, L, T> ReadFormat<'data> for LengthPrefixed<L, T>
+where
+    L: ReadFormat<'data>,
+    L::Host: Into<u64>,
+    T: ReadFormat<'data>,
+{
+    fn read(reader: &mut FormatReader<'data>) -> Result<Vec<T::Host>, ReadError> {
+        let len =
+            usize::try_from(reader.read::<L>()?.into()).map_err(|_| ReadError::IntOverflow)?;
+        if len > reader.scope().data().len() {
+            return Err(ReadError::Eof(ReadEofError {}));
+        }
+
+        (0..len).map(|_| reader.read::<T>()).collect()
+    }
+}
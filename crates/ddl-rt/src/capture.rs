@@ -0,0 +1,36 @@
+//! Regions of binary data that are kept alongside their decoded values.
+
+use std::marker::PhantomData;
+
+use crate::{Format, FormatReader, ReadError, ReadFormat};
+
+/// A decoded value, along with the exact bytes that it was read from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Captured<T> {
+    /// The decoded value.
+    pub value: T,
+    /// The bytes that were consumed while reading the value.
+    pub bytes: Vec<u8>,
+}
+
+/// Marker type for regions that are read as `T`, while also retaining the raw
+/// bytes that were consumed.
+///
+/// This is useful for recomputing checksums over a region after its contents
+/// have been edited.
+pub struct Capture<T>(PhantomData<T>);
+
+impl<T: Format> Format for Capture<T> {
+    type Host = Captured<T::Host>;
+}
+
+impl<'data, T: ReadFormat<'data>> ReadFormat<'data> for Capture<T> {
+    fn read(reader: &mut FormatReader<'data>) -> Result<Captured<T::Host>, ReadError> {
+        let data = reader.scope().data();
+        let start = reader.offset();
+        let value = reader.read::<T>()?;
+        let bytes = data[..reader.offset() - start].to_vec();
+
+        Ok(Captured { value, bytes })
+    }
+}
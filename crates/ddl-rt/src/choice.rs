@@ -0,0 +1,50 @@
+//! Formats that are chosen by attempting to read each alternative in turn.
+
+use std::marker::PhantomData;
+
+use crate::{Either, Format, FormatReader, ReadError, ReadFormat};
+
+/// Marker type for unions without a discriminant, read as the first of `A`
+/// or `B` that can be read without error.
+///
+/// The reader is restored to its original position between attempts. More
+/// than two variants can be expressed by nesting, for example
+/// `FirstMatch<A, FirstMatch<B, C>>`.
+pub struct FirstMatch<A, B>(PhantomData<(A, B)>);
+
+impl<A: Format, B: Format> Format for FirstMatch<A, B> {
+    type Host = Either<A::Host, B::Host>;
+}
+
+impl<'data, A, B> ReadFormat<'data> for FirstMatch<A, B>
+where
+    A: ReadFormat<'data>,
+    B: ReadFormat<'data>,
+{
+    fn read(reader: &mut FormatReader<'data>) -> Result<Self::Host, ReadError> {
+        let mut attempt = reader.clone();
+        let left_error = match attempt.read::<A>() {
+            Ok(value) => {
+                *reader = attempt;
+                return Ok(Either::Left(value));
+            }
+            Err(error) => error,
+        };
+
+        let mut attempt = reader.clone();
+        let right_error = match attempt.read::<B>() {
+            Ok(value) => {
+                *reader = attempt;
+                return Ok(Either::Right(value));
+            }
+            Err(error) => error,
+        };
+
+        let mut errors = vec![left_error];
+        match right_error {
+            ReadError::NoVariantMatched(right_errors) => errors.extend(right_errors),
+            right_error => errors.push(right_error),
+        }
+        Err(ReadError::NoVariantMatched(errors))
+    }
+}
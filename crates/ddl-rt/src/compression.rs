@@ -0,0 +1,60 @@
+//! Compressed regions of binary data.
+
+use flate2::bufread::{DeflateDecoder, ZlibDecoder};
+use std::io::Read;
+use std::marker::PhantomData;
+
+use crate::{Format, FormatReader, ReadError, ReadFormat, ReadScope};
+
+/// Marker type for zlib-compressed regions, read as `T` once decompressed.
+///
+/// The compressed stream is self-delimiting, so reading continues
+/// immediately after the end of the stream.
+pub struct Zlib<T>(PhantomData<T>);
+
+impl<T: Format> Format for Zlib<T> {
+    type Host = T::Host;
+}
+
+impl<'data, T> ReadFormat<'data> for Zlib<T>
+where
+    T: for<'a> ReadFormat<'a>,
+{
+    fn read(reader: &mut FormatReader<'data>) -> Result<T::Host, ReadError> {
+        let mut decoder = ZlibDecoder::new(reader.scope().data());
+        let mut buffer = Vec::new();
+        decoder
+            .read_to_end(&mut buffer)
+            .map_err(ReadError::DecompressionFailed)?;
+        reader.skip(decoder.total_in() as usize)?;
+
+        ReadScope::new(&buffer).read::<T>()
+    }
+}
+
+/// Marker type for raw deflate-compressed regions, read as `T` once
+/// decompressed.
+///
+/// The compressed stream is self-delimiting, so reading continues
+/// immediately after the end of the stream.
+pub struct Deflate<T>(PhantomData<T>);
+
+impl<T: Format> Format for Deflate<T> {
+    type Host = T::Host;
+}
+
+impl<'data, T> ReadFormat<'data> for Deflate<T>
+where
+    T: for<'a> ReadFormat<'a>,
+{
+    fn read(reader: &mut FormatReader<'data>) -> Result<T::Host, ReadError> {
+        let mut decoder = DeflateDecoder::new(reader.scope().data());
+        let mut buffer = Vec::new();
+        decoder
+            .read_to_end(&mut buffer)
+            .map_err(ReadError::DecompressionFailed)?;
+        reader.skip(decoder.total_in() as usize)?;
+
+        ReadScope::new(&buffer).read::<T>()
+    }
+}
@@ -0,0 +1,37 @@
+//! Null-terminated byte strings, as used by C.
+
+use std::ffi::CString;
+
+use crate::{Format, FormatReader, FormatWriter, ReadEofError, ReadError, ReadFormat, WriteFormat};
+
+/// Marker type for a string of bytes that ends with a null byte.
+///
+/// The terminator is consumed when reading, but is not part of the host
+/// value. It is an error for the data to end before the terminator is found.
+#[derive(Copy, Clone)]
+pub enum CStr {}
+
+impl Format for CStr {
+    type Host = CString;
+}
+
+impl<'data> ReadFormat<'data> for CStr {
+    fn read(reader: &mut FormatReader<'data>) -> Result<CString, ReadError> {
+        let data = reader.scope().data();
+        let len = match data.iter().position(|&byte| byte == 0) {
+            Some(len) => len,
+            None => return Err(ReadError::Eof(ReadEofError {})),
+        };
+        reader.skip(len + 1)?;
+
+        Ok(CString::new(&data[..len]).expect("null byte before the terminator"))
+    }
+}
+
+impl WriteFormat for CStr {
+    fn write(writer: &mut FormatWriter, value: CString) {
+        for &byte in value.as_bytes_with_nul() {
+            writer.write_u8(byte);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Domain names, as found in DNS messages.
+
+use crate::{Format, FormatReader, ReadError, ReadFormat, U8};
+
+/// Marker type for domain names with message compression.
+///
+/// A name is a sequence of length-prefixed labels, ending in either an empty
+/// label or a two byte pointer, flagged by its top two bits, to the rest of
+/// the name elsewhere in the message. Pointers are offsets from the start of
+/// the scope that the reader was created from, which should be the start of
+/// the message.
+///
+/// Names are read as the labels joined by dots, without a trailing dot.
+#[derive(Copy, Clone)]
+pub enum DnsName {}
+
+impl Format for DnsName {
+    type Host = String;
+}
+
+impl<'data> ReadFormat<'data> for DnsName {
+    fn read(reader: &mut FormatReader<'data>) -> Result<String, ReadError> {
+        let mut name = String::new();
+        let mut visited = Vec::new();
+        // Pointers are followed with a separate reader, so that `reader` is
+        // left just after the first pointer.
+        let mut pointer_reader = None;
+
+        loop {
+            let current = pointer_reader.as_mut().unwrap_or(&mut *reader);
+            let offset = current.offset();
+            let len = current.read::<U8>()?;
+
+            match len & 0xC0 {
+                0x00 if len == 0 => return Ok(name),
+                0x00 => {
+                    let len = usize::from(len);
+                    current.check_available(len)?;
+                    if !name.is_empty() {
+                        name.push('.');
+                    }
+                    name.push_str(&String::from_utf8_lossy(&current.scope().data()[..len]));
+                    current.skip(len)?;
+                }
+                0xC0 => {
+                    let low = current.read::<U8>()?;
+                    let target = (usize::from(len & 0x3F) << 8) | usize::from(low);
+                    if visited.contains(&target) {
+                        return Err(ReadError::CompressionLoop { offset: target });
+                    }
+                    visited.push(target);
+                    pointer_reader = Some(current.reader_at(target));
+                }
+                _ => return Err(ReadError::ReservedLabelType { offset }),
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Byte orders that are determined by the data itself.
+
+use crate::{FormatReader, ReadError, U32Be};
+
+/// The byte order of a region of data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl<'data> FormatReader<'data> {
+    /// Read a 32-bit magic number, returning the byte order in which it
+    /// matches `magic`.
+    ///
+    /// Formats like TIFF are written in the byte order of the machine that
+    /// produced them, and start with a known value that can be used to find
+    /// out which one was used. If `magic` reads the same way in both byte
+    /// orders then `Endianness::Big` is returned.
+    pub fn detect_endianness(&mut self, magic: u32) -> Result<Endianness, ReadError> {
+        let found = self.read::<U32Be>()?;
+
+        if found == magic {
+            Ok(Endianness::Big)
+        } else if found.swap_bytes() == magic {
+            Ok(Endianness::Little)
+        } else {
+            Err(ReadError::MagicMismatch {
+                expected: magic,
+                found,
+            })
+        }
+    }
+}
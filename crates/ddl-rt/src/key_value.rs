@@ -0,0 +1,77 @@
+//! Repeated key/value pairs, as found in metadata blocks and text chunks.
+
+use crate::{FormatReader, ReadError, ReadFormat};
+
+/// How keys that appear more than once should be handled when reading
+/// key/value pairs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DuplicateKeys {
+    /// Keep every pair. Lookups return the first pair with a matching key.
+    Allow,
+    /// Fail with `ReadError::DuplicateKey` if a key appears more than once.
+    Reject,
+}
+
+/// Key/value pairs, in the order that they appeared in the data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyValues<K, V> {
+    pairs: Vec<(K, V)>,
+}
+
+impl<K, V> KeyValues<K, V> {
+    /// The number of pairs.
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns `true` if there are no pairs.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Iterate over the pairs in the order that they were read.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.pairs.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Returns the value of the first pair with the given key.
+    pub fn get(&self, key: &K) -> Option<&V>
+    where
+        K: PartialEq,
+    {
+        self.iter().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+
+    /// The underlying pairs.
+    pub fn into_pairs(self) -> Vec<(K, V)> {
+        self.pairs
+    }
+}
+
+impl<'data> FormatReader<'data> {
+    /// Read `count` consecutive pairs of a key, formatted as `K`, followed by
+    /// a value, formatted as `V`.
+    pub fn read_key_values<K, V>(
+        &mut self,
+        count: usize,
+        duplicates: DuplicateKeys,
+    ) -> Result<KeyValues<K::Host, V::Host>, ReadError>
+    where
+        K: ReadFormat<'data>,
+        K::Host: PartialEq,
+        V: ReadFormat<'data>,
+    {
+        let mut pairs = Vec::<(K::Host, V::Host)>::new();
+
+        for index in 0..count {
+            let key = self.read::<K>()?;
+            if duplicates == DuplicateKeys::Reject && pairs.iter().any(|(k, _)| *k == key) {
+                return Err(ReadError::DuplicateKey { index });
+            }
+            let value = self.read::<V>()?;
+            pairs.push((key, value));
+        }
+
+        Ok(KeyValues { pairs })
+    }
+}
@@ -0,0 +1,111 @@
+//! Variable-length integer formats.
+
+use crate::{Format, FormatReader, FormatWriter, ReadError, ReadFormat, WriteFormat, U8};
+
+/// Marker type for unsigned LEB128 integers.
+///
+/// Each byte holds seven bits of the value, least significant group first,
+/// with the high bit set on every byte but the last.
+#[derive(Copy, Clone)]
+pub enum ULeb128 {}
+
+impl Format for ULeb128 {
+    type Host = u64;
+}
+
+impl<'data> ReadFormat<'data> for ULeb128 {
+    fn read(reader: &mut FormatReader<'data>) -> Result<u64, ReadError> {
+        let mut value = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = reader.read::<U8>()?;
+            let group = u64::from(byte & 0x7F);
+            if shift == 63 && group > 1 {
+                return Err(ReadError::IntOverflow);
+            }
+            value |= group << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            if shift > 63 {
+                return Err(ReadError::IntOverflow);
+            }
+        }
+    }
+}
+
+impl WriteFormat for ULeb128 {
+    fn write(writer: &mut FormatWriter, mut value: u64) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+
+            if value == 0 {
+                writer.write::<U8>(byte);
+                return;
+            }
+            writer.write::<U8>(byte | 0x80);
+        }
+    }
+}
+
+/// Marker type for signed LEB128 integers, as used in DWARF.
+///
+/// This is encoded like [`ULeb128`], but the value is sign-extended from the
+/// second-highest bit of the final byte.
+///
+/// [`ULeb128`]: enum.ULeb128.html
+#[derive(Copy, Clone)]
+pub enum SLeb128 {}
+
+impl Format for SLeb128 {
+    type Host = i64;
+}
+
+impl<'data> ReadFormat<'data> for SLeb128 {
+    fn read(reader: &mut FormatReader<'data>) -> Result<i64, ReadError> {
+        let mut value = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = reader.read::<U8>()?;
+            let group = i64::from(byte & 0x7F);
+            // Only the sign bit remains in the last group, so the rest of
+            // its bits must agree with it.
+            if shift == 63 && group != 0x00 && group != 0x7F {
+                return Err(ReadError::IntOverflow);
+            }
+            value |= group << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1 << shift;
+                }
+                return Ok(value);
+            }
+            if shift > 63 {
+                return Err(ReadError::IntOverflow);
+            }
+        }
+    }
+}
+
+impl WriteFormat for SLeb128 {
+    fn write(writer: &mut FormatWriter, mut value: i64) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+
+            let sign_bit = byte & 0x40 != 0;
+            if (value == 0 && !sign_bit) || (value == -1 && sign_bit) {
+                writer.write::<U8>(byte);
+                return;
+            }
+            writer.write::<U8>(byte | 0x80);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Lengths that count the bytes of their own encoding.
+
+use std::convert::TryFrom;
+
+use crate::{FormatReader, ReadError, ReadFormat, ReadFormatUnchecked};
+
+impl<'data> FormatReader<'data> {
+    /// Read a length, formatted as `L`, that includes the size of the length
+    /// itself, returning the number of bytes that follow it.
+    ///
+    /// Chunked formats often store the size of a chunk header as the first
+    /// field of the header, making it easy to forget to subtract the size of
+    /// that field. The returned length can be passed on to methods like
+    /// `read_bounded` or `skip` to read the rest of the region.
+    pub fn read_inclusive_len<L>(&mut self) -> Result<usize, ReadError>
+    where
+        L: ReadFormat<'data> + ReadFormatUnchecked<'data>,
+        L::Host: Into<u64>,
+    {
+        let len = usize::try_from(self.read::<L>()?.into()).map_err(|_| ReadError::IntOverflow)?;
+
+        len.checked_sub(L::SIZE)
+            .ok_or(ReadError::LengthTooSmall { len, size: L::SIZE })
+    }
+}
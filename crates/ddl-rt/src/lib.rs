@@ -2,11 +2,46 @@
 
 #![warn(rust_2018_idioms)]
 
+mod array;
+mod capture;
+mod choice;
+#[cfg(feature = "flate2")]
+mod compression;
+mod cstr;
+mod dns;
+mod endian;
+mod key_value;
+mod leb128;
+mod length;
+mod net;
+mod offset;
+mod packed;
 mod read;
+mod rle;
+mod scramble;
+mod sized;
+mod time;
 mod write;
 
+pub use array::LengthPrefixed;
+pub use capture::{Capture, Captured};
+pub use choice::FirstMatch;
+#[cfg(feature = "flate2")]
+pub use compression::{Deflate, Zlib};
+pub use cstr::CStr;
+pub use dns::DnsName;
 pub use either::Either;
-pub use read::{FormatReader, ReadEofError, ReadError, ReadFormat, ReadFormatUnchecked, ReadScope};
+pub use endian::Endianness;
+pub use key_value::{DuplicateKeys, KeyValues};
+pub use leb128::{SLeb128, ULeb128};
+pub use net::{Ipv4, Ipv6, Mac, MacAddr};
+pub use offset::OffsetTo;
+pub use read::{
+    FieldLocation, FormatReader, ReadEofError, ReadError, ReadFormat, ReadFormatUnchecked,
+    ReadScope,
+};
+pub use sized::SizedBy;
+pub use time::{DateTime, DosDateTime, UnixTime32Be};
 pub use write::{FormatWriter, WriteFormat};
 
 /// Binary formats with a corresponding host representation.
@@ -215,6 +250,50 @@ impl WriteFormat for U64Be {
     }
 }
 
+/// Marker type for unsigned 128-bit integers (little endian).
+#[derive(Copy, Clone)]
+pub enum U128Le {}
+
+impl<'data> ReadFormatUnchecked<'data> for U128Le {
+    const SIZE: usize = std::mem::size_of::<u128>();
+
+    #[inline]
+    unsafe fn read_unchecked(reader: &mut FormatReader<'data>) -> u128 {
+        let low = u128::from(reader.read_unchecked::<U64Le>());
+        let high = u128::from(reader.read_unchecked::<U64Le>());
+        low | (high << 64)
+    }
+}
+
+impl WriteFormat for U128Le {
+    fn write(writer: &mut FormatWriter, value: u128) {
+        writer.write::<U64Le>(value as u64);
+        writer.write::<U64Le>((value >> 64) as u64);
+    }
+}
+
+/// Marker type for unsigned 128-bit integers (big endian).
+#[derive(Copy, Clone)]
+pub enum U128Be {}
+
+impl<'data> ReadFormatUnchecked<'data> for U128Be {
+    const SIZE: usize = std::mem::size_of::<u128>();
+
+    #[inline]
+    unsafe fn read_unchecked(reader: &mut FormatReader<'data>) -> u128 {
+        let high = u128::from(reader.read_unchecked::<U64Be>());
+        let low = u128::from(reader.read_unchecked::<U64Be>());
+        (high << 64) | low
+    }
+}
+
+impl WriteFormat for U128Be {
+    fn write(writer: &mut FormatWriter, value: u128) {
+        writer.write::<U64Be>((value >> 64) as u64);
+        writer.write::<U64Be>(value as u64);
+    }
+}
+
 macro_rules! impl_uint_marker {
     ($UInt:ident, $uint:ident) => {
         // TODO: Generate ReadFormatUnchecked implementations
@@ -240,6 +319,8 @@ impl_uint_marker!(U32Le, u32);
 impl_uint_marker!(U32Be, u32);
 impl_uint_marker!(U64Le, u64);
 impl_uint_marker!(U64Be, u64);
+impl_uint_marker!(U128Le, u128);
+impl_uint_marker!(U128Be, u128);
 
 /// Marker type for signed, two's complement 8-bit integers.
 #[derive(Copy, Clone)]
@@ -269,6 +350,14 @@ pub enum I64Le {}
 #[derive(Copy, Clone)]
 pub enum I64Be {}
 
+/// Marker type for signed, two's complement 128-bit integers (little endian).
+#[derive(Copy, Clone)]
+pub enum I128Le {}
+
+/// Marker type for signed, two's complement 128-bit integers (big endian).
+#[derive(Copy, Clone)]
+pub enum I128Be {}
+
 macro_rules! impl_int_marker {
     ($Int:ident, $UInt:ident, $int:ident) => {
         impl Format for $Int {
@@ -307,6 +396,22 @@ impl_int_marker!(I32Le, U32Le, i32);
 impl_int_marker!(I32Be, U32Be, i32);
 impl_int_marker!(I64Le, U64Le, i64);
 impl_int_marker!(I64Be, U64Be, i64);
+impl_int_marker!(I128Le, U128Le, i128);
+impl_int_marker!(I128Be, U128Be, i128);
+
+/// Marker type for IEEE-754 half-precision floating point numbers (little endian).
+///
+/// These are widened to `f32` when read, and rounded to the nearest
+/// half-precision number when written.
+#[derive(Copy, Clone)]
+pub enum F16Le {}
+
+/// Marker type for IEEE-754 half-precision floating point numbers (big endian).
+///
+/// These are widened to `f32` when read, and rounded to the nearest
+/// half-precision number when written.
+#[derive(Copy, Clone)]
+pub enum F16Be {}
 
 /// Marker type for IEEE-754 single-precision floating point numbers (little endian).
 #[derive(Copy, Clone)]
@@ -357,11 +462,87 @@ macro_rules! impl_float_marker {
     };
 }
 
+macro_rules! impl_half_float_marker {
+    ($Float:ident, $UInt:ident) => {
+        impl Format for $Float {
+            type Host = f32;
+        }
+
+        impl<'data> ReadFormatUnchecked<'data> for $Float {
+            const SIZE: usize = std::mem::size_of::<u16>();
+
+            #[inline]
+            unsafe fn read_unchecked(reader: &mut FormatReader<'data>) -> f32 {
+                half::f16::from_bits(reader.read_unchecked::<$UInt>()).to_f32()
+            }
+        }
+
+        impl<'data> ReadFormat<'data> for $Float {
+            #[inline]
+            fn read(reader: &mut FormatReader<'data>) -> Result<f32, ReadError> {
+                reader
+                    .read::<$UInt>()
+                    .map(|value| half::f16::from_bits(value).to_f32())
+            }
+        }
+
+        impl WriteFormat for $Float {
+            #[inline]
+            fn write(writer: &mut FormatWriter, value: f32) {
+                writer.write::<$UInt>(half::f16::from_f32(value).to_bits());
+            }
+        }
+    };
+}
+
+impl_half_float_marker!(F16Le, U16Le);
+impl_half_float_marker!(F16Be, U16Be);
 impl_float_marker!(F32Le, U32Le, f32);
 impl_float_marker!(F32Be, U32Be, f32);
 impl_float_marker!(F64Le, U64Le, f64);
 impl_float_marker!(F64Be, U64Be, f64);
 
+/// Marker type for four-character codes, as used for tagging chunks in formats
+/// like RIFF and QuickTime.
+///
+/// The host representation is the raw bytes of the tag, allowing it to be
+/// matched against byte string literals, for example `b"RIFF"`.
+#[derive(Copy, Clone)]
+pub enum FourCc {}
+
+impl Format for FourCc {
+    type Host = [u8; 4];
+}
+
+impl<'data> ReadFormatUnchecked<'data> for FourCc {
+    const SIZE: usize = 4;
+
+    #[inline]
+    unsafe fn read_unchecked(reader: &mut FormatReader<'data>) -> [u8; 4] {
+        let b0 = reader.read_unchecked::<U8>();
+        let b1 = reader.read_unchecked::<U8>();
+        let b2 = reader.read_unchecked::<U8>();
+        let b3 = reader.read_unchecked::<U8>();
+        [b0, b1, b2, b3]
+    }
+}
+
+impl<'data> ReadFormat<'data> for FourCc {
+    #[inline]
+    fn read(reader: &mut FormatReader<'data>) -> Result<[u8; 4], ReadError> {
+        reader.check_available(<FourCc as ReadFormatUnchecked<'data>>::SIZE)?;
+        Ok(unsafe { reader.read_unchecked::<FourCc>() })
+    }
+}
+
+impl WriteFormat for FourCc {
+    fn write(writer: &mut FormatWriter, value: [u8; 4]) {
+        for byte in &value {
+            writer.write_u8(*byte);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
@@ -419,6 +600,18 @@ mod tests {
             let mut writer = FormatWriter::new(vec![]);
             prop_assert_eq!(round_trip::<U64Be>(&mut writer, value), value);
         }
+
+        #[test]
+        fn u128le_round_trip(value: u128) {
+            let mut writer = FormatWriter::new(vec![]);
+            prop_assert_eq!(round_trip::<U128Le>(&mut writer, value), value);
+        }
+
+        #[test]
+        fn u128be_round_trip(value: u128) {
+            let mut writer = FormatWriter::new(vec![]);
+            prop_assert_eq!(round_trip::<U128Be>(&mut writer, value), value);
+        }
         #[test]
         fn i8_round_trip(value: i8) {
             let mut writer = FormatWriter::new(vec![]);
@@ -461,6 +654,52 @@ mod tests {
             prop_assert_eq!(round_trip::<I64Be>(&mut writer, value), value);
         }
 
+        #[test]
+        fn i128le_round_trip(value: i128) {
+            let mut writer = FormatWriter::new(vec![]);
+            prop_assert_eq!(round_trip::<I128Le>(&mut writer, value), value);
+        }
+
+        #[test]
+        fn i128be_round_trip(value: i128) {
+            let mut writer = FormatWriter::new(vec![]);
+            prop_assert_eq!(round_trip::<I128Be>(&mut writer, value), value);
+        }
+
+        #[test]
+        fn uleb128_round_trip(value: u64) {
+            let mut writer = FormatWriter::new(vec![]);
+            prop_assert_eq!(round_trip::<ULeb128>(&mut writer, value), value);
+        }
+
+        #[test]
+        fn sleb128_round_trip(value: i64) {
+            let mut writer = FormatWriter::new(vec![]);
+            prop_assert_eq!(round_trip::<SLeb128>(&mut writer, value), value);
+        }
+
+        #[test]
+        fn f16le_round_trip(bits: u16) {
+            let mut writer = FormatWriter::new(vec![]);
+            let value = half::f16::from_bits(bits).to_f32();
+            if value.is_nan() {
+                prop_assert!(round_trip::<F16Le>(&mut writer, value).is_nan());
+            } else {
+                prop_assert_eq!(round_trip::<F16Le>(&mut writer, value), value);
+            }
+        }
+
+        #[test]
+        fn f16be_round_trip(bits: u16) {
+            let mut writer = FormatWriter::new(vec![]);
+            let value = half::f16::from_bits(bits).to_f32();
+            if value.is_nan() {
+                prop_assert!(round_trip::<F16Be>(&mut writer, value).is_nan());
+            } else {
+                prop_assert_eq!(round_trip::<F16Be>(&mut writer, value), value);
+            }
+        }
+
         #[test]
         fn f32le_round_trip(value in proptest::num::f32::ANY) {
             let mut writer = FormatWriter::new(vec![]);
@@ -500,5 +739,961 @@ mod tests {
                 prop_assert_eq!(round_trip::<F64Be>(&mut writer, value), value);
             }
         }
+
+        #[test]
+        fn unix_time_32be_round_trip(value: u32) {
+            let mut writer = FormatWriter::new(vec![]);
+            let date_time = DateTime::from_unix_time(value);
+            prop_assert_eq!(round_trip::<UnixTime32Be>(&mut writer, date_time), date_time);
+            prop_assert_eq!(date_time.to_unix_time(), Some(value));
+        }
+
+        #[test]
+        fn ipv4_round_trip(value: u32) {
+            let mut writer = FormatWriter::new(vec![]);
+            let address = std::net::Ipv4Addr::from(value);
+            prop_assert_eq!(round_trip::<Ipv4>(&mut writer, address), address);
+        }
+    }
+
+    #[test]
+    fn four_cc_round_trip() {
+        let mut writer = FormatWriter::new(vec![]);
+        assert_eq!(round_trip::<FourCc>(&mut writer, *b"fmt "), *b"fmt ");
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Chunk {
+        Riff { size: u32 },
+        Fmt { format_tag: u16 },
+        Unknown { tag: [u8; 4], data: Vec<u8> },
+    }
+
+    enum ChunkFormat {}
+
+    impl Format for ChunkFormat {
+        type Host = Chunk;
+    }
+
+    impl<'data> ReadFormat<'data> for ChunkFormat {
+        fn read(reader: &mut FormatReader<'data>) -> Result<Chunk, ReadError> {
+            match &reader.read::<FourCc>()? {
+                b"RIFF" => Ok(Chunk::Riff {
+                    size: reader.read::<U32Le>()?,
+                }),
+                b"fmt " => Ok(Chunk::Fmt {
+                    format_tag: reader.read::<U16Le>()?,
+                }),
+                tag => Ok(Chunk::Unknown {
+                    tag: *tag,
+                    data: reader.scope().data().to_vec(),
+                }),
+            }
+        }
+    }
+
+    #[test]
+    fn four_cc_dispatch() {
+        let scope = ReadScope::new(b"RIFF\x24\x08\x00\x00");
+        assert_eq!(
+            scope.read::<ChunkFormat>().unwrap(),
+            Chunk::Riff { size: 2084 }
+        );
+
+        let scope = ReadScope::new(b"fmt \x01\x00");
+        assert_eq!(
+            scope.read::<ChunkFormat>().unwrap(),
+            Chunk::Fmt { format_tag: 1 }
+        );
+
+        let scope = ReadScope::new(b"LIST\x01\x02\x03");
+        assert_eq!(
+            scope.read::<ChunkFormat>().unwrap(),
+            Chunk::Unknown {
+                tag: *b"LIST",
+                data: vec![1, 2, 3],
+            },
+        );
+    }
+
+    #[test]
+    fn four_cc_eof() {
+        let scope = ReadScope::new(b"RIF");
+
+        match scope.read::<FourCc>() {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn u128_known_values() {
+        let data = (0..16).collect::<Vec<u8>>();
+        let scope = ReadScope::new(&data);
+
+        assert_eq!(
+            scope.read::<U128Be>().unwrap(),
+            0x0001_0203_0405_0607_0809_0a0b_0c0d_0e0f,
+        );
+        assert_eq!(
+            scope.read::<U128Le>().unwrap(),
+            0x0f0e_0d0c_0b0a_0908_0706_0504_0302_0100,
+        );
+    }
+
+    #[test]
+    fn f16_known_values() {
+        assert_eq!(ReadScope::new(&[0x3C, 0x00]).read::<F16Be>().unwrap(), 1.0);
+        assert_eq!(ReadScope::new(&[0x00, 0xC0]).read::<F16Le>().unwrap(), -2.0);
+        assert_eq!(
+            ReadScope::new(&[0x7B, 0xFF]).read::<F16Be>().unwrap(),
+            65504.0
+        );
+
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<F16Be>(0.1);
+        assert_eq!(writer.buffer(), &[0x2E, 0x66]);
+    }
+
+    #[test]
+    fn ipv4_known_value() {
+        let address = ReadScope::new(&[192, 168, 0, 1]).read::<Ipv4>().unwrap();
+        assert_eq!(address.to_string(), "192.168.0.1");
+    }
+
+    #[test]
+    fn ipv6_known_value() {
+        let data = [
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ];
+        let address = ReadScope::new(&data).read::<Ipv6>().unwrap();
+        assert_eq!(address.to_string(), "2001:db8::1");
+
+        let mut writer = FormatWriter::new(vec![]);
+        assert_eq!(round_trip::<Ipv6>(&mut writer, address), address);
+        assert_eq!(writer.buffer(), &data);
+    }
+
+    #[test]
+    fn mac_known_value() {
+        let data = [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+        let address = ReadScope::new(&data).read::<Mac>().unwrap();
+        assert_eq!(address.to_string(), "00:1a:2b:3c:4d:5e");
+
+        match ReadScope::new(&data[..5]).read::<Mac>() {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn first_match_second_variant() {
+        // Too short for `U32Be`, but long enough for `U16Be`
+        let scope = ReadScope::new(&[0x01, 0x02, 0x03]);
+        let mut reader = scope.reader();
+
+        match reader.read::<FirstMatch<U32Be, U16Be>>().unwrap() {
+            Either::Right(value) => assert_eq!(value, 0x0102),
+            Either::Left(value) => panic!("second variant expected, found: {:?}", value),
+        }
+        assert_eq!(reader.offset(), 2);
+    }
+
+    #[test]
+    fn first_match_no_variant() {
+        let scope = ReadScope::new(&[0x01]);
+
+        match scope.read::<FirstMatch<U32Be, FirstMatch<U16Be, FourCc>>>() {
+            Err(ReadError::NoVariantMatched(errors)) => {
+                assert_eq!(errors.len(), 3);
+                assert!(errors.iter().all(|error| match error {
+                    ReadError::Eof(_) => true,
+                    _ => false,
+                }));
+            }
+            Err(err) => panic!("no variant error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn key_values() {
+        let data = [0x01, 0x00, 0x0A, 0x02, 0x00, 0x14, 0x01, 0x00, 0x1E];
+
+        let mut reader = ReadScope::new(&data).reader();
+        let pairs = reader
+            .read_key_values::<U8, U16Be>(3, DuplicateKeys::Allow)
+            .unwrap();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(
+            pairs.iter().collect::<Vec<_>>(),
+            [(&1, &0x0A), (&2, &0x14), (&1, &0x1E)],
+        );
+        assert_eq!(pairs.get(&1), Some(&0x0A));
+        assert_eq!(pairs.get(&3), None);
+        assert_eq!(reader.offset(), 9);
+
+        let mut reader = ReadScope::new(&data).reader();
+        match reader.read_key_values::<U8, U16Be>(3, DuplicateKeys::Reject) {
+            Err(ReadError::DuplicateKey { index: 2 }) => {}
+            Err(err) => panic!("duplicate key error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn read_or_default() {
+        let scope = ReadScope::new(&[0x01, 0x02, 0x03]);
+        let mut reader = scope.reader();
+
+        assert_eq!(reader.read_or::<U16Be>(7).unwrap(), 0x0102);
+        assert_eq!(reader.read_or::<U8>(7).unwrap(), 0x03);
+        assert_eq!(reader.read_or::<U32Be>(7).unwrap(), 7);
+        assert_eq!(reader.offset(), 3);
+
+        let mut reader = scope.offset(2).reader();
+        match reader.read_or::<U16Be>(7) {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(value) => panic!("error expected, found: Ok({})", value),
+        }
+    }
+
+    #[test]
+    fn rle_expands_runs() {
+        let mut reader = ReadScope::new(&[0x03, 0xAA, 0x01, 0x00, 0x02, 0xFF, 0x07]).reader();
+
+        assert_eq!(
+            reader.read_rle(6).unwrap(),
+            [0xAA, 0xAA, 0xAA, 0x00, 0xFF, 0xFF],
+        );
+        assert_eq!(reader.offset(), 6);
+    }
+
+    #[test]
+    fn rle_overrun() {
+        let scope = ReadScope::new(&[0x02, 0xAA, 0x03, 0xBB]);
+
+        match scope.reader().read_rle(4) {
+            Err(ReadError::RleOverrun { len: 4, decoded: 5 }) => {}
+            Err(err) => panic!("overrun error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn rle_len_exceeds_data() {
+        let scope = ReadScope::new(&[0xFF, 0xAA, 0xFF, 0xBB]);
+
+        assert_eq!(scope.reader().read_rle(510).unwrap().len(), 510);
+        for len in &[511, usize::max_value()] {
+            match scope.reader().read_rle(*len) {
+                Err(ReadError::Eof(_)) => {}
+                Err(err) => panic!("eof error expected, found: {:?}", err),
+                Ok(_) => panic!("error expected, found: Ok(_)"),
+            }
+        }
+    }
+
+    #[test]
+    fn detect_endianness() {
+        let magic = 0x4D4D_002A;
+
+        let mut reader = ReadScope::new(&[0x4D, 0x4D, 0x00, 0x2A]).reader();
+        assert_eq!(reader.detect_endianness(magic).unwrap(), Endianness::Big);
+
+        let mut reader = ReadScope::new(&[0x2A, 0x00, 0x4D, 0x4D]).reader();
+        assert_eq!(reader.detect_endianness(magic).unwrap(), Endianness::Little);
+
+        let mut reader = ReadScope::new(&[0x49, 0x49, 0x2A, 0x00]).reader();
+        match reader.detect_endianness(magic) {
+            Err(ReadError::MagicMismatch {
+                expected: 0x4D4D_002A,
+                found: 0x4949_2A00,
+            }) => {}
+            Err(err) => panic!("magic mismatch error expected, found: {:?}", err),
+            Ok(endianness) => panic!("error expected, found: Ok({:?})", endianness),
+        }
+    }
+
+    #[test]
+    fn check_magic() {
+        let scope = ReadScope::new(&[0x42, 0x4D, 0x00]);
+        let mut reader = scope.reader();
+
+        let signature = reader.read::<U16Be>().unwrap();
+        assert!(reader
+            .check_magic("signature", 0x424D_u16, signature)
+            .is_ok());
+
+        let version = reader.read::<U8>().unwrap();
+        match reader.check_magic("version", 1_u8, version) {
+            Err(ReadError::UnexpectedMagic {
+                field,
+                expected: 1,
+                found: 0,
+            }) => assert_eq!(field, "version"),
+            Err(err) => panic!("unexpected magic error expected, found: {:?}", err),
+            Ok(()) => panic!("error expected, found: Ok(())"),
+        }
+    }
+
+    #[test]
+    fn inclusive_len() {
+        let scope = ReadScope::new(&[0x00, 0x06, 0xDE, 0xAD, 0xBE, 0xEF, 0xFF]);
+        let mut reader = scope.reader();
+
+        let len = reader.read_inclusive_len::<U16Be>().unwrap();
+        assert_eq!(len, 4);
+        assert_eq!(reader.read_bounded::<U32Be>(len).unwrap(), 0xDEAD_BEEF);
+        assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+
+        let mut reader = ReadScope::new(&[0x00, 0x01, 0xFF]).reader();
+        match reader.read_inclusive_len::<U16Be>() {
+            Err(ReadError::LengthTooSmall { len: 1, size: 2 }) => {}
+            Err(err) => panic!("length too small error expected, found: {:?}", err),
+            Ok(len) => panic!("error expected, found: Ok({:?})", len),
+        }
+    }
+
+    #[test]
+    fn packed_ranges() {
+        let mut reader = ReadScope::new(&[0xA5, 0x12, 0x34, 0x56, 0xFF]).reader();
+
+        assert_eq!(
+            reader.read_packed::<U32Be>(&[4, 4, 24]).unwrap(),
+            [0xA, 0x5, 0x12_3456],
+        );
+        assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+
+        let mut reader = ReadScope::new(&[0xA5, 0x12, 0x34, 0x56]).reader();
+        match reader.read_packed::<U32Be>(&[4, 4, 16]) {
+            Err(ReadError::PackedWidthMismatch {
+                backing_width: 32,
+                total_width: 24,
+            }) => {}
+            Err(err) => panic!("packed width mismatch error expected, found: {:?}", err),
+            Ok(values) => panic!("error expected, found: Ok({:?})", values),
+        }
+        assert_eq!(reader.offset(), 0);
+
+        match reader.read_packed::<U32Be>(&[u32::max_value(), 33]) {
+            Err(ReadError::InvalidDataDescription) => {}
+            Err(err) => panic!("invalid data description error expected, found: {:?}", err),
+            Ok(values) => panic!("error expected, found: Ok({:?})", values),
+        }
+        assert_eq!(reader.offset(), 0);
+    }
+
+    #[test]
+    fn dns_name_pointer() {
+        let data = b"\x07example\x03com\x00\x03www\xC0\x00\xFF";
+        let mut reader = ReadScope::new(data).reader();
+
+        assert_eq!(reader.read::<DnsName>().unwrap(), "example.com");
+        assert_eq!(reader.read::<DnsName>().unwrap(), "www.example.com");
+        assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+    }
+
+    #[test]
+    fn dns_name_loop() {
+        let scope = ReadScope::new(b"\x03abc\xC0\x00");
+
+        match scope.read::<DnsName>() {
+            Err(ReadError::CompressionLoop { offset: 0 }) => {}
+            Err(err) => panic!("compression loop error expected, found: {:?}", err),
+            Ok(name) => panic!("error expected, found: Ok({:?})", name),
+        }
+    }
+
+    #[test]
+    fn capture_retains_bytes() {
+        let data = b"\x01fmt \x01\x00\x02";
+        let mut reader = ReadScope::new(data).offset(1).reader();
+
+        let captured = reader.read::<Capture<ChunkFormat>>().unwrap();
+        assert_eq!(captured.value, Chunk::Fmt { format_tag: 1 });
+        assert_eq!(captured.bytes, &data[1..7]);
+        assert_eq!(reader.read::<U8>().unwrap(), 2);
+    }
+
+    /// The last two bytes of a region, read as a big endian checksum.
+    enum ChecksumFooter {}
+
+    impl Format for ChecksumFooter {
+        type Host = u16;
+    }
+
+    impl<'data> ReadFormat<'data> for ChecksumFooter {
+        fn read(reader: &mut FormatReader<'data>) -> Result<u16, ReadError> {
+            reader.reader_from_scope_end(2)?.read::<U16Be>()
+        }
+    }
+
+    #[test]
+    fn reader_from_scope_end() {
+        let scope = ReadScope::new(&[0x01, 0x02, 0xAB, 0xCD, 0xFF, 0xFF]);
+
+        assert_eq!(
+            scope.reader().read_bounded::<ChecksumFooter>(4).unwrap(),
+            0xABCD
+        );
+        assert_eq!(scope.read::<ChecksumFooter>().unwrap(), 0xFFFF);
+
+        match scope.offset(5).read::<ChecksumFooter>() {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(footer) => panic!("error expected, found: Ok({:?})", footer),
+        }
+    }
+
+    /// A nested object, made up of a tag and a value.
+    enum Nested {}
+
+    impl Format for Nested {
+        type Host = (u8, u16);
+    }
+
+    impl<'data> ReadFormat<'data> for Nested {
+        fn read(reader: &mut FormatReader<'data>) -> Result<(u8, u16), ReadError> {
+            Ok((reader.read::<U8>()?, reader.read::<U16Be>()?))
+        }
+    }
+
+    #[test]
+    fn sized_by() {
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<U32Be>(3); // length
+        writer.write::<U8>(7); // Nested::tag
+        writer.write::<U16Be>(0x1234); // Nested::value
+        writer.write::<U8>(0xFF);
+
+        let scope = ReadScope::new(writer.buffer());
+        let mut reader = scope.reader();
+        assert_eq!(
+            reader.read::<SizedBy<U32Be, Nested>>().unwrap(),
+            (7, 0x1234),
+        );
+        assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+
+        // The length leaves a byte that `Nested` doesn't use
+        let scope = ReadScope::new(&[0, 0, 0, 4, 7, 0x12, 0x34, 0xFF]);
+        match scope.read::<SizedBy<U32Be, Nested>>() {
+            Err(ReadError::SizedUnderrun { len, consumed }) => assert_eq!((len, consumed), (4, 3)),
+            Err(err) => panic!("sized underrun error expected, found: {:?}", err),
+            Ok(nested) => panic!("error expected, found: Ok({:?})", nested),
+        }
+
+        // The length cuts `Nested` short, even though more data follows
+        let scope = ReadScope::new(&[0, 0, 0, 2, 7, 0x12, 0x34]);
+        match scope.read::<SizedBy<U32Be, Nested>>() {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(nested) => panic!("error expected, found: Ok({:?})", nested),
+        }
+    }
+
+    #[test]
+    fn offset_to() {
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<U16Be>(6); // offset
+        writer.write::<U8>(0xAA);
+        writer.write::<U8>(0xBB);
+        writer.write::<U16Be>(0); // padding
+        writer.write::<U8>(7); // Nested::tag
+        writer.write::<U16Be>(0x1234); // Nested::value
+
+        let scope = ReadScope::new(writer.buffer());
+        let mut reader = scope.reader();
+        assert_eq!(
+            reader.read::<OffsetTo<U16Be, Nested>>().unwrap(),
+            (7, 0x1234),
+        );
+        // Only the offset was consumed
+        assert_eq!(reader.read::<U8>().unwrap(), 0xAA);
+
+        // Offsets are relative to the start of the scope
+        let scope = ReadScope::new(&[0xFF, 1, 0x42]).offset(1);
+        assert_eq!(scope.read::<OffsetTo<U8, U8>>().unwrap(), 0x42);
+
+        // The offset points past the end of the buffer
+        let scope = ReadScope::new(&[0, 9, 7, 0x12, 0x34]);
+        match scope.read::<OffsetTo<U16Be, Nested>>() {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(nested) => panic!("error expected, found: Ok({:?})", nested),
+        }
+    }
+
+    #[test]
+    fn cstr() {
+        let scope = ReadScope::new(b"abc\0def\0\0");
+        let mut reader = scope.reader();
+        assert_eq!(reader.read::<CStr>().unwrap().as_bytes(), b"abc");
+        assert_eq!(reader.read::<CStr>().unwrap().as_bytes(), b"def");
+        assert_eq!(reader.read::<CStr>().unwrap().as_bytes(), b"");
+        assert!(reader.check_eof().is_ok());
+
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<CStr>(std::ffi::CString::new("abc").unwrap());
+        assert_eq!(writer.buffer(), b"abc\0");
+
+        // The data ends before the terminator
+        match ReadScope::new(b"abc").read::<CStr>() {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(value) => panic!("error expected, found: Ok({:?})", value),
+        }
+    }
+
+    #[test]
+    fn length_prefixed() {
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<U16Be>(2); // count
+        writer.write::<U8>(7); // Nested::tag
+        writer.write::<U16Be>(0x1234); // Nested::value
+        writer.write::<U8>(8); // Nested::tag
+        writer.write::<U16Be>(0x5678); // Nested::value
+        writer.write::<U8>(0xFF);
+
+        let scope = ReadScope::new(writer.buffer());
+        let mut reader = scope.reader();
+        assert_eq!(
+            reader.read::<LengthPrefixed<U16Be, Nested>>().unwrap(),
+            vec![(7, 0x1234), (8, 0x5678)],
+        );
+        assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+
+        let scope = ReadScope::new(&[0]);
+        assert!(scope.read::<LengthPrefixed<U8, U8>>().unwrap().is_empty());
+
+        // The count is larger than the remaining data
+        let scope = ReadScope::new(&[0xFF, 0xFF, 0xFF, 0xFF, 1, 2]);
+        match scope.read::<LengthPrefixed<U32Be, U8>>() {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(values) => panic!("error expected, found: Ok({:?})", values),
+        }
+    }
+
+    #[test]
+    fn read_remaining() {
+        let scope = ReadScope::new(&[0x01, 0x02, 0x03, 0x04]);
+        let mut reader = scope.reader();
+
+        assert_eq!(reader.read::<U8>().unwrap(), 0x01);
+        assert_eq!(reader.read_remaining(), &[0x02, 0x03, 0x04]);
+        assert_eq!(reader.read_remaining(), &[]);
+        assert!(reader.check_eof().is_ok());
+    }
+
+    #[test]
+    fn read_exhaustive() {
+        let scope = ReadScope::new(&[0x00, 0x2A]);
+        assert_eq!(scope.read_exhaustive::<U16Be>().unwrap(), 42);
+
+        let scope = ReadScope::new(&[0x00, 0x2A, 0xFF, 0xFF, 0xFF]);
+        match scope.read_exhaustive::<U16Be>() {
+            Err(ReadError::ExpectedEof { remaining: 3 }) => {}
+            Err(err) => panic!("expected eof error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn xor_descrambled() {
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<U16Be>(0xA55A); // key
+        writer.write_xor_scrambled::<U32Be>(0x0102_0304, &[0xA5, 0x5A]);
+        writer.write::<U8>(0xFF);
+        assert_eq!(writer.buffer()[2..6], [0xA4, 0x58, 0xA6, 0x5E]);
+
+        let scope = ReadScope::new(writer.buffer());
+        let mut reader = scope.reader();
+        let key = reader.read::<U16Be>().unwrap().to_be_bytes();
+        assert_eq!(
+            reader.read_xor_descrambled::<U32Be>(4, &key).unwrap(),
+            0x0102_0304,
+        );
+        assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+
+        match scope.reader().read_xor_descrambled::<U32Be>(8, &key) {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn read_bounded_within_limit() {
+        let scope = ReadScope::new(b"RIFF\x24\x08\x00\x00\xFF");
+        let mut reader = scope.reader();
+
+        assert_eq!(
+            reader.read_bounded::<ChunkFormat>(8).unwrap(),
+            Chunk::Riff { size: 2084 }
+        );
+        assert_eq!(reader.offset(), 8);
+        assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+    }
+
+    #[test]
+    fn read_bounded_exceeds_limit() {
+        let scope = ReadScope::new(b"RIFF\x24\x08\x00\x00");
+
+        match scope.reader().read_bounded::<ChunkFormat>(6) {
+            Err(ReadError::SizeLimitExceeded { limit }) => assert_eq!(limit, 6),
+            Err(err) => panic!("size limit error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+
+        match scope.offset(6).reader().read_bounded::<U32Le>(8) {
+            Err(ReadError::Eof(_)) => {}
+            Err(err) => panic!("eof error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn uleb128_known_values() {
+        assert_eq!(ReadScope::new(&[0x00]).read::<ULeb128>().unwrap(), 0);
+        assert_eq!(ReadScope::new(&[0x7F]).read::<ULeb128>().unwrap(), 127);
+        assert_eq!(
+            ReadScope::new(&[0xE5, 0x8E, 0x26])
+                .read::<ULeb128>()
+                .unwrap(),
+            624_485
+        );
+    }
+
+    #[test]
+    fn sleb128_negative_one() {
+        assert_eq!(ReadScope::new(&[0x7F]).read::<SLeb128>().unwrap(), -1);
+
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<SLeb128>(-1);
+        assert_eq!(writer.buffer(), &[0x7F]);
+    }
+
+    #[test]
+    fn sleb128_negative_128() {
+        assert_eq!(
+            ReadScope::new(&[0x80, 0x7F]).read::<SLeb128>().unwrap(),
+            -128
+        );
+
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<SLeb128>(-128);
+        assert_eq!(writer.buffer(), &[0x80, 0x7F]);
+    }
+
+    #[test]
+    fn sleb128_positive_with_high_group_bit() {
+        // 64 sets the sign bit of a single group, so it needs a second byte
+        assert_eq!(ReadScope::new(&[0xC0, 0x00]).read::<SLeb128>().unwrap(), 64);
+        assert_eq!(ReadScope::new(&[0x40]).read::<SLeb128>().unwrap(), -64);
+
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<SLeb128>(64);
+        assert_eq!(writer.buffer(), &[0xC0, 0x00]);
+    }
+
+    #[test]
+    fn sleb128_extremes() {
+        let mut writer = FormatWriter::new(vec![]);
+        assert_eq!(
+            round_trip::<SLeb128>(&mut writer, i64::min_value()),
+            i64::min_value()
+        );
+        let mut writer = FormatWriter::new(vec![]);
+        assert_eq!(
+            round_trip::<SLeb128>(&mut writer, i64::max_value()),
+            i64::max_value()
+        );
+    }
+
+    #[test]
+    fn uleb128_overflow() {
+        let scope = ReadScope::new(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02]);
+
+        match scope.read::<ULeb128>() {
+            Err(ReadError::IntOverflow) => {}
+            Err(err) => panic!("overflow error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn unix_time_32be_known_value() {
+        let scope = ReadScope::new(&[0x3B, 0x9A, 0xCA, 0x00]); // 1_000_000_000
+
+        assert_eq!(
+            scope.read::<UnixTime32Be>().unwrap(),
+            DateTime {
+                year: 2001,
+                month: 9,
+                day: 9,
+                hour: 1,
+                minute: 46,
+                second: 40,
+            },
+        );
+    }
+
+    #[test]
+    fn unix_time_32be_leap_day() {
+        let scope = ReadScope::new(&[0x38, 0xBB, 0x4C, 0x8F]); // 951_798_927
+
+        assert_eq!(
+            scope.read::<UnixTime32Be>().unwrap(),
+            DateTime {
+                year: 2000,
+                month: 2,
+                day: 29,
+                hour: 4,
+                minute: 35,
+                second: 27,
+            },
+        );
+    }
+
+    #[test]
+    fn dos_date_time_known_value() {
+        // time: 14:30:22 => 0b01110_011110_01011
+        // date: 2019-10-16 => 0b0100111_1010_10000
+        let scope = ReadScope::new(&[0xCB, 0x73, 0x50, 0x4F]);
+
+        assert_eq!(
+            scope.read::<DosDateTime>().unwrap(),
+            DateTime {
+                year: 2019,
+                month: 10,
+                day: 16,
+                hour: 14,
+                minute: 30,
+                second: 22,
+            },
+        );
+    }
+
+    #[test]
+    fn dos_date_time_round_trip() {
+        let date_time = DateTime {
+            year: 2107,
+            month: 12,
+            day: 31,
+            hour: 23,
+            minute: 59,
+            second: 58,
+        };
+        let mut writer = FormatWriter::new(vec![]);
+        assert_eq!(round_trip::<DosDateTime>(&mut writer, date_time), date_time);
+    }
+
+    #[test]
+    fn dos_date_time_day_zero() {
+        // date: 2019-03-00 => 0b0100111_0011_00000
+        let scope = ReadScope::new(&[0x00, 0x00, 0x60, 0x4E]);
+
+        match scope.read::<DosDateTime>() {
+            Err(ReadError::InvalidDateTime(date_time)) => assert_eq!(date_time.day, 0),
+            Err(err) => panic!("invalid date time error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn dos_date_time_month_13() {
+        // date: 2019-13-01 => 0b0100111_1101_00001
+        let scope = ReadScope::new(&[0x00, 0x00, 0xA1, 0x4F]);
+
+        match scope.read::<DosDateTime>() {
+            Err(ReadError::InvalidDateTime(date_time)) => assert_eq!(date_time.month, 13),
+            Err(err) => panic!("invalid date time error expected, found: {:?}", err),
+            Ok(_) => panic!("error expected, found: Ok(_)"),
+        }
+    }
+
+    #[test]
+    fn to_unix_time_out_of_range() {
+        let date_time = DateTime {
+            year: 2019,
+            month: 3,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        };
+
+        assert!(date_time.to_unix_time().is_some());
+        assert_eq!(
+            DateTime {
+                day: 0,
+                ..date_time
+            }
+            .to_unix_time(),
+            None
+        );
+        assert_eq!(
+            DateTime {
+                month: 13,
+                ..date_time
+            }
+            .to_unix_time(),
+            None
+        );
+        assert_eq!(
+            DateTime {
+                month: 2,
+                day: 29,
+                ..date_time
+            }
+            .to_unix_time(),
+            None
+        );
+        assert_eq!(
+            DateTime {
+                hour: 24,
+                ..date_time
+            }
+            .to_unix_time(),
+            None
+        );
+        assert_eq!(
+            DateTime {
+                minute: 60,
+                ..date_time
+            }
+            .to_unix_time(),
+            None
+        );
+        assert_eq!(
+            DateTime {
+                second: 60,
+                ..date_time
+            }
+            .to_unix_time(),
+            None
+        );
+    }
+
+    #[test]
+    fn unix_time_32be_write_clamped() {
+        let date_time = DateTime {
+            year: 1969,
+            month: 12,
+            day: 31,
+            hour: 23,
+            minute: 59,
+            second: 59,
+        };
+        let mut writer = FormatWriter::new(vec![]);
+        writer.write::<UnixTime32Be>(date_time);
+        writer.write::<UnixTime32Be>(DateTime {
+            year: 2200,
+            ..date_time
+        });
+        writer.write::<UnixTime32Be>(DateTime {
+            year: 2001,
+            month: 13,
+            day: 0,
+            ..date_time
+        });
+
+        let scope = ReadScope::new(writer.buffer());
+        let mut reader = scope.reader();
+        assert_eq!(reader.read::<U32Be>().unwrap(), 0);
+        assert_eq!(reader.read::<U32Be>().unwrap(), u32::max_value());
+        assert_eq!(
+            reader.read::<UnixTime32Be>().unwrap(),
+            DateTime {
+                year: 2001,
+                month: 12,
+                day: 1,
+                ..date_time
+            },
+        );
+    }
+
+    #[test]
+    fn writer_truncate() {
+        let mut writer = FormatWriter::new(vec![1]);
+        writer.write::<U16Be>(0x0203);
+        writer.truncate(2);
+        assert_eq!(writer.buffer(), &[1, 2]);
+        writer.truncate(3);
+        assert_eq!(writer.buffer(), &[1, 2]);
+    }
+
+    #[cfg(feature = "flate2")]
+    mod compression {
+        use flate2::write::{DeflateEncoder, ZlibEncoder};
+        use flate2::Compression;
+        use std::io::Write;
+
+        use super::*;
+
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: u16,
+            y: u16,
+        }
+
+        enum PointFormat {}
+
+        impl Format for PointFormat {
+            type Host = Point;
+        }
+
+        impl<'data> ReadFormat<'data> for PointFormat {
+            fn read(reader: &mut FormatReader<'data>) -> Result<Point, ReadError> {
+                let x = reader.read::<U16Le>()?;
+                let y = reader.read::<U16Le>()?;
+                Ok(Point { x, y })
+            }
+        }
+
+        #[test]
+        fn zlib_struct() {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&[0x01, 0x00, 0x02, 0x00]).unwrap();
+            let mut data = encoder.finish().unwrap();
+            data.push(0xFF);
+
+            let scope = ReadScope::new(&data);
+            let mut reader = scope.reader();
+            assert_eq!(
+                reader.read::<Zlib<PointFormat>>().unwrap(),
+                Point { x: 1, y: 2 },
+            );
+            assert_eq!(reader.read::<U8>().unwrap(), 0xFF);
+        }
+
+        #[test]
+        fn deflate_struct() {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&[0x03, 0x00, 0x04, 0x00]).unwrap();
+            let data = encoder.finish().unwrap();
+
+            let scope = ReadScope::new(&data);
+            assert_eq!(
+                scope.read::<Deflate<PointFormat>>().unwrap(),
+                Point { x: 3, y: 4 },
+            );
+        }
+
+        #[test]
+        fn zlib_invalid() {
+            let scope = ReadScope::new(&[0x00, 0x01, 0x02, 0x03]);
+
+            match scope.read::<Zlib<PointFormat>>() {
+                Err(ReadError::DecompressionFailed(_)) => {}
+                Err(err) => panic!("decompression error expected, found: {:?}", err),
+                Ok(_) => panic!("error expected, found: Ok(_)"),
+            }
+        }
     }
 }
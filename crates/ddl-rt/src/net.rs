@@ -0,0 +1,100 @@
+//! Interpreted network address formats.
+//!
+//! These are always stored in network byte order.
+
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{Format, FormatReader, FormatWriter, ReadError, ReadFormat, WriteFormat};
+use crate::{U32Be, U64Be, U8};
+
+/// Marker type for IPv4 addresses.
+#[derive(Copy, Clone)]
+pub enum Ipv4 {}
+
+impl Format for Ipv4 {
+    type Host = Ipv4Addr;
+}
+
+impl<'data> ReadFormat<'data> for Ipv4 {
+    #[inline]
+    fn read(reader: &mut FormatReader<'data>) -> Result<Ipv4Addr, ReadError> {
+        reader.read::<U32Be>().map(Ipv4Addr::from)
+    }
+}
+
+impl WriteFormat for Ipv4 {
+    fn write(writer: &mut FormatWriter, value: Ipv4Addr) {
+        writer.write::<U32Be>(u32::from(value));
+    }
+}
+
+/// Marker type for IPv6 addresses.
+#[derive(Copy, Clone)]
+pub enum Ipv6 {}
+
+impl Format for Ipv6 {
+    type Host = Ipv6Addr;
+}
+
+impl<'data> ReadFormat<'data> for Ipv6 {
+    #[inline]
+    fn read(reader: &mut FormatReader<'data>) -> Result<Ipv6Addr, ReadError> {
+        let high = reader.read::<U64Be>()?;
+        let low = reader.read::<U64Be>()?;
+        Ok(Ipv6Addr::from((u128::from(high) << 64) | u128::from(low)))
+    }
+}
+
+impl WriteFormat for Ipv6 {
+    fn write(writer: &mut FormatWriter, value: Ipv6Addr) {
+        let value = u128::from(value);
+        writer.write::<U64Be>((value >> 64) as u64);
+        writer.write::<U64Be>(value as u64);
+    }
+}
+
+/// A 48-bit MAC address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MacAddr(pub [u8; 6]);
+
+impl fmt::Display for MacAddr {
+    /// Formats the address as six colon-separated pairs of lowercase
+    /// hexadecimal digits, for example `00:1a:2b:3c:4d:5e`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [b0, b1, b2, b3, b4, b5] = self.0;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            b0, b1, b2, b3, b4, b5,
+        )
+    }
+}
+
+/// Marker type for 48-bit MAC addresses.
+#[derive(Copy, Clone)]
+pub enum Mac {}
+
+impl Format for Mac {
+    type Host = MacAddr;
+}
+
+impl<'data> ReadFormat<'data> for Mac {
+    #[inline]
+    fn read(reader: &mut FormatReader<'data>) -> Result<MacAddr, ReadError> {
+        reader.check_available(6)?;
+        let mut bytes = [0; 6];
+        for byte in &mut bytes {
+            *byte = reader.read::<U8>()?;
+        }
+        Ok(MacAddr(bytes))
+    }
+}
+
+impl WriteFormat for Mac {
+    fn write(writer: &mut FormatWriter, value: MacAddr) {
+        for byte in &value.0 {
+            writer.write::<U8>(*byte);
+        }
+    }
+}
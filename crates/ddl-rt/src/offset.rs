@@ -0,0 +1,33 @@
+//! Formats that are stored elsewhere in the buffer, at a position given by
+//! an offset.
+
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use crate::{Format, FormatReader, ReadError, ReadFormat};
+
+/// Marker type for an offset, formatted as `O`, to a value formatted as `T`.
+///
+/// The offset is measured from the start of the current scope, so formats
+/// where offsets are relative to a table should be read from a scope that
+/// starts at that table. Reading consumes only the offset, leaving the
+/// reader positioned at the data that follows it.
+pub struct OffsetTo<O, T>(PhantomData<(O, T)>);
+
+impl<O: Format, T: Format> Format for OffsetTo<O, T> {
+    type Host = T::Host;
+}
+
+impl<'data, O, T> ReadFormat<'data> for OffsetTo<O, T>
+where
+    O: ReadFormat<'data>,
+    O::Host: Into<u64>,
+    T: ReadFormat<'data>,
+{
+    fn read(reader: &mut FormatReader<'data>) -> Result<T::Host, ReadError> {
+        let offset =
+            usize::try_from(reader.read::<O>()?.into()).map_err(|_| ReadError::IntOverflow)?;
+
+        reader.read_at::<T>(offset)
+    }
+}
@@ -0,0 +1,52 @@
+//! Groups of bit fields that are packed into a single backing integer.
+
+use crate::{FormatReader, ReadError, ReadFormat, ReadFormatUnchecked};
+
+impl<'data> FormatReader<'data> {
+    /// Read a backing integer, formatted as `T`, and slice it into
+    /// consecutive ranges of bits with the given widths.
+    ///
+    /// Ranges are taken starting from the most significant bit, matching the
+    /// way that packed fields are usually laid out in format specifications.
+    /// The widths must add up to the width of the backing integer, otherwise
+    /// `ReadError::PackedWidthMismatch` is returned without reading anything.
+    /// Widths that overflow when added together are reported as
+    /// `ReadError::InvalidDataDescription`.
+    pub fn read_packed<T>(&mut self, widths: &[u32]) -> Result<Vec<u64>, ReadError>
+    where
+        T: ReadFormat<'data> + ReadFormatUnchecked<'data>,
+        T::Host: Into<u64>,
+    {
+        let backing_width = (T::SIZE * 8) as u32;
+        let total_width = widths
+            .iter()
+            .try_fold(0u32, |total, &width| total.checked_add(width));
+        let total_width = match total_width {
+            Some(total_width) => total_width,
+            None => return Err(ReadError::InvalidDataDescription),
+        };
+        if total_width != backing_width {
+            return Err(ReadError::PackedWidthMismatch {
+                backing_width,
+                total_width,
+            });
+        }
+
+        let bits = self.read::<T>()?.into();
+        let mut remaining_width = backing_width;
+
+        widths
+            .iter()
+            .map(|&width| {
+                remaining_width = match remaining_width.checked_sub(width) {
+                    Some(remaining_width) => remaining_width,
+                    None => return Err(ReadError::InvalidDataDescription),
+                };
+                match width {
+                    0 => Ok(0),
+                    width => Ok((bits >> remaining_width) & (u64::max_value() >> (64 - width))),
+                }
+            })
+            .collect()
+    }
+}
@@ -3,7 +3,7 @@
 use std::error::Error;
 use std::fmt;
 
-use crate::Format;
+use crate::{DateTime, Format};
 
 /// An error produced while reading binary data.
 #[derive(Debug)]
@@ -12,6 +12,64 @@ pub enum ReadError {
     InvalidDataDescription,
     /// An end of file error.
     Eof(ReadEofError),
+    /// An encoded integer was too large to fit in its host representation.
+    IntOverflow,
+    /// A compressed region of the data could not be decompressed.
+    DecompressionFailed(std::io::Error),
+    /// An error occurred while reading a field.
+    Field {
+        location: FieldLocation,
+        error: Box<ReadError>,
+    },
+    /// The data continued past the end of a format that must consume it all.
+    ExpectedEof { remaining: usize },
+    /// A field did not start at the offset required by its alignment.
+    MisalignedField {
+        field: String,
+        offset: usize,
+        align: usize,
+    },
+    /// Reading would have consumed more than the permitted number of bytes.
+    SizeLimitExceeded { limit: usize },
+    /// None of the variants of a union could be read. The errors are listed
+    /// in the order that the variants were attempted.
+    NoVariantMatched(Vec<ReadError>),
+    /// The underlying source of the data could not be read from.
+    Io(std::io::Error),
+    /// A key was repeated in a list of key/value pairs that requires unique
+    /// keys. The index is that of the pair containing the repeated key.
+    DuplicateKey { index: usize },
+    /// A run in run-length encoded data would have expanded beyond the
+    /// expected length.
+    RleOverrun { len: usize, decoded: usize },
+    /// A magic number did not match its expected value in either byte order.
+    MagicMismatch { expected: u32, found: u32 },
+    /// A compressed domain name pointed back to a part of the name that had
+    /// already been read.
+    CompressionLoop { offset: usize },
+    /// A domain name label used one of the reserved label types.
+    ReservedLabelType { offset: usize },
+    /// A length that includes its own size was smaller than that size.
+    LengthTooSmall { len: usize, size: usize },
+    /// A format read from a region of a known length finished before the end
+    /// of the region.
+    SizedUnderrun { len: usize, consumed: usize },
+    /// The widths of a group of packed bit fields did not add up to the
+    /// width of their backing integer.
+    PackedWidthMismatch {
+        backing_width: u32,
+        total_width: u32,
+    },
+    /// A field did not contain the magic number that it was expected to.
+    UnexpectedMagic {
+        field: String,
+        expected: u64,
+        found: u64,
+    },
+    /// An enum was read with a value that did not match any of its variants.
+    UnknownDiscriminant { ty: String, value: i128 },
+    /// A date and time was read with fields that were out of range.
+    InvalidDateTime(DateTime),
 }
 
 impl fmt::Display for ReadError {
@@ -21,6 +79,103 @@ impl fmt::Display for ReadError {
                 write!(f, "attempted to read improperly specified data")
             }
             ReadError::Eof(error) => error.fmt(f),
+            ReadError::IntOverflow => write!(f, "encoded integer is too large"),
+            ReadError::DecompressionFailed(error) => {
+                write!(f, "failed to decompress data: {}", error)
+            }
+            ReadError::Field { location, error } => write!(f, "{}: {}", location, error),
+            ReadError::ExpectedEof { remaining } => {
+                write!(
+                    f,
+                    "expected end of data, found {} remaining bytes",
+                    remaining
+                )
+            }
+            ReadError::MisalignedField {
+                field,
+                offset,
+                align,
+            } => write!(
+                f,
+                "field `{}` starts at offset {}, which is not aligned to {} bytes",
+                field, offset, align,
+            ),
+            ReadError::SizeLimitExceeded { limit } => {
+                write!(f, "exceeded the size limit of {} bytes", limit)
+            }
+            ReadError::NoVariantMatched(errors) => {
+                write!(f, "no variant matched")?;
+                for (i, error) in errors.iter().enumerate() {
+                    write!(
+                        f,
+                        "{} variant {}: {}",
+                        if i == 0 { ":" } else { "," },
+                        i,
+                        error
+                    )?;
+                }
+                Ok(())
+            }
+            ReadError::Io(error) => write!(f, "failed to read data: {}", error),
+            ReadError::DuplicateKey { index } => {
+                write!(f, "duplicate key in key/value pair {}", index)
+            }
+            ReadError::RleOverrun { len, decoded } => write!(
+                f,
+                "run-length encoded data expanded to {} bytes, expected {}",
+                decoded, len,
+            ),
+            ReadError::MagicMismatch { expected, found } => write!(
+                f,
+                "expected magic number {:#010x} in either byte order, found {:#010x}",
+                expected, found,
+            ),
+            ReadError::CompressionLoop { offset } => {
+                write!(f, "compressed name loops back to offset {}", offset)
+            }
+            ReadError::ReservedLabelType { offset } => {
+                write!(f, "reserved label type at offset {}", offset)
+            }
+            ReadError::LengthTooSmall { len, size } => write!(
+                f,
+                "length of {} bytes is smaller than the {} bytes used to store it",
+                len, size,
+            ),
+            ReadError::SizedUnderrun { len, consumed } => write!(
+                f,
+                "expected to read {} bytes, but only {} were used",
+                len, consumed,
+            ),
+            ReadError::PackedWidthMismatch {
+                backing_width,
+                total_width,
+            } => write!(
+                f,
+                "packed fields are {} bits wide, but their backing integer is {} bits wide",
+                total_width, backing_width,
+            ),
+            ReadError::UnexpectedMagic {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected magic number {:#x} in field `{}`, found {:#x}",
+                expected, field, found,
+            ),
+            ReadError::UnknownDiscriminant { ty, value } => {
+                write!(f, "`{}` is not a variant of `{}`", value, ty)
+            }
+            ReadError::InvalidDateTime(date_time) => write!(
+                f,
+                "invalid date and time {:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                date_time.year,
+                date_time.month,
+                date_time.day,
+                date_time.hour,
+                date_time.minute,
+                date_time.second,
+            ),
         }
     }
 }
@@ -30,6 +185,40 @@ impl Error for ReadError {
         match self {
             ReadError::InvalidDataDescription => None,
             ReadError::Eof(error) => Some(error),
+            ReadError::IntOverflow => None,
+            ReadError::DecompressionFailed(error) => Some(error),
+            ReadError::Field { error, .. } => Some(error),
+            ReadError::ExpectedEof { .. } => None,
+            ReadError::MisalignedField { .. } => None,
+            ReadError::SizeLimitExceeded { .. } => None,
+            ReadError::NoVariantMatched(_) => None,
+            ReadError::Io(error) => Some(error),
+            ReadError::DuplicateKey { .. } => None,
+            ReadError::RleOverrun { .. } => None,
+            ReadError::MagicMismatch { .. } => None,
+            ReadError::CompressionLoop { .. } => None,
+            ReadError::ReservedLabelType { .. } => None,
+            ReadError::LengthTooSmall { .. } => None,
+            ReadError::SizedUnderrun { .. } => None,
+            ReadError::PackedWidthMismatch { .. } => None,
+            ReadError::UnexpectedMagic { .. } => None,
+            ReadError::UnknownDiscriminant { .. } => None,
+            ReadError::InvalidDateTime(_) => None,
+        }
+    }
+}
+
+impl ReadError {
+    /// Replace the end of file error that caused this error with a size
+    /// limit error, keeping the locations of any enclosing fields.
+    fn into_size_limit_exceeded(self, limit: usize) -> ReadError {
+        match self {
+            ReadError::Eof(_) => ReadError::SizeLimitExceeded { limit },
+            ReadError::Field { location, error } => ReadError::Field {
+                location,
+                error: Box::new(error.into_size_limit_exceeded(limit)),
+            },
+            error => error,
         }
     }
 }
@@ -40,6 +229,30 @@ impl From<ReadEofError> for ReadError {
     }
 }
 
+/// The location of a field's definition in a data description, embedded in
+/// generated code for error reporting.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FieldLocation {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The file that the field was defined in.
+    pub file: &'static str,
+    /// The line of the field's definition, starting at `1`.
+    pub line: u32,
+    /// The column of the field's definition, starting at `1`.
+    pub column: u32,
+}
+
+impl fmt::Display for FieldLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "field `{}` defined at {}:{}:{}",
+            self.name, self.file, self.line, self.column,
+        )
+    }
+}
+
 /// An end of file error.
 #[derive(Copy, Clone, Debug)]
 pub struct ReadEofError {}
@@ -98,6 +311,16 @@ impl<'data> ReadScope<'data> {
         self.reader().read::<T>()
     }
 
+    /// Read some binary data in the context, returning an error if any of the
+    /// data is left over afterwards.
+    #[inline]
+    pub fn read_exhaustive<T: ReadFormat<'data>>(&self) -> Result<T::Host, ReadError> {
+        let mut reader = self.reader();
+        let value = reader.read::<T>()?;
+        reader.check_eof()?;
+        Ok(value)
+    }
+
     /// Read some binary data in the context without bounds checking.
     #[inline]
     pub unsafe fn read_unchecked<T: ReadFormatUnchecked<'data>>(&mut self) -> T::Host {
@@ -119,6 +342,34 @@ impl<'data> FormatReader<'data> {
         self.scope.offset(self.offset)
     }
 
+    /// The offset of this context from the start of the buffer.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.scope.base + self.offset
+    }
+
+    /// Create a new reader in the same scope as this one, at `offset` from
+    /// the start of the scope.
+    #[inline]
+    pub(crate) fn reader_at(&self, offset: usize) -> FormatReader<'data> {
+        FormatReader {
+            scope: self.scope,
+            offset,
+        }
+    }
+
+    /// Create a new reader positioned `bytes` before the end of the current
+    /// scope, for example to read a footer at the end of a bounded region.
+    ///
+    /// This fails if the scope holds fewer than `bytes` bytes.
+    #[inline]
+    pub fn reader_from_scope_end(&self, bytes: usize) -> Result<FormatReader<'data>, ReadEofError> {
+        match self.scope.data.len().checked_sub(bytes) {
+            Some(offset) => Ok(self.reader_at(offset)),
+            None => Err(ReadEofError {}),
+        }
+    }
+
     /// Read some binary data in the context.
     #[inline]
     pub fn read<T: ReadFormat<'data>>(&mut self) -> Result<T::Host, ReadError> {
@@ -131,6 +382,120 @@ impl<'data> FormatReader<'data> {
         T::read_unchecked(self)
     }
 
+    /// Read some binary data in the context, consuming at most `max_bytes`.
+    ///
+    /// Reads that would run past the limit fail with
+    /// `ReadError::SizeLimitExceeded` as soon as they are attempted.
+    pub fn read_bounded<T: ReadFormat<'data>>(
+        &mut self,
+        max_bytes: usize,
+    ) -> Result<T::Host, ReadError> {
+        self.read_bounded_with(max_bytes, |reader| reader.read::<T>())
+    }
+
+    /// Call `read` with a reader that can consume at most `max_bytes`, in the
+    /// same way as `read_bounded`.
+    ///
+    /// This is useful when the format to be read is only known at runtime.
+    pub fn read_bounded_with<T>(
+        &mut self,
+        max_bytes: usize,
+        read: impl FnOnce(&mut FormatReader<'data>) -> Result<T, ReadError>,
+    ) -> Result<T, ReadError> {
+        let scope = self.scope();
+        let is_truncated = max_bytes < scope.data.len();
+        let mut reader = ReadScope {
+            base: scope.base,
+            data: &scope.data[..usize::min(max_bytes, scope.data.len())],
+        }
+        .reader();
+
+        match read(&mut reader) {
+            Ok(value) => {
+                self.offset += reader.offset;
+                Ok(value)
+            }
+            Err(error) if is_truncated => Err(error.into_size_limit_exceeded(max_bytes)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Read `T` from exactly the next `len` bytes.
+    ///
+    /// Reads that would run past the region fail with an end of file error,
+    /// and if `T` finishes before the end of the region this fails with
+    /// `ReadError::SizedUnderrun`.
+    pub fn read_within<T: ReadFormat<'data>>(&mut self, len: usize) -> Result<T::Host, ReadError> {
+        self.check_available(len)?;
+        let scope = self.scope();
+        let mut reader = ReadScope {
+            base: scope.base,
+            data: &scope.data[..len],
+        }
+        .reader();
+
+        let value = reader.read::<T>()?;
+        match reader.offset {
+            consumed if consumed == len => {
+                self.offset += len;
+                Ok(value)
+            }
+            consumed => Err(ReadError::SizedUnderrun { len, consumed }),
+        }
+    }
+
+    /// Read `T` at `offset` from the start of the current scope, without
+    /// moving this reader.
+    ///
+    /// This is useful for following offsets to data stored elsewhere in the
+    /// buffer.
+    pub fn read_at<T: ReadFormat<'data>>(&self, offset: usize) -> Result<T::Host, ReadError> {
+        if offset > self.scope.data.len() {
+            return Err(ReadError::Eof(ReadEofError {}));
+        }
+
+        self.reader_at(offset).read::<T>()
+    }
+
+    /// Read the binary data for a field, attaching the field's location to
+    /// any errors that occur.
+    #[inline]
+    pub fn read_field<T: ReadFormat<'data>>(
+        &mut self,
+        location: &FieldLocation,
+    ) -> Result<T::Host, ReadError> {
+        T::read(self).map_err(|error| ReadError::Field {
+            location: *location,
+            error: Box::new(error),
+        })
+    }
+
+    /// Read a value, or return `default` if the data ends before the value
+    /// starts.
+    #[inline]
+    pub fn read_or<T: ReadFormat<'data>>(
+        &mut self,
+        default: T::Host,
+    ) -> Result<T::Host, ReadError> {
+        match self.check_eof() {
+            Ok(()) => Ok(default),
+            Err(_) => self.read::<T>(),
+        }
+    }
+
+    /// Read a field, or return `default` if the data ends before the field
+    /// starts.
+    pub fn read_field_or<T: ReadFormat<'data>>(
+        &mut self,
+        location: &FieldLocation,
+        default: T::Host,
+    ) -> Result<T::Host, ReadError> {
+        match self.check_eof() {
+            Ok(()) => Ok(default),
+            Err(_) => self.read_field::<T>(location),
+        }
+    }
+
     /// Read an unsigned u8-bit integer without performing a bounds check.
     #[inline]
     pub unsafe fn read_unchecked_u8(&mut self) -> u8 {
@@ -148,6 +513,65 @@ impl<'data> FormatReader<'data> {
             Some(_) | None => Err(ReadEofError {}),
         }
     }
+
+    /// Skip over the given number of bytes.
+    #[inline]
+    pub fn skip(&mut self, bytes: usize) -> Result<(), ReadEofError> {
+        self.check_available(bytes)?;
+        self.offset += bytes;
+        Ok(())
+    }
+
+    /// Read all of the bytes left in the buffer, leaving the reader at the end.
+    #[inline]
+    pub fn read_remaining(&mut self) -> &'data [u8] {
+        let bytes = &self.scope.data[self.offset..];
+        self.offset = self.scope.data.len();
+        bytes
+    }
+
+    /// Check that there is no data left to be read in the buffer.
+    #[inline]
+    pub fn check_eof(&self) -> Result<(), ReadError> {
+        match self.scope.data.len() - self.offset {
+            0 => Ok(()),
+            remaining => Err(ReadError::ExpectedEof { remaining }),
+        }
+    }
+
+    /// Check that the value read for the given field is the magic number
+    /// that it is expected to be.
+    #[inline]
+    pub fn check_magic<T: Into<u64>>(
+        &self,
+        field: &str,
+        expected: T,
+        found: T,
+    ) -> Result<(), ReadError> {
+        let (expected, found) = (expected.into(), found.into());
+        if expected != found {
+            return Err(ReadError::UnexpectedMagic {
+                field: field.to_owned(),
+                expected,
+                found,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check that the current offset is a multiple of `align`, in preparation
+    /// for reading the given field.
+    #[inline]
+    pub fn check_aligned(&self, field: &str, align: usize) -> Result<(), ReadError> {
+        match self.offset() % align {
+            0 => Ok(()),
+            _ => Err(ReadError::MisalignedField {
+                field: field.to_owned(),
+                offset: self.offset(),
+                align,
+            }),
+        }
+    }
 }
 
 /// Binary format types that can be read into host data structures without bounds checking.
@@ -170,4 +594,15 @@ where
 {
     /// Read a host value in the context.
     fn read(reader: &mut FormatReader<'data>) -> Result<Self::Host, ReadError>;
+
+    /// Read a host value from the front of `data`, returning it along with
+    /// the bytes that were left unread.
+    fn from_bytes_with_remainder(data: &'data [u8]) -> Result<(Self::Host, &'data [u8]), ReadError>
+    where
+        Self: Sized,
+    {
+        let mut reader = ReadScope::new(data).reader();
+        let value = reader.read::<Self>()?;
+        Ok((value, reader.scope().data()))
+    }
 }
@@ -0,0 +1,37 @@
+//! Run-length encoded byte arrays.
+
+use crate::{FormatReader, ReadEofError, ReadError, U8};
+
+impl<'data> FormatReader<'data> {
+    /// Read a run-length encoded byte array, expanding it to `len` bytes.
+    ///
+    /// The encoded data is a sequence of `(count, value)` byte pairs, each
+    /// expanding to `count` copies of `value`, as used by simple BMP and icon
+    /// encoders. Pairs are read until exactly `len` bytes have been produced.
+    ///
+    /// The length often comes from the data itself, so it is checked against
+    /// the longest run that the remaining data could expand to before any
+    /// memory is allocated for it.
+    pub fn read_rle(&mut self, len: usize) -> Result<Vec<u8>, ReadError> {
+        let max_len = (self.scope().data().len() / 2).saturating_mul(usize::from(u8::max_value()));
+        if len > max_len {
+            return Err(ReadError::Eof(ReadEofError {}));
+        }
+
+        let mut bytes = Vec::with_capacity(len);
+
+        while bytes.len() < len {
+            let count = usize::from(self.read::<U8>()?);
+            let value = self.read::<U8>()?;
+            if bytes.len() + count > len {
+                return Err(ReadError::RleOverrun {
+                    len,
+                    decoded: bytes.len() + count,
+                });
+            }
+            bytes.resize(bytes.len() + count, value);
+        }
+
+        Ok(bytes)
+    }
+}
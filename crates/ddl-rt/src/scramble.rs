@@ -0,0 +1,49 @@
+//! Regions of binary data that have been obfuscated with a key.
+
+use crate::{FormatReader, FormatWriter, ReadError, ReadFormat, ReadScope, WriteFormat};
+
+/// XOR `bytes` with `key`, repeating the key from its first byte as needed.
+/// An empty key leaves the bytes unchanged.
+fn xor_with_key(bytes: &[u8], key: &[u8]) -> Vec<u8> {
+    if key.is_empty() {
+        return bytes.to_vec();
+    }
+
+    bytes
+        .iter()
+        .zip(key.iter().cycle())
+        .map(|(byte, key_byte)| byte ^ key_byte)
+        .collect()
+}
+
+impl<'data> FormatReader<'data> {
+    /// Read the next `len` bytes as `T`, after XOR-ing them with `key`.
+    ///
+    /// Some proprietary formats scramble their payload like this, usually
+    /// with a key derived from a header field that has already been read.
+    /// Reading continues immediately after the scrambled region.
+    pub fn read_xor_descrambled<T>(&mut self, len: usize, key: &[u8]) -> Result<T::Host, ReadError>
+    where
+        T: for<'a> ReadFormat<'a>,
+    {
+        self.check_available(len)?;
+        let buffer = xor_with_key(&self.scope().data()[..len], key);
+        self.skip(len)?;
+
+        ReadScope::new(&buffer).read::<T>()
+    }
+}
+
+impl FormatWriter {
+    /// Write `value` as `T`, XOR-ing the written bytes with `key`.
+    ///
+    /// This is the inverse of `FormatReader::read_xor_descrambled`.
+    pub fn write_xor_scrambled<T: WriteFormat>(&mut self, value: T::Host, key: &[u8]) {
+        let mut writer = FormatWriter::new(Vec::new());
+        writer.write::<T>(value);
+
+        for byte in xor_with_key(writer.buffer(), key) {
+            self.write_u8(byte);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Formats that are stored in a region given by a length prefix.
+
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use crate::{Format, FormatReader, ReadError, ReadFormat};
+
+/// Marker type for a length, formatted as `L`, followed by a value formatted
+/// as `T` that occupies exactly that many bytes.
+///
+/// This is the usual way of embedding one object inside another. The value
+/// can't read past the end of its region, and it is an error for it to stop
+/// before the end.
+pub struct SizedBy<L, T>(PhantomData<(L, T)>);
+
+impl<L: Format, T: Format> Format for SizedBy<L, T> {
+    type Host = T::Host;
+}
+
+impl<'data, L, T> ReadFormat<'data> for SizedBy<L, T>
+where
+    L: ReadFormat<'data>,
+    L::Host: Into<u64>,
+    T: ReadFormat<'data>,
+{
+    fn read(reader: &mut FormatReader<'data>) -> Result<T::Host, ReadError> {
+        let len =
+            usize::try_from(reader.read::<L>()?.into()).map_err(|_| ReadError::IntOverflow)?;
+
+        reader.read_within::<T>(len)
+    }
+}
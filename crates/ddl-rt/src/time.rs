@@ -0,0 +1,233 @@
+//! Interpreted date and time formats.
+
+use crate::{Format, FormatReader, FormatWriter, ReadError, ReadFormat, WriteFormat};
+use crate::{U16Le, U32Be};
+
+/// A calendar date and time of day, in UTC.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DateTime {
+    /// The year.
+    pub year: u16,
+    /// The month of the year, starting at `1` for January.
+    pub month: u8,
+    /// The day of the month, starting at `1`.
+    pub day: u8,
+    /// The hour of the day, from `0` to `23`.
+    pub hour: u8,
+    /// The minute of the hour, from `0` to `59`.
+    pub minute: u8,
+    /// The second of the minute, from `0` to `59`.
+    pub second: u8,
+}
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+impl DateTime {
+    /// Convert a count of seconds since the Unix epoch (1970-01-01T00:00:00Z)
+    /// into a date and time.
+    pub fn from_unix_time(seconds: u32) -> DateTime {
+        let days = seconds / SECONDS_PER_DAY;
+        let seconds = seconds % SECONDS_PER_DAY;
+        let (year, month, day) = civil_from_days(days);
+
+        DateTime {
+            year,
+            month,
+            day,
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+        }
+    }
+
+    /// Returns `true` if each of the fields is within its range, taking the
+    /// length of the month into account.
+    pub fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && (1..=days_in_month(self.year, self.month)).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 60
+    }
+
+    /// Bring each of the fields into its range, keeping the year as is.
+    fn clamp_fields(&self) -> DateTime {
+        let month = match self.month {
+            0 => 1,
+            1..=12 => self.month,
+            _ => 12,
+        };
+        let day = match self.day {
+            0 => 1,
+            day => day.min(days_in_month(self.year, month)),
+        };
+
+        DateTime {
+            year: self.year,
+            month,
+            day,
+            hour: self.hour.min(23),
+            minute: self.minute.min(59),
+            second: self.second.min(59),
+        }
+    }
+
+    /// Convert this date and time into a count of seconds since the Unix
+    /// epoch (1970-01-01T00:00:00Z).
+    ///
+    /// Returns `None` if any of the fields are out of range, if the date
+    /// precedes the epoch, or if the result does not fit in 32 bits.
+    pub fn to_unix_time(&self) -> Option<u32> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let days = days_from_civil(self.year, self.month, self.day)?;
+        let seconds =
+            u32::from(self.hour) * 3600 + u32::from(self.minute) * 60 + u32::from(self.second);
+
+        days.checked_mul(SECONDS_PER_DAY)?.checked_add(seconds)
+    }
+}
+
+// The following conversions are adapted from Howard Hinnant's
+// [date algorithms](http://howardhinnant.github.io/date_algorithms.html),
+// restricted to dates on or after the Unix epoch.
+
+/// Convert a number of days since 1970-01-01 into a `(year, month, day)` triple.
+fn civil_from_days(days: u32) -> (u16, u8, u8) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year as u16, month as u8, day as u8)
+}
+
+/// The number of days in the given month, which must be from `1` to `12`.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    let is_leap_year = match (year % 4, year % 100, year % 400) {
+        (_, _, 0) => true,
+        (_, 0, _) => false,
+        (0, _, _) => true,
+        (_, _, _) => false,
+    };
+
+    match month {
+        2 if is_leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Convert a valid `(year, month, day)` triple into a number of days since
+/// 1970-01-01.
+fn days_from_civil(year: u16, month: u8, day: u8) -> Option<u32> {
+    let year = u32::from(year).checked_sub(if month <= 2 { 1 } else { 0 })?;
+    let month = u32::from(month);
+    let era = year / 400;
+    let yoe = year - era * 400; // [0, 399]
+    let mp = if month > 2 { month - 3 } else { month + 9 }; // [0, 11]
+    let doy = (153 * mp + 2) / 5 + u32::from(day) - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+
+    (era * 146_097 + doe).checked_sub(719_468)
+}
+
+/// Marker type for dates stored as an unsigned 32-bit count of seconds since
+/// the Unix epoch (big endian).
+#[derive(Copy, Clone)]
+pub enum UnixTime32Be {}
+
+impl Format for UnixTime32Be {
+    type Host = DateTime;
+}
+
+impl<'data> ReadFormat<'data> for UnixTime32Be {
+    #[inline]
+    fn read(reader: &mut FormatReader<'data>) -> Result<DateTime, ReadError> {
+        reader.read::<U32Be>().map(DateTime::from_unix_time)
+    }
+}
+
+impl WriteFormat for UnixTime32Be {
+    /// Fields that are out of range are clamped before writing, and dates
+    /// outside of the range of the timestamp are written as the earliest or
+    /// latest time that it can represent.
+    fn write(writer: &mut FormatWriter, value: DateTime) {
+        let value = value.clamp_fields();
+        let seconds = match value.to_unix_time() {
+            Some(seconds) => seconds,
+            None if value.year < 1970 => 0,
+            None => u32::max_value(),
+        };
+        writer.write::<U32Be>(seconds);
+    }
+}
+
+/// Marker type for MS-DOS dates and times, as found in FAT directory entries
+/// and ZIP archives.
+///
+/// This is stored as a 16-bit time followed by a 16-bit date, both little
+/// endian, packed as follows:
+///
+/// | Field  | Bits       | Description            |
+/// | ------ | ---------- | ---------------------- |
+/// | second | time 0-4   | seconds divided by two |
+/// | minute | time 5-10  | minutes                |
+/// | hour   | time 11-15 | hours                  |
+/// | day    | date 0-4   | day of the month       |
+/// | month  | date 5-8   | month of the year      |
+/// | year   | date 9-15  | years since 1980       |
+#[derive(Copy, Clone)]
+pub enum DosDateTime {}
+
+impl Format for DosDateTime {
+    type Host = DateTime;
+}
+
+impl<'data> ReadFormat<'data> for DosDateTime {
+    #[inline]
+    fn read(reader: &mut FormatReader<'data>) -> Result<DateTime, ReadError> {
+        let time = reader.read::<U16Le>()?;
+        let date = reader.read::<U16Le>()?;
+        let date_time = DateTime {
+            year: 1980 + (date >> 9),
+            month: ((date >> 5) & 0x0F) as u8,
+            day: (date & 0x1F) as u8,
+            hour: (time >> 11) as u8,
+            minute: ((time >> 5) & 0x3F) as u8,
+            second: ((time & 0x1F) * 2) as u8,
+        };
+
+        match date_time.is_valid() {
+            true => Ok(date_time),
+            false => Err(ReadError::InvalidDateTime(date_time)),
+        }
+    }
+}
+
+impl WriteFormat for DosDateTime {
+    /// Seconds are truncated to an even number, as the DOS format can only
+    /// represent them with a resolution of two seconds. Fields that are out
+    /// of range are clamped before writing, and years outside of 1980 to 2107
+    /// are clamped to that range.
+    fn write(writer: &mut FormatWriter, value: DateTime) {
+        let value = value.clamp_fields();
+        let time = (u16::from(value.hour) << 11)
+            | (u16::from(value.minute & 0x3F) << 5)
+            | u16::from(value.second / 2);
+        let date = (value.year.saturating_sub(1980).min(0x7F) << 9)
+            | (u16::from(value.month & 0x0F) << 5)
+            | u16::from(value.day & 0x1F);
+
+        writer.write::<U16Le>(time);
+        writer.write::<U16Le>(date);
+    }
+}
@@ -21,6 +21,13 @@ impl FormatWriter {
         &self.buffer
     }
 
+    /// Discard everything written to the buffer after the first `len` bytes.
+    ///
+    /// This has no effect if the buffer is not longer than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        self.buffer.truncate(len);
+    }
+
     /// Write a `u8` to the buffer.
     pub fn write_u8(&mut self, value: u8) {
         self.buffer.push(value);
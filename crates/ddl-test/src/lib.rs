@@ -36,6 +36,8 @@ mod alias {
     test!(pass_if_else_if_else_format_type, "alias/pass_if_else_if_else_format_type.ddl");
     test!(pass_if_else_term, "alias/pass_if_else_term.ddl");
     test!(pass_if_else_term_item, "alias/pass_if_else_term_item.ddl");
+    test!(pass_int_limits, "alias/pass_int_limits.ddl");
+    test!(pass_int_limits_128, "alias/pass_int_limits_128.ddl");
     test!(pass_simple, "alias/pass_simple.ddl");
     test!(pass_simple_doc, "alias/pass_simple_doc.ddl");
 
@@ -50,21 +52,56 @@ mod alias {
     test!(fail_unconstrained_int_type, "alias/fail_unconstrained_int_type.ddl");
 }
 
+#[rustfmt::skip]
+mod r#enum {
+    test!(pass_empty, "enum/pass_empty.ddl");
+    test!(pass_simple, "enum/pass_simple.ddl");
+
+    test!(fail_duplicate_variants, "enum/fail_duplicate_variants.ddl");
+    test!(fail_invalid_repr, "enum/fail_invalid_repr.ddl");
+}
+
 #[rustfmt::skip]
 mod r#struct {
+    test!(pass_aligned, "struct/pass_aligned.ddl");
+    test!(pass_cfg, "struct/pass_cfg.ddl");
+    test!(pass_cstr, "struct/pass_cstr.ddl");
+    test!(pass_defaults, "struct/pass_defaults.ddl");
+    test!(pass_doc_hide, "struct/pass_doc_hide.ddl");
     test!(pass_empty, "struct/pass_empty.ddl");
     test!(pass_empty_doc, "struct/pass_empty_doc.ddl");
+    test!(pass_eof, "struct/pass_eof.ddl");
+    test!(pass_fields_differing_by_case, "struct/pass_fields_differing_by_case.ddl");
+    test!(pass_half_floats, "struct/pass_half_floats.ddl");
     test!(pass_if_else_type_item, "struct/pass_if_else_type_item.ddl");
     test!(pass_if_else_type_item_item, "struct/pass_if_else_type_item_item.ddl");
+    test!(pass_include, "struct/pass_include.ddl");
+    test!(pass_keyword_fields, "struct/pass_keyword_fields.ddl");
+    test!(pass_language_keyword_fields, "struct/pass_language_keyword_fields.ddl");
+    test!(pass_magic, "struct/pass_magic.ddl");
     test!(pass_mixed_format_host, "struct/pass_mixed_format_host.ddl");
     test!(pass_pair, "struct/pass_pair.ddl");
+    test!(pass_read_bounded, "struct/pass_read_bounded.ddl");
+    test!(pass_rename, "struct/pass_rename.ddl");
+    test!(pass_reserved, "struct/pass_reserved.ddl");
+    test!(pass_rest, "struct/pass_rest.ddl");
     test!(pass_singleton, "struct/pass_singleton.ddl");
+    test!(pass_transparent, "struct/pass_transparent.ddl");
     test!(pass_var, "struct/pass_var.ddl");
+    test!(pass_visitor, "struct/pass_visitor.ddl");
+    test!(pass_wide_ints, "struct/pass_wide_ints.ddl");
 
+    test!(fail_defaults, "struct/fail_defaults.ddl");
     test!(fail_duplicate_fields, "struct/fail_duplicate_fields.ddl");
+    test!(fail_field_host_type, "struct/fail_field_host_type.ddl");
     test!(fail_field_type_mismatch, "struct/fail_field_type_mismatch.ddl");
+    test!(fail_include, "struct/fail_include.ddl");
+    test!(fail_invalid_attributes, "struct/fail_invalid_attributes.ddl");
+    test!(fail_magic, "struct/fail_magic.ddl");
     test!(fail_missing_closing_brace, "struct/fail_missing_closing_brace.ddl");
     test!(fail_missing_fields, "struct/fail_missing_fields.ddl");
     test!(fail_missing_name, "struct/fail_missing_name.ddl");
+    test!(fail_rest, "struct/fail_rest.ddl");
+    test!(fail_transparent, "struct/fail_transparent.ddl");
     test!(fail_undefined_field, "struct/fail_undefined_field.ddl");
 }
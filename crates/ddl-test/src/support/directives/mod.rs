@@ -18,6 +18,18 @@ pub struct Directives {
     /// //~ SKIP: reason
     /// ```
     pub skip: Option<String>,
+    /// Generate bounded readers for structure types.
+    ///
+    /// ```text
+    /// //~ READ_BOUNDED
+    /// ```
+    pub read_bounded: bool,
+    /// Generate a visitor trait for structure types.
+    ///
+    /// ```text
+    /// //~ VISITOR
+    /// ```
+    pub visitor: bool,
     /// Diagnostic directives:
     ///
     /// ```text
@@ -34,6 +46,8 @@ impl Default for Directives {
     fn default() -> Directives {
         Directives {
             skip: None,
+            read_bounded: false,
+            visitor: false,
             expected_diagnostics: Vec::new(),
         }
     }
@@ -34,6 +34,22 @@ impl<'a> Parser<'a> {
                         }
                         Some(reason) => self.directives.skip = Some(reason.to_string()),
                     },
+                    ("READ_BOUNDED", value) => match value {
+                        Some(value) => self.diagnostics.push(Diagnostic::new_error(
+                            "`READ_BOUNDED` directive does not take a value",
+                            self.label(value.span(), "unexpected value"),
+                        )),
+                        None if self.directives.read_bounded => self.duplicate_directive(&key),
+                        None => self.directives.read_bounded = true,
+                    },
+                    ("VISITOR", value) => match value {
+                        Some(value) => self.diagnostics.push(Diagnostic::new_error(
+                            "`VISITOR` directive does not take a value",
+                            self.label(value.span(), "unexpected value"),
+                        )),
+                        None if self.directives.visitor => self.duplicate_directive(&key),
+                        None => self.directives.visitor = true,
+                    },
                     ("bug", pattern) => self.expect_bug(span, pattern),
                     ("error", pattern) => self.expect_error(span, pattern),
                     ("warning", pattern) => self.expect_warning(span, pattern),
@@ -48,6 +64,8 @@ impl<'a> Parser<'a> {
                             "
                                 perhaps you meant:
                                     - SKIP:         <reason>
+                                    - READ_BOUNDED
+                                    - VISITOR
                                     - bug:          <regex>
                                     - error:        <regex>
                                     - warning:      <regex>
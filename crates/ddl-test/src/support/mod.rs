@@ -3,7 +3,7 @@ use codespan_reporting::diagnostic::{Diagnostic, Severity};
 use codespan_reporting::term;
 use codespan_reporting::term::termcolor::{BufferWriter, ColorChoice, StandardStream};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 mod directives;
@@ -58,7 +58,8 @@ pub fn run_integration_test(test_name: &str, ddl_path: &str) {
     let core_module = test.elaborate(&files, &surface_module);
     test.roundtrip_delaborate_core(&files, &core_module);
     test.roundtrip_pretty_core(&mut files, &core_module);
-    test.compile_rust(&core_module);
+    test.roundtrip_source(&mut files, &core_module);
+    test.compile_rust(&files, &core_module);
 
     test.finish(&files);
 }
@@ -88,7 +89,8 @@ impl Test {
         let source = fs::read_to_string(&input_ddl_path).unwrap_or_else(|error| {
             panic!("error reading `{}`: {}", input_ddl_path.display(), error)
         });
-        let input_ddl_file_id = files.add(input_ddl_path.display().to_string(), source);
+        let input_ddl_name = Path::new("tests").join("input").join(ddl_path);
+        let input_ddl_file_id = files.add(input_ddl_name.display().to_string(), source);
 
         // Extract the directives from the source code
 
@@ -285,11 +287,75 @@ impl Test {
         }
     }
 
-    fn compile_rust(&mut self, core_module: &ddl::core::Module) {
+    fn roundtrip_source(&mut self, files: &mut Files, core_module: &ddl::core::Module) {
+        // Modules containing errors can't be rendered as valid source code
+        if !self.found_diagnostics.is_empty() {
+            return;
+        }
+
+        let source = core_module.to_source();
+        let source_file_id = files.add(
+            self.snapshot_filename
+                .with_extension("ddl")
+                .display()
+                .to_string(),
+            source.clone(),
+        );
+
+        let mut source_diagnostics = Vec::new();
+        let source_core_module = {
+            let keywords = &ddl::lexer::SURFACE_KEYWORDS;
+            let lexer = ddl::lexer::Lexer::new(files, source_file_id, keywords);
+            let surface_module = ddl::surface::Module::parse(source_file_id, lexer, &mut |d| {
+                source_diagnostics.push(d)
+            });
+            ddl::surface::elaborate::elaborate_module(&surface_module, &mut |d| {
+                source_diagnostics.push(d)
+            })
+        };
+
+        if !source_diagnostics.is_empty() {
+            self.failed_checks.push("roundtrip_source: parse source");
+
+            let mut buffer = BufferWriter::stderr(ColorChoice::Auto).buffer();
+            for diagnostic in &source_diagnostics {
+                term::emit(&mut buffer, &self.term_config, files, diagnostic).unwrap();
+            }
+
+            eprintln!("  • roundtrip_source: parse source");
+            eprintln!();
+            eprintln_indented(4, "", "---- found diagnostics ----");
+            eprintln_indented(4, "| ", &String::from_utf8_lossy(buffer.as_slice()));
+            eprintln!();
+        }
+
+        if *core_module != source_core_module {
+            self.failed_checks
+                .push("roundtrip_source: core != elaborate(parse(to_source(core)))");
+
+            eprintln!("  • roundtrip_source: core != elaborate(parse(to_source(core)))");
+            eprintln!();
+            eprintln_indented(4, "", "---- to_source(core) ----");
+            for line in source.lines() {
+                eprintln_indented(4, "| ", line);
+            }
+            eprintln!();
+        }
+    }
+
+    fn compile_rust(&mut self, files: &Files, core_module: &ddl::core::Module) {
         let mut output = Vec::new();
-        let rust_module = ddl::core::compile::rust::compile_module(core_module, &mut |d| {
-            self.found_diagnostics.push(d);
-        });
+        let options = ddl::core::compile::rust::Options {
+            read_bounded: self.directives.read_bounded,
+            visitor: self.directives.visitor,
+            ..ddl::core::compile::rust::Options::default()
+        };
+        let rust_module = ddl::core::compile::rust::compile_module_with_options(
+            files,
+            core_module,
+            &options,
+            &mut |d| self.found_diagnostics.push(d),
+        );
         ddl::rust::emit::emit_module(&mut output, &rust_module).unwrap();
         let snapshot_rs_path = self.snapshot_filename.with_extension("rs");
 
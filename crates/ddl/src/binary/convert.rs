@@ -0,0 +1,168 @@
+//! Conversions from binary terms into Rust primitives.
+
+use num_bigint::BigInt;
+use num_traits::cast::ToPrimitive;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use crate::binary::Term;
+
+/// An error produced when converting a term into a Rust primitive.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    /// The term was not of the expected kind.
+    KindMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// The integer could not be represented in the target type.
+    IntOutOfRange { ty: &'static str, value: BigInt },
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::KindMismatch { expected, found } => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            ConversionError::IntOutOfRange { ty, value } => {
+                write!(f, "integer `{}` is out of range for `{}`", value, ty)
+            }
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+fn kind_name(term: &Term) -> &'static str {
+    match term {
+        Term::Int(_) => "integer",
+        Term::F32(_) => "32-bit float",
+        Term::F64(_) => "64-bit float",
+        Term::Bytes(_) => "byte array",
+        Term::Struct(_) => "structure",
+    }
+}
+
+macro_rules! impl_try_from_int {
+    ($($ty:ident => $to_ty:ident),* $(,)?) => {
+        $(
+            impl TryFrom<&Term> for $ty {
+                type Error = ConversionError;
+
+                fn try_from(term: &Term) -> Result<$ty, ConversionError> {
+                    match term {
+                        Term::Int(value) => {
+                            value.$to_ty().ok_or_else(|| ConversionError::IntOutOfRange {
+                                ty: stringify!($ty),
+                                value: value.clone(),
+                            })
+                        }
+                        term => Err(ConversionError::KindMismatch {
+                            expected: "integer",
+                            found: kind_name(term),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int! {
+    u8 => to_u8,
+    u16 => to_u16,
+    u32 => to_u32,
+    u64 => to_u64,
+    i8 => to_i8,
+    i16 => to_i16,
+    i32 => to_i32,
+    i64 => to_i64,
+}
+
+impl TryFrom<&Term> for f32 {
+    type Error = ConversionError;
+
+    fn try_from(term: &Term) -> Result<f32, ConversionError> {
+        match term {
+            Term::F32(value) => Ok(*value),
+            term => Err(ConversionError::KindMismatch {
+                expected: "32-bit float",
+                found: kind_name(term),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&Term> for f64 {
+    type Error = ConversionError;
+
+    fn try_from(term: &Term) -> Result<f64, ConversionError> {
+        match term {
+            Term::F64(value) => Ok(*value),
+            term => Err(ConversionError::KindMismatch {
+                expected: "64-bit float",
+                found: kind_name(term),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn ints() {
+        assert_eq!(u8::try_from(&Term::Int(255.into())), Ok(255));
+        assert_eq!(i64::try_from(&Term::Int((-3).into())), Ok(-3));
+        assert_eq!(
+            u64::try_from(&Term::Int(u64::max_value().into())),
+            Ok(u64::max_value())
+        );
+
+        assert_eq!(
+            u8::try_from(&Term::Int(300.into())),
+            Err(ConversionError::IntOutOfRange {
+                ty: "u8",
+                value: 300.into(),
+            }),
+        );
+        assert_eq!(
+            u32::try_from(&Term::Int((-1).into()))
+                .unwrap_err()
+                .to_string(),
+            "integer `-1` is out of range for `u32`",
+        );
+        assert_eq!(
+            i64::try_from(&Term::F64(1.0)),
+            Err(ConversionError::KindMismatch {
+                expected: "integer",
+                found: "64-bit float",
+            }),
+        );
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(f32::try_from(&Term::F32(1.5)), Ok(1.5));
+        assert_eq!(f64::try_from(&Term::F64(-2.25)), Ok(-2.25));
+
+        assert_eq!(
+            f32::try_from(&Term::F64(1.5)),
+            Err(ConversionError::KindMismatch {
+                expected: "32-bit float",
+                found: "64-bit float",
+            }),
+        );
+        assert_eq!(
+            f64::try_from(&Term::Struct(BTreeMap::new()))
+                .unwrap_err()
+                .to_string(),
+            "expected 64-bit float, found structure",
+        );
+    }
+}
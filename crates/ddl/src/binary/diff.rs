@@ -0,0 +1,80 @@
+//! Structural differences between binary terms.
+//!
+//! This is useful for regression testing data descriptions, by comparing the
+//! terms read from the same data before and after a change to a format.
+
+use crate::binary::Term;
+
+/// A difference found between two terms, at a path of structure field names.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TermDiff {
+    /// The term changed to a new value of the same type.
+    Changed {
+        path: Vec<String>,
+        old: Term,
+        new: Term,
+    },
+    /// The term changed to a value of a different type.
+    TypeChanged {
+        path: Vec<String>,
+        old: Term,
+        new: Term,
+    },
+    /// A field was added to a structure.
+    Added { path: Vec<String>, term: Term },
+    /// A field was removed from a structure.
+    Removed { path: Vec<String>, term: Term },
+}
+
+/// Find the differences between two terms.
+pub fn diff_terms(old: &Term, new: &Term) -> Vec<TermDiff> {
+    let mut diffs = Vec::new();
+    diff_terms_at(&mut Vec::new(), old, new, &mut diffs);
+    diffs
+}
+
+fn diff_terms_at(path: &mut Vec<String>, old: &Term, new: &Term, diffs: &mut Vec<TermDiff>) {
+    match (old, new) {
+        (Term::Struct(old_fields), Term::Struct(new_fields)) => {
+            for (name, old_term) in old_fields {
+                path.push(name.clone());
+                match new_fields.get(name) {
+                    Some(new_term) => diff_terms_at(path, old_term, new_term, diffs),
+                    None => diffs.push(TermDiff::Removed {
+                        path: path.clone(),
+                        term: old_term.clone(),
+                    }),
+                }
+                path.pop();
+            }
+
+            for (name, new_term) in new_fields {
+                if !old_fields.contains_key(name) {
+                    path.push(name.clone());
+                    diffs.push(TermDiff::Added {
+                        path: path.clone(),
+                        term: new_term.clone(),
+                    });
+                    path.pop();
+                }
+            }
+        }
+        (Term::Int(_), Term::Int(_))
+        | (Term::F32(_), Term::F32(_))
+        | (Term::F64(_), Term::F64(_))
+        | (Term::Bytes(_), Term::Bytes(_)) => {
+            if old != new {
+                diffs.push(TermDiff::Changed {
+                    path: path.clone(),
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        }
+        (_, _) => diffs.push(TermDiff::TypeChanged {
+            path: path.clone(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+    }
+}
@@ -0,0 +1,137 @@
+//! Reading binary data directly from the source of a data description, and
+//! rendering the result for display.
+//!
+//! This strings together the front-end, the binary interpreter, and the term
+//! renderers, which is what a command line tool for dumping the contents of
+//! a file would need.
+
+use codespan::{FileId, Files};
+use codespan_reporting::diagnostic::{Diagnostic, Severity};
+use std::error::Error;
+use std::fmt;
+
+use crate::lexer::{Lexer, SURFACE_KEYWORDS};
+use crate::{binary, core, surface};
+
+/// How to render the terms read from binary data.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// An indented tree of fields, as produced by `Term::display_tree`.
+    Tree,
+    /// JSON, as produced by `Term::display_json`.
+    Json,
+}
+
+/// An error produced while dumping binary data.
+#[derive(Debug)]
+pub enum DumpError {
+    /// The data description contained errors. All of the diagnostics that
+    /// were reported are included, so that they can be emitted against the
+    /// files they refer to.
+    Diagnostics(Vec<Diagnostic>),
+    /// The data description was valid, but the data could not be read.
+    Read(ddl_rt::ReadError),
+}
+
+impl fmt::Display for DumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DumpError::Diagnostics(diagnostics) => {
+                let error_count = diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity >= Severity::Error)
+                    .count();
+                write!(f, "invalid data description: {} errors", error_count)
+            }
+            DumpError::Read(error) => write!(f, "failed to read data: {}", error),
+        }
+    }
+}
+
+impl Error for DumpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DumpError::Diagnostics(_) => None,
+            DumpError::Read(error) => Some(error),
+        }
+    }
+}
+
+impl From<ddl_rt::ReadError> for DumpError {
+    fn from(error: ddl_rt::ReadError) -> DumpError {
+        DumpError::Read(error)
+    }
+}
+
+/// Check the data description in `file_id`, then read `data` as the item
+/// named `item_name`, rendering the result in the given format.
+pub fn parse_and_render(
+    files: &Files,
+    file_id: FileId,
+    item_name: &str,
+    data: &[u8],
+    format: OutputFormat,
+) -> Result<String, DumpError> {
+    let mut diagnostics = Vec::new();
+    let lexer = Lexer::new(files, file_id, &SURFACE_KEYWORDS);
+    let surface_module = surface::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+    let core_module =
+        surface::elaborate::elaborate_module(&surface_module, &mut |d| diagnostics.push(d));
+    core::validate::validate_module(&core_module, &mut |d| diagnostics.push(d));
+
+    if diagnostics.iter().any(|d| d.severity >= Severity::Error) {
+        return Err(DumpError::Diagnostics(diagnostics));
+    }
+
+    let scope = ddl_rt::ReadScope::new(data);
+    let term = binary::read::read_module_item(&core_module, item_name, &mut scope.reader())?;
+
+    Ok(match format {
+        OutputFormat::Tree => term.display_tree().to_string(),
+        OutputFormat::Json => term.display_json().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAIR_SOURCE: &str = "
+        struct Point { x : U8, y : U8 }
+        struct Pair { first : Point, second : U16Be }
+    ";
+    const PAIR_DATA: &[u8] = &[1, 2, 0x01, 0x00];
+
+    #[test]
+    fn pair_tree() {
+        let mut files = Files::new();
+        let file_id = files.add("pair.ddl", PAIR_SOURCE);
+
+        assert_eq!(
+            parse_and_render(&files, file_id, "Pair", PAIR_DATA, OutputFormat::Tree).unwrap(),
+            "first:\n    x: 1\n    y: 2\nsecond: 256",
+        );
+    }
+
+    #[test]
+    fn pair_json() {
+        let mut files = Files::new();
+        let file_id = files.add("pair.ddl", PAIR_SOURCE);
+
+        assert_eq!(
+            parse_and_render(&files, file_id, "Pair", PAIR_DATA, OutputFormat::Json).unwrap(),
+            r#"{"first":{"x":1,"y":2},"second":256}"#,
+        );
+    }
+
+    #[test]
+    fn invalid_description() {
+        let mut files = Files::new();
+        let file_id = files.add("pair.ddl", "struct Pair { first : Unknown }");
+
+        match parse_and_render(&files, file_id, "Pair", PAIR_DATA, OutputFormat::Tree) {
+            Err(DumpError::Diagnostics(diagnostics)) => assert_eq!(diagnostics.len(), 1),
+            result => panic!("diagnostics expected, found: {:?}", result),
+        }
+    }
+}
@@ -2,11 +2,21 @@
 //!
 //! This is only a naive implementation, and intended for getting a better idea
 //! of whether our compiled back-ends actually meet the specification.
+//!
+//! There is no way to select Cargo features here, so fields marked with
+//! `@cfg(...)` are interpreted as if every feature were enabled.
 
 use num_bigint::BigInt;
 use std::collections::BTreeMap;
+use std::fmt;
 
+mod convert;
+pub mod diff;
+pub mod dump;
 pub mod read;
+pub mod write;
+
+pub use self::convert::ConversionError;
 
 /// Terms that can be produced as a result of reading a binary file, or used as
 /// a source from which to write binary data.
@@ -18,6 +28,119 @@ pub enum Term {
     F32(f32),
     /// IEEE-754 double-precision floating point numbers.
     F64(f64),
+    /// Raw bytes, as captured by the rest of a structure.
+    Bytes(Vec<u8>),
     /// Structure values
     Struct(BTreeMap<String, Term>),
 }
+
+impl Term {
+    /// Display the term as an indented tree of fields, for debugging output.
+    pub fn display_tree(&self) -> DisplayTree<'_> {
+        DisplayTree(self)
+    }
+
+    /// Display the term as JSON, with structures rendered as objects.
+    ///
+    /// Floating point numbers that can't be represented in JSON, like
+    /// infinities and NaNs, are rendered as `null`.
+    pub fn display_json(&self) -> DisplayJson<'_> {
+        DisplayJson(self)
+    }
+}
+
+/// An indented tree rendering of a term, returned by [`Term::display_tree`].
+///
+/// [`Term::display_tree`]: enum.Term.html#method.display_tree
+pub struct DisplayTree<'term>(&'term Term);
+
+impl fmt::Display for DisplayTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Term::Struct(fields) if !fields.is_empty() => fmt_tree_fields(f, fields, 0),
+            term => fmt_tree_leaf(f, term),
+        }
+    }
+}
+
+fn fmt_tree_fields(
+    f: &mut fmt::Formatter<'_>,
+    fields: &BTreeMap<String, Term>,
+    depth: usize,
+) -> fmt::Result {
+    for (index, (name, term)) in fields.iter().enumerate() {
+        if index > 0 || depth > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "{:indent$}{}:", "", name, indent = depth * 4)?;
+        match term {
+            Term::Struct(fields) if !fields.is_empty() => fmt_tree_fields(f, fields, depth + 1)?,
+            term => {
+                write!(f, " ")?;
+                fmt_tree_leaf(f, term)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn fmt_tree_leaf(f: &mut fmt::Formatter<'_>, term: &Term) -> fmt::Result {
+    match term {
+        Term::Int(value) => write!(f, "{}", value),
+        Term::F32(value) => write!(f, "{:?}", value),
+        Term::F64(value) => write!(f, "{:?}", value),
+        Term::Bytes(bytes) => write!(f, "{:?}", bytes),
+        Term::Struct(_) => write!(f, "{{}}"),
+    }
+}
+
+/// A JSON rendering of a term, returned by [`Term::display_json`].
+///
+/// [`Term::display_json`]: enum.Term.html#method.display_json
+pub struct DisplayJson<'term>(&'term Term);
+
+impl fmt::Display for DisplayJson<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Term::Int(value) => write!(f, "{}", value),
+            Term::F32(value) if value.is_finite() => write!(f, "{:?}", value),
+            Term::F64(value) if value.is_finite() => write!(f, "{:?}", value),
+            Term::F32(_) | Term::F64(_) => write!(f, "null"),
+            Term::Bytes(bytes) => {
+                write!(f, "[")?;
+                for (index, byte) in bytes.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", byte)?;
+                }
+                write!(f, "]")
+            }
+            Term::Struct(fields) => {
+                write!(f, "{{")?;
+                for (index, (name, term)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    fmt_json_string(f, name)?;
+                    write!(f, ":{}", term.display_json())?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn fmt_json_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for ch in value.chars() {
+        match ch {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            ch if ch.is_control() => write!(f, "\\u{:04x}", ch as u32)?,
+            ch => write!(f, "{}", ch)?,
+        }
+    }
+    write!(f, "\"")
+}
@@ -1,12 +1,12 @@
 use num_bigint::BigInt;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::binary::Term;
 use crate::core;
 
-/// Contextual information to be used when parsing items.
+/// Contextual information to be used when reading or writing items.
 pub struct ItemContext<'module> {
-    items: HashMap<core::Label, &'module core::Item>,
+    pub(crate) items: HashMap<core::Label, &'module core::Item>,
 }
 
 impl<'module> ItemContext<'module> {
@@ -16,84 +16,567 @@ impl<'module> ItemContext<'module> {
             items: HashMap::new(),
         }
     }
+
+    /// Evaluate the condition of a conditional format, unfolding any aliases
+    /// that it refers to. Returns `None` if the condition does not evaluate
+    /// to a boolean constant.
+    pub(crate) fn eval_condition(&self, term: &core::Term) -> Option<bool> {
+        match core::semantics::eval(term) {
+            core::Value::BoolConst(value) => Some(value),
+            core::Value::Neutral(core::Head::Item(label), elims) if elims.is_empty() => {
+                match self.items.get(&label) {
+                    Some(core::Item::Alias(alias)) => self.eval_condition(&alias.term),
+                    Some(core::Item::Struct(_)) | Some(core::Item::Enum(_)) | None => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Receives the values of an item as they are read, in the order that they
+/// appear in the data.
+///
+/// This is an alternative to building up a `Term`, for callers that want to
+/// store the values in their own data structures.
+pub trait ReadSink {
+    /// Called before the fields of a structure are read.
+    fn enter_struct(&mut self);
+    /// Called after the fields of a structure have been read.
+    fn exit_struct(&mut self);
+    /// Called before the value of a field is read.
+    fn field(&mut self, name: &str);
+    /// Called with every value that is not a structure.
+    fn value(&mut self, value: Term);
+}
+
+/// A sink that builds up a `Term` from the values that are read.
+#[derive(Debug, Default)]
+pub struct TermBuilder {
+    structs: Vec<BTreeMap<String, Term>>,
+    names: Vec<String>,
+    term: Option<Term>,
+}
+
+impl TermBuilder {
+    /// Create a new term builder.
+    pub fn new() -> TermBuilder {
+        TermBuilder::default()
+    }
+
+    /// The term that was read, if a complete value was received.
+    pub fn finish(self) -> Option<Term> {
+        self.term
+    }
+
+    fn push_term(&mut self, term: Term) {
+        match self.structs.last_mut() {
+            Some(fields) => {
+                if let Some(name) = self.names.pop() {
+                    fields.insert(name, term);
+                }
+            }
+            None => self.term = Some(term),
+        }
+    }
+}
+
+impl ReadSink for TermBuilder {
+    fn enter_struct(&mut self) {
+        self.structs.push(BTreeMap::new());
+    }
+
+    fn exit_struct(&mut self) {
+        if let Some(fields) = self.structs.pop() {
+            self.push_term(Term::Struct(fields));
+        }
+    }
+
+    fn field(&mut self, name: &str) {
+        self.names.push(name.to_owned());
+    }
+
+    fn value(&mut self, value: Term) {
+        self.push_term(value);
+    }
+}
+
+/// Run a read that feeds a sink, collecting the values into a `Term`.
+fn build_term(
+    read: impl FnOnce(&mut dyn ReadSink) -> Result<(), ddl_rt::ReadError>,
+) -> Result<Term, ddl_rt::ReadError> {
+    let mut builder = TermBuilder::new();
+    read(&mut builder)?;
+    builder
+        .finish()
+        .ok_or(ddl_rt::ReadError::InvalidDataDescription)
 }
 
+/// Find the item with the given name, along with the items that it can refer to.
+fn lookup_module_item<'module>(
+    module: &'module core::Module,
+    name: &str,
+) -> Option<(ItemContext<'module>, &'module core::Item)> {
+    let mut context = ItemContext::new();
+
+    for item in &module.items {
+        if item.name().0 == name {
+            return Some((context, item));
+        }
+        context.items.insert(item.name().clone(), item);
+    }
+
+    None
+}
+
+/// Read an item from the current position of the reader.
+///
+/// On success the reader is left just past the end of the item, so
+/// `reader.offset()` gives the position of any data that follows it.
 pub fn read_module_item(
     module: &core::Module,
     name: &str,
     reader: &mut ddl_rt::FormatReader<'_>,
 ) -> Result<Term, ddl_rt::ReadError> {
-    let mut context = ItemContext::new();
+    build_term(|sink| read_module_item_with(module, name, reader, sink))
+}
 
-    for item in &module.items {
-        match item {
-            core::Item::Alias(alias) if alias.name.0 == name => {
-                return read_ty(&context, &alias.term, reader);
-            }
-            core::Item::Struct(struct_ty) if struct_ty.name.0 == name => {
-                return read_struct_ty(&context, struct_ty, reader);
-            }
-            core::Item::Alias(alias) => {
-                context.items.insert(alias.name.clone(), item);
-            }
-            core::Item::Struct(struct_ty) => {
-                context.items.insert(struct_ty.name.clone(), item);
-            }
+/// Read an item that is expected to take up all of the remaining data.
+///
+/// This fails with `ReadError::ExpectedEof` if any data is left over after
+/// the item, which usually means that the data description only covers part
+/// of the format.
+pub fn read_module_item_exhaustive(
+    module: &core::Module,
+    name: &str,
+    reader: &mut ddl_rt::FormatReader<'_>,
+) -> Result<Term, ddl_rt::ReadError> {
+    let term = read_module_item(module, name, reader)?;
+    reader.check_eof()?;
+    Ok(term)
+}
+
+/// Read an item, passing the values to `sink` as they are read instead of
+/// building up a `Term`.
+pub fn read_module_item_with(
+    module: &core::Module,
+    name: &str,
+    reader: &mut ddl_rt::FormatReader<'_>,
+    sink: &mut dyn ReadSink,
+) -> Result<(), ddl_rt::ReadError> {
+    match lookup_module_item(module, name) {
+        Some((context, core::Item::Alias(alias))) => {
+            read_ty_with(&context, &alias.term, reader, sink)
+        }
+        Some((context, core::Item::Struct(struct_ty))) => {
+            read_struct_ty_with(&context, struct_ty, reader, sink)
+        }
+        Some((context, core::Item::Enum(enum_ty))) => {
+            read_enum_ty_with(&context, enum_ty, reader, sink)
+        }
+        None => Err(ddl_rt::ReadError::InvalidDataDescription),
+    }
+}
+
+/// Read an item, consuming at most `max_bytes` of the data.
+///
+/// Nested structures share the same limit, and reads that would run past it
+/// fail with `ReadError::SizeLimitExceeded`. This is useful for reading an
+/// item that is embedded in a larger buffer.
+pub fn read_module_item_bounded(
+    module: &core::Module,
+    name: &str,
+    reader: &mut ddl_rt::FormatReader<'_>,
+    max_bytes: usize,
+) -> Result<Term, ddl_rt::ReadError> {
+    reader.read_bounded_with(max_bytes, |reader| read_module_item(module, name, reader))
+}
+
+/// Read an item from an asynchronous source of data.
+///
+/// The bytes of the item are buffered before being read in the same way as
+/// `read_module_item`. If the size of the item is not known ahead of time,
+/// for example if it contains null-terminated strings or defaulted fields, or
+/// if it is a struct that ends with `eof`, the source is read until it is
+/// exhausted.
+#[cfg(feature = "futures")]
+pub async fn read_module_item_async<R>(
+    module: &core::Module,
+    name: &str,
+    reader: &mut R,
+) -> Result<Term, ddl_rt::ReadError>
+where
+    R: futures::io::AsyncRead + Unpin,
+{
+    use futures::io::AsyncReadExt;
+    use std::io;
+
+    let (context, item) = match lookup_module_item(module, name) {
+        Some(found) => found,
+        None => return Err(ddl_rt::ReadError::InvalidDataDescription),
+    };
+
+    let size = match item {
+        core::Item::Struct(struct_ty) if struct_ty.eof => None,
+        core::Item::Alias(alias) => ty_size(&context, &alias.term),
+        core::Item::Struct(struct_ty) => struct_ty_size(&context, struct_ty),
+        core::Item::Enum(enum_ty) => ty_size(&context, &enum_ty.repr),
+    };
+
+    let mut buffer = Vec::new();
+    let result = match size {
+        Some(size) => {
+            buffer.resize(size, 0);
+            reader.read_exact(&mut buffer).await
         }
+        None => reader.read_to_end(&mut buffer).await.map(|_| ()),
+    };
+
+    match result {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => {
+            return Err(ddl_rt::ReadError::Eof(ddl_rt::ReadEofError {}));
+        }
+        Err(error) => return Err(ddl_rt::ReadError::Io(error)),
     }
 
-    Err(ddl_rt::ReadError::InvalidDataDescription)
+    let mut reader = ddl_rt::ReadScope::new(&buffer).reader();
+    match item {
+        core::Item::Alias(alias) => read_ty(&context, &alias.term, &mut reader),
+        core::Item::Struct(struct_ty) => read_struct_ty(&context, struct_ty, &mut reader),
+        core::Item::Enum(enum_ty) => {
+            build_term(|sink| read_enum_ty_with(&context, enum_ty, &mut reader, sink))
+        }
+    }
 }
 
+/// Read a struct from the current position of the reader.
+///
+/// Fields gated behind a feature with `@cfg` are always read, matching code
+/// that was compiled with all features enabled.
 pub fn read_struct_ty(
     context: &ItemContext<'_>,
     struct_ty: &core::StructType,
     reader: &mut ddl_rt::FormatReader<'_>,
 ) -> Result<Term, ddl_rt::ReadError> {
-    let fields = struct_ty
+    build_term(|sink| read_struct_ty_with(context, struct_ty, reader, sink))
+}
+
+fn read_struct_ty_with(
+    context: &ItemContext<'_>,
+    struct_ty: &core::StructType,
+    reader: &mut ddl_rt::FormatReader<'_>,
+    sink: &mut dyn ReadSink,
+) -> Result<(), ddl_rt::ReadError> {
+    sink.enter_struct();
+    for field in &struct_ty.fields {
+        if field.reserved {
+            build_term(|sink| read_field_with(context, field, reader, sink))?;
+            continue;
+        }
+        sink.field(&field.name.0);
+        read_field_with(context, field, reader, sink)?;
+    }
+    if let Some(rest) = &struct_ty.rest {
+        sink.field(&rest.0);
+        sink.value(Term::Bytes(reader.read_remaining().to_vec()));
+    }
+    if struct_ty.eof {
+        reader.check_eof()?;
+    }
+    sink.exit_struct();
+
+    Ok(())
+}
+
+fn read_field_with(
+    context: &ItemContext<'_>,
+    field: &core::TypeField,
+    reader: &mut ddl_rt::FormatReader<'_>,
+    sink: &mut dyn ReadSink,
+) -> Result<(), ddl_rt::ReadError> {
+    match &field.magic {
+        None => read_field_value_with(context, field, reader, sink),
+        Some(magic) => {
+            let term = build_term(|sink| read_field_value_with(context, field, reader, sink))?;
+            check_magic(field, magic, &term, reader)?;
+            sink.value(term);
+            Ok(())
+        }
+    }
+}
+
+fn read_field_value_with(
+    context: &ItemContext<'_>,
+    field: &core::TypeField,
+    reader: &mut ddl_rt::FormatReader<'_>,
+    sink: &mut dyn ReadSink,
+) -> Result<(), ddl_rt::ReadError> {
+    // Alignment is checked before falling back to the default, in the same
+    // order as the compiled Rust code
+    if let Some(align) = field.align {
+        reader.check_aligned(&field.name.0, align as usize)?;
+    }
+    if let (Some(default), Ok(())) = (&field.default, reader.check_eof()) {
+        sink.value(read_default(default)?);
+        return Ok(());
+    }
+    read_ty_with(context, &field.term, reader, sink)
+}
+
+/// Read a single field of a struct item, without decoding the fields before
+/// it where possible.
+///
+/// Preceding fields are skipped over if their sizes are known ahead of time,
+/// and they are not aligned and have no default values or magic numbers. The reader is then
+/// left just after the field. Otherwise this falls back to reading the whole
+/// struct, leaving the reader at the end of the struct.
+pub fn read_module_item_field(
+    module: &core::Module,
+    name: &str,
+    field_name: &str,
+    reader: &mut ddl_rt::FormatReader<'_>,
+) -> Result<Term, ddl_rt::ReadError> {
+    let (context, struct_ty) = match lookup_module_item(module, name) {
+        Some((context, core::Item::Struct(struct_ty))) => (context, struct_ty),
+        Some((_, core::Item::Alias(_))) | Some((_, core::Item::Enum(_))) | None => {
+            return Err(ddl_rt::ReadError::InvalidDataDescription);
+        }
+    };
+    let index = match struct_ty
         .fields
         .iter()
-        .map(|field| Ok((field.name.0.clone(), read_ty(context, &field.term, reader)?)))
-        .collect::<Result<_, ddl_rt::ReadError>>()?;
+        .position(|field| field.name.0 == field_name && !field.reserved)
+    {
+        Some(index) => index,
+        None if struct_ty.rest.iter().any(|rest| rest.0 == field_name) => struct_ty.fields.len(),
+        None => return Err(ddl_rt::ReadError::InvalidDataDescription),
+    };
 
-    Ok(Term::Struct(fields))
+    let offset = struct_ty.fields[..index]
+        .iter()
+        .try_fold(0usize, |offset, field| match field {
+            core::TypeField {
+                align: None,
+                default: None,
+                magic: None,
+                ..
+            } => offset.checked_add(ty_size(&context, &field.term)?),
+            _ => None,
+        });
+
+    match offset {
+        Some(offset) if index == struct_ty.fields.len() => {
+            reader.skip(offset)?;
+            Ok(Term::Bytes(reader.read_remaining().to_vec()))
+        }
+        Some(offset) => {
+            reader.skip(offset)?;
+            build_term(|sink| read_field_with(&context, &struct_ty.fields[index], reader, sink))
+        }
+        None => match read_struct_ty(&context, struct_ty, reader)? {
+            Term::Struct(mut fields) => fields
+                .remove(field_name)
+                .ok_or(ddl_rt::ReadError::InvalidDataDescription),
+            _ => Err(ddl_rt::ReadError::InvalidDataDescription),
+        },
+    }
 }
 
-pub fn read_ty(
+/// Read an enum, checking that the value of its representation is the
+/// discriminant of one of its variants.
+fn read_enum_ty_with(
     context: &ItemContext<'_>,
-    term: &core::Term,
+    enum_ty: &core::EnumType,
     reader: &mut ddl_rt::FormatReader<'_>,
-) -> Result<Term, ddl_rt::ReadError> {
+    sink: &mut dyn ReadSink,
+) -> Result<(), ddl_rt::ReadError> {
+    use num_traits::cast::ToPrimitive;
+
+    let found = match read_ty(context, &enum_ty.repr, reader)? {
+        Term::Int(found) => found,
+        _ => return Err(ddl_rt::ReadError::InvalidDataDescription),
+    };
+    let is_variant =
+        enum_ty
+            .variants
+            .iter()
+            .any(|variant| match core::semantics::eval(&variant.value) {
+                core::Value::IntConst(value) => value == found,
+                _ => false,
+            });
+
+    match is_variant {
+        true => {
+            sink.value(Term::Int(found));
+            Ok(())
+        }
+        false => Err(ddl_rt::ReadError::UnknownDiscriminant {
+            ty: enum_ty.name.0.clone(),
+            value: found
+                .to_i128()
+                .ok_or(ddl_rt::ReadError::InvalidDataDescription)?,
+        }),
+    }
+}
+
+/// Evaluate the default value of a field, for use when the data ends before
+/// the field.
+fn read_default(default: &core::Term) -> Result<Term, ddl_rt::ReadError> {
+    match core::semantics::eval(default) {
+        core::Value::IntConst(value) => Ok(Term::Int(value)),
+        core::Value::F32Const(value) => Ok(Term::F32(value)),
+        core::Value::F64Const(value) => Ok(Term::F64(value)),
+        _ => Err(ddl_rt::ReadError::InvalidDataDescription),
+    }
+}
+
+/// Check that the value read for a field is its magic number.
+fn check_magic(
+    field: &core::TypeField,
+    magic: &core::Term,
+    term: &Term,
+    reader: &ddl_rt::FormatReader<'_>,
+) -> Result<(), ddl_rt::ReadError> {
+    use num_traits::cast::ToPrimitive;
+
+    match (core::semantics::eval(magic), term) {
+        (core::Value::IntConst(expected), Term::Int(found)) => {
+            match (expected.to_u64(), found.to_u64()) {
+                (Some(expected), Some(found)) => reader.check_magic(&field.name.0, expected, found),
+                (_, _) => Err(ddl_rt::ReadError::InvalidDataDescription),
+            }
+        }
+        (_, _) => Err(ddl_rt::ReadError::InvalidDataDescription),
+    }
+}
+
+/// The number of bytes occupied by a struct, if it can be determined without
+/// reading any data.
+///
+/// Structs with defaulted fields have no fixed size, because the data may end
+/// before those fields.
+pub fn struct_ty_size(context: &ItemContext<'_>, struct_ty: &core::StructType) -> Option<usize> {
+    if struct_ty.rest.is_some() || struct_ty.fields.iter().any(|field| field.default.is_some()) {
+        return None;
+    }
+    struct_ty.fields.iter().try_fold(0usize, |size, field| {
+        size.checked_add(ty_size(context, &field.term)?)
+    })
+}
+
+/// The number of bytes occupied by a format type, if it can be determined
+/// without reading any data.
+pub fn ty_size(context: &ItemContext<'_>, term: &core::Term) -> Option<usize> {
     match term {
-        core::Term::Item(_, label) => match context.items.get(label) {
-            Some(core::Item::Alias(alias)) => read_ty(&context, &alias.term, reader),
-            Some(core::Item::Struct(struct_ty)) => read_struct_ty(&context, struct_ty, reader),
-            None => Err(ddl_rt::ReadError::InvalidDataDescription),
+        core::Term::Item(_, label) => match context.items.get(label)? {
+            core::Item::Alias(alias) => ty_size(context, &alias.term),
+            core::Item::Struct(struct_ty) => struct_ty_size(context, struct_ty),
+            core::Item::Enum(enum_ty) => ty_size(context, &enum_ty.repr),
         },
-        core::Term::Ann(term, _) => read_ty(context, term, reader),
-        core::Term::U8Type(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::U8>()?))),
-        core::Term::U16LeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::U16Le>()?))),
-        core::Term::U16BeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::U16Be>()?))),
-        core::Term::U32LeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::U32Le>()?))),
-        core::Term::U32BeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::U32Be>()?))),
-        core::Term::U64LeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::U64Le>()?))),
-        core::Term::U64BeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::U64Be>()?))),
-        core::Term::S8Type(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::I8>()?))),
-        core::Term::S16LeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::I16Le>()?))),
-        core::Term::S16BeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::I16Be>()?))),
-        core::Term::S32LeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::I32Le>()?))),
-        core::Term::S32BeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::I32Be>()?))),
-        core::Term::S64LeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::I64Le>()?))),
-        core::Term::S64BeType(_) => Ok(Term::Int(BigInt::from(reader.read::<ddl_rt::I64Be>()?))),
-        core::Term::F32LeType(_) => Ok(Term::F32(reader.read::<ddl_rt::F32Le>()?)),
-        core::Term::F32BeType(_) => Ok(Term::F32(reader.read::<ddl_rt::F32Be>()?)),
-        core::Term::F64LeType(_) => Ok(Term::F64(reader.read::<ddl_rt::F64Le>()?)),
-        core::Term::F64BeType(_) => Ok(Term::F64(reader.read::<ddl_rt::F64Be>()?)),
-        core::Term::BoolElim(_, term, if_true, if_false) => match core::semantics::eval(term) {
-            core::Value::BoolConst(true) => read_ty(context, if_true, reader),
-            core::Value::BoolConst(false) => read_ty(context, if_false, reader),
-            _ => Err(ddl_rt::ReadError::InvalidDataDescription),
+        core::Term::Ann(term, _) => ty_size(context, term),
+        core::Term::U8Type(_) | core::Term::S8Type(_) => Some(1),
+        core::Term::U16LeType(_)
+        | core::Term::U16BeType(_)
+        | core::Term::S16LeType(_)
+        | core::Term::S16BeType(_)
+        | core::Term::F16LeType(_)
+        | core::Term::F16BeType(_) => Some(2),
+        core::Term::U32LeType(_)
+        | core::Term::U32BeType(_)
+        | core::Term::S32LeType(_)
+        | core::Term::S32BeType(_)
+        | core::Term::F32LeType(_)
+        | core::Term::F32BeType(_) => Some(4),
+        core::Term::U64LeType(_)
+        | core::Term::U64BeType(_)
+        | core::Term::S64LeType(_)
+        | core::Term::S64BeType(_)
+        | core::Term::F64LeType(_)
+        | core::Term::F64BeType(_) => Some(8),
+        core::Term::U128LeType(_)
+        | core::Term::U128BeType(_)
+        | core::Term::S128LeType(_)
+        | core::Term::S128BeType(_) => Some(16),
+        core::Term::BoolElim(_, term, if_true, if_false) => match context.eval_condition(term) {
+            Some(true) => ty_size(context, if_true),
+            Some(false) => ty_size(context, if_false),
+            None => None,
         },
+        core::Term::CStrType(_) => None,
+        core::Term::Universe(_, _)
+        | core::Term::BoolType(_)
+        | core::Term::IntType(_)
+        | core::Term::F32Type(_)
+        | core::Term::F64Type(_)
+        | core::Term::BoolConst(_, _)
+        | core::Term::IntConst(_, _)
+        | core::Term::F32Const(_, _)
+        | core::Term::F64Const(_, _)
+        | core::Term::Error(_) => None,
+    }
+}
+
+pub fn read_ty(
+    context: &ItemContext<'_>,
+    term: &core::Term,
+    reader: &mut ddl_rt::FormatReader<'_>,
+) -> Result<Term, ddl_rt::ReadError> {
+    build_term(|sink| read_ty_with(context, term, reader, sink))
+}
+
+fn read_ty_with(
+    context: &ItemContext<'_>,
+    term: &core::Term,
+    reader: &mut ddl_rt::FormatReader<'_>,
+    sink: &mut dyn ReadSink,
+) -> Result<(), ddl_rt::ReadError> {
+    let value = match term {
+        core::Term::Item(_, label) => {
+            return match context.items.get(label) {
+                Some(core::Item::Alias(alias)) => read_ty_with(context, &alias.term, reader, sink),
+                Some(core::Item::Struct(struct_ty)) => {
+                    read_struct_ty_with(context, struct_ty, reader, sink)
+                }
+                Some(core::Item::Enum(enum_ty)) => {
+                    read_enum_ty_with(context, enum_ty, reader, sink)
+                }
+                None => Err(ddl_rt::ReadError::InvalidDataDescription),
+            };
+        }
+        core::Term::Ann(term, _) => return read_ty_with(context, term, reader, sink),
+        core::Term::U8Type(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U8>()?)),
+        core::Term::U16LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U16Le>()?)),
+        core::Term::U16BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U16Be>()?)),
+        core::Term::U32LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U32Le>()?)),
+        core::Term::U32BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U32Be>()?)),
+        core::Term::U64LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U64Le>()?)),
+        core::Term::U64BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U64Be>()?)),
+        core::Term::U128LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U128Le>()?)),
+        core::Term::U128BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::U128Be>()?)),
+        core::Term::S8Type(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I8>()?)),
+        core::Term::S16LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I16Le>()?)),
+        core::Term::S16BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I16Be>()?)),
+        core::Term::S32LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I32Le>()?)),
+        core::Term::S32BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I32Be>()?)),
+        core::Term::S64LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I64Le>()?)),
+        core::Term::S64BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I64Be>()?)),
+        core::Term::S128LeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I128Le>()?)),
+        core::Term::S128BeType(_) => Term::Int(BigInt::from(reader.read::<ddl_rt::I128Be>()?)),
+        core::Term::F16LeType(_) => Term::F32(reader.read::<ddl_rt::F16Le>()?),
+        core::Term::F16BeType(_) => Term::F32(reader.read::<ddl_rt::F16Be>()?),
+        core::Term::F32LeType(_) => Term::F32(reader.read::<ddl_rt::F32Le>()?),
+        core::Term::F32BeType(_) => Term::F32(reader.read::<ddl_rt::F32Be>()?),
+        core::Term::F64LeType(_) => Term::F64(reader.read::<ddl_rt::F64Le>()?),
+        core::Term::F64BeType(_) => Term::F64(reader.read::<ddl_rt::F64Be>()?),
+        core::Term::CStrType(_) => Term::Bytes(reader.read::<ddl_rt::CStr>()?.into_bytes()),
+        core::Term::BoolElim(_, term, if_true, if_false) => {
+            return match context.eval_condition(term) {
+                Some(true) => read_ty_with(context, if_true, reader, sink),
+                Some(false) => read_ty_with(context, if_false, reader, sink),
+                None => Err(ddl_rt::ReadError::InvalidDataDescription),
+            };
+        }
         core::Term::Universe(_, _)
         | core::Term::BoolType(_)
         | core::Term::IntType(_)
@@ -103,6 +586,399 @@ pub fn read_ty(
         | core::Term::IntConst(_, _)
         | core::Term::F32Const(_, _)
         | core::Term::F64Const(_, _)
-        | core::Term::Error(_) => Err(ddl_rt::ReadError::InvalidDataDescription),
+        | core::Term::Error(_) => return Err(ddl_rt::ReadError::InvalidDataDescription),
+    };
+
+    sink.value(value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use codespan::Files;
+    #[cfg(feature = "futures")]
+    use futures::executor::block_on;
+    #[cfg(feature = "futures")]
+    use futures::io::Cursor;
+
+    use super::*;
+    use crate::lexer::{Lexer, CORE_KEYWORDS};
+
+    fn parse_module(source: &str) -> core::Module {
+        let mut files = Files::new();
+        let file_id = files.add("test", source);
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = core::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+        module
+    }
+
+    #[test]
+    fn read_struct_defaults() {
+        let module = parse_module(
+            "struct Record { id : U8, @default(int 1) flags : U8, @default(f64 0.5) scale : F64Le, }",
+        );
+        let read = |data: &[u8]| {
+            let mut reader = ddl_rt::ReadScope::new(data).reader();
+            read_module_item(&module, "Record", &mut reader)
+        };
+        let record = |flags: Term, scale: Term| {
+            Term::Struct(
+                vec![
+                    ("id".to_owned(), Term::Int(7.into())),
+                    ("flags".to_owned(), flags),
+                    ("scale".to_owned(), scale),
+                ]
+                .into_iter()
+                .collect(),
+            )
+        };
+
+        assert_eq!(
+            read(&[7]).unwrap(),
+            record(Term::Int(1.into()), Term::F64(0.5)),
+        );
+        assert_eq!(
+            read(&[7, 3]).unwrap(),
+            record(Term::Int(3.into()), Term::F64(0.5)),
+        );
+        match read(&[7, 3, 0]) {
+            Err(ddl_rt::ReadError::Eof(_)) => {}
+            result => panic!("expected end of file error, found: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn read_struct_magic() {
+        let module =
+            parse_module("struct Bitmap { @magic(int 16973) signature : U16Be, size : U8, }");
+        let read = |data: &[u8]| {
+            let mut reader = ddl_rt::ReadScope::new(data).reader();
+            read_module_item(&module, "Bitmap", &mut reader)
+        };
+
+        assert_eq!(
+            read(&[0x42, 0x4D, 7]).unwrap(),
+            Term::Struct(
+                vec![
+                    ("signature".to_owned(), Term::Int(0x424D.into())),
+                    ("size".to_owned(), Term::Int(7.into())),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        );
+        match read(&[0x89, 0x50, 7]) {
+            Err(ddl_rt::ReadError::UnexpectedMagic {
+                field,
+                expected: 0x424D,
+                found: 0x8950,
+            }) => assert_eq!(field, "signature"),
+            result => panic!("expected unexpected magic error, found: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn read_enum() {
+        let module = parse_module(
+            "enum Compression : U8 { None = int 0, Rle = int 1, } \
+             struct Header { compression : item Compression, }",
+        );
+        let read = |data: &[u8]| {
+            let mut reader = ddl_rt::ReadScope::new(data).reader();
+            read_module_item(&module, "Header", &mut reader)
+        };
+
+        assert_eq!(
+            read(&[1]).unwrap(),
+            Term::Struct(
+                vec![("compression".to_owned(), Term::Int(1.into()))]
+                    .into_iter()
+                    .collect(),
+            ),
+        );
+        match read(&[7]) {
+            Err(ddl_rt::ReadError::UnknownDiscriminant { ty, value: 7 }) => {
+                assert_eq!(ty, "Compression")
+            }
+            result => panic!("expected unknown discriminant error, found: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn read_struct_field() {
+        let module = parse_module(
+            "struct Record { a : U8, b : U16Be, c : U32Be, d : U64Be, } \
+             struct Aligned { a : U16Be, @aligned(2) b : U16Be, c : U8, }",
+        );
+        let data = [0xFF, 0x00, 0x01, 0x00, 0x00, 0x00, 0x2A];
+
+        // Only the first seven bytes of `Record` are present, so reading it
+        // eagerly would fail.
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let c = read_module_item_field(&module, "Record", "c", &mut reader).unwrap();
+        assert_eq!(c, Term::Int(42.into()));
+        assert_eq!(reader.offset(), 7);
+
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        match read_module_item_field(&module, "Record", "d", &mut reader) {
+            Err(ddl_rt::ReadError::Eof(_)) => {}
+            result => panic!("expected end of file error, found: {:?}", result),
+        }
+
+        // Fields after an aligned field fall back to reading the whole struct
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let c = read_module_item_field(&module, "Aligned", "c", &mut reader).unwrap();
+        assert_eq!(c, Term::Int(0.into()));
+        assert_eq!(reader.offset(), 5);
+    }
+
+    #[test]
+    fn read_struct_bounded() {
+        let module = parse_module(
+            "struct Point { x : U8, y : U8, } \
+             struct Line { start : item Point, end : item Point, }",
+        );
+        let data = [1, 2, 3, 4, 5];
+
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let point = |x: u8, y: u8| {
+            let fields = vec![
+                ("x".to_owned(), Term::Int(x.into())),
+                ("y".to_owned(), Term::Int(y.into())),
+            ];
+            Term::Struct(fields.into_iter().collect())
+        };
+        let expected = Term::Struct(
+            vec![
+                ("start".to_owned(), point(1, 2)),
+                ("end".to_owned(), point(3, 4)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(
+            read_module_item_bounded(&module, "Line", &mut reader, 4).unwrap(),
+            expected,
+        );
+        assert_eq!(reader.offset(), 4);
+
+        // The nested points share the limit of the line
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        match read_module_item_bounded(&module, "Line", &mut reader, 3) {
+            Err(ddl_rt::ReadError::SizeLimitExceeded { limit: 3 }) => {}
+            result => panic!("expected size limit error, found: {:?}", result),
+        }
+        assert_eq!(reader.offset(), 0);
+    }
+
+    #[test]
+    fn read_struct_trailing_data() {
+        let module = parse_module("struct Header { magic : U16Be, version : U8, }");
+        let data = [0xCA, 0xFE, 0x01, 0xAA, 0xBB];
+
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        read_module_item(&module, "Header", &mut reader).unwrap();
+        assert_eq!(reader.offset(), 3);
+        assert_eq!(reader.read_remaining(), &[0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn read_struct_exhaustive() {
+        let module = parse_module("struct Header { magic : U16Be, version : U8, }");
+
+        let mut reader = ddl_rt::ReadScope::new(&[0xCA, 0xFE, 0x01]).reader();
+        assert!(read_module_item_exhaustive(&module, "Header", &mut reader).is_ok());
+
+        let mut reader = ddl_rt::ReadScope::new(&[0xCA, 0xFE, 0x01, 0xAA, 0xBB]).reader();
+        match read_module_item_exhaustive(&module, "Header", &mut reader) {
+            Err(ddl_rt::ReadError::ExpectedEof { remaining: 2 }) => {}
+            result => panic!("expected eof error, found: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn read_struct_with_sink() {
+        struct FieldNames {
+            path: Vec<String>,
+            names: Vec<String>,
+        }
+
+        impl ReadSink for FieldNames {
+            fn enter_struct(&mut self) {}
+
+            fn exit_struct(&mut self) {
+                self.path.pop();
+            }
+
+            fn field(&mut self, name: &str) {
+                self.path.push(name.to_owned());
+                self.names.push(self.path.join("."));
+            }
+
+            fn value(&mut self, _: Term) {
+                self.path.pop();
+            }
+        }
+
+        let module = parse_module(
+            "struct Point { y : U8, x : U8, } \
+             struct Line { start : item Point, end : item Point, }",
+        );
+        let data = [1, 2, 3, 4];
+
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let mut sink = FieldNames {
+            path: Vec::new(),
+            names: Vec::new(),
+        };
+        read_module_item_with(&module, "Line", &mut reader, &mut sink).unwrap();
+        assert_eq!(
+            sink.names,
+            ["start", "start.y", "start.x", "end", "end.y", "end.x"],
+        );
+        assert!(sink.path.is_empty());
+    }
+
+    #[test]
+    fn read_struct_rest() {
+        let module = parse_module("struct Chunk { tag : U16Be, ...rest, }");
+        let data = [0x00, 0x07, 1, 2, 3, 4, 5];
+
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let expected = Term::Struct(
+            vec![
+                ("tag".to_owned(), Term::Int(7.into())),
+                ("rest".to_owned(), Term::Bytes(vec![1, 2, 3, 4, 5])),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(
+            read_module_item(&module, "Chunk", &mut reader).unwrap(),
+            expected
+        );
+        assert!(reader.check_eof().is_ok());
+
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let rest = read_module_item_field(&module, "Chunk", "rest", &mut reader).unwrap();
+        assert_eq!(rest, Term::Bytes(vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn read_struct_conditional_item() {
+        let module = parse_module(
+            "
+            is_be = true;
+            big_endian = item is_be;
+            struct Record { len : bool_elim item big_endian { U16Be, U32Le }, tag : U8, }
+            ",
+        );
+        let data = [0x01, 0x02, 0x03, 0x04];
+
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let record = read_module_item(&module, "Record", &mut reader).unwrap();
+        let expected = Term::Struct(
+            vec![
+                ("len".to_owned(), Term::Int(0x0102.into())),
+                ("tag".to_owned(), Term::Int(0x03.into())),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(record, expected);
+        assert_eq!(reader.offset(), 3);
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn read_struct_async() {
+        let module = parse_module(
+            "
+            struct Point { x : U16Be, y : U16Be, }
+            struct Record { tag : U8, point : item Point, }
+            ",
+        );
+        let data = [7, 0x01, 0x02, 0x03, 0x04, 0xFF];
+
+        let mut reader = Cursor::new(&data[..]);
+        let term = block_on(read_module_item_async(&module, "Record", &mut reader)).unwrap();
+        let expected = read_module_item(
+            &module,
+            "Record",
+            &mut ddl_rt::ReadScope::new(&data).reader(),
+        )
+        .unwrap();
+
+        assert_eq!(term, expected);
+        assert_eq!(reader.position(), 5);
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn read_struct_async_eof() {
+        let module = parse_module("struct Trailer { checksum : U16Le, eof, }");
+
+        let term = block_on(read_module_item_async(
+            &module,
+            "Trailer",
+            &mut Cursor::new(&[0x34, 0x12][..]),
+        ));
+        let expected = Term::Struct(
+            vec![("checksum".to_owned(), Term::Int(0x1234.into()))]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(term.unwrap(), expected);
+
+        match block_on(read_module_item_async(
+            &module,
+            "Trailer",
+            &mut Cursor::new(&[0x34, 0x12, 0x00][..]),
+        )) {
+            Err(ddl_rt::ReadError::ExpectedEof { remaining: 1 }) => {}
+            result => panic!("expected end of data error, found: {:?}", result),
+        }
+
+        match block_on(read_module_item_async(
+            &module,
+            "Trailer",
+            &mut Cursor::new(&[0x34][..]),
+        )) {
+            Err(ddl_rt::ReadError::Eof(_)) => {}
+            result => panic!("expected end of file error, found: {:?}", result),
+        }
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn read_struct_async_defaults() {
+        let module = parse_module("struct Record { id : U8, @default(int 1) flags : U8, }");
+        let read = |data: &[u8]| {
+            block_on(read_module_item_async(
+                &module,
+                "Record",
+                &mut Cursor::new(data),
+            ))
+        };
+        let record = |flags: u8| {
+            Term::Struct(
+                vec![
+                    ("id".to_owned(), Term::Int(7.into())),
+                    ("flags".to_owned(), Term::Int(flags.into())),
+                ]
+                .into_iter()
+                .collect(),
+            )
+        };
+
+        assert_eq!(read(&[7]).unwrap(), record(1));
+        assert_eq!(read(&[7, 3]).unwrap(), record(3));
+        match read(&[]) {
+            Err(ddl_rt::ReadError::Eof(_)) => {}
+            result => panic!("expected end of file error, found: {:?}", result),
+        }
     }
 }
@@ -0,0 +1,610 @@
+use num_bigint::BigInt;
+use num_traits::cast::ToPrimitive;
+use std::error::Error;
+use std::ffi::CString;
+use std::fmt;
+use std::slice;
+
+use crate::binary::read::{self, ItemContext};
+use crate::binary::Term;
+use crate::core;
+
+/// An error produced while writing binary data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WriteError {
+    /// Tried to write a portion of broken DDL.
+    InvalidDataDescription,
+    /// A field was supplied out of order, or was not present in the structure.
+    UnexpectedField {
+        expected: Option<String>,
+        found: String,
+    },
+    /// The encoder was finished before all of the fields were supplied.
+    MissingFields(Vec<String>),
+    /// The supplied term did not match the type it was to be written as.
+    TypeMismatch,
+    /// The supplied integer could not be represented in the format it was to
+    /// be written as.
+    IntOutOfRange(BigInt),
+    /// The supplied bytes contained a null byte, so could not be written as a
+    /// null-terminated string.
+    NullInString,
+    /// The supplied integer was not the discriminant of any of the variants
+    /// of the enum it was to be written as.
+    UnknownDiscriminant(BigInt),
+    /// The supplied integer was not the magic number that the field must
+    /// contain.
+    UnexpectedMagic {
+        field: String,
+        expected: BigInt,
+        found: BigInt,
+    },
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::InvalidDataDescription => {
+                write!(f, "attempted to write improperly specified data")
+            }
+            WriteError::UnexpectedField {
+                expected: Some(expected),
+                found,
+            } => write!(f, "expected field `{}`, found `{}`", expected, found),
+            WriteError::UnexpectedField {
+                expected: None,
+                found,
+            } => write!(f, "unexpected field `{}`", found),
+            WriteError::MissingFields(names) => {
+                write!(f, "missing fields: ")?;
+                for (i, name) in names.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{}`", name)?;
+                }
+                Ok(())
+            }
+            WriteError::TypeMismatch => write!(f, "term did not match the expected type"),
+            WriteError::IntOutOfRange(value) => {
+                write!(f, "integer `{}` is out of range for the format", value)
+            }
+            WriteError::NullInString => {
+                write!(f, "null-terminated string contains a null byte")
+            }
+            WriteError::UnknownDiscriminant(value) => {
+                write!(f, "integer `{}` is not a variant of the enum", value)
+            }
+            WriteError::UnexpectedMagic {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected magic number `{}` in field `{}`, found `{}`",
+                expected, field, found,
+            ),
+        }
+    }
+}
+
+impl Error for WriteError {}
+
+/// A streaming encoder for a structure type.
+///
+/// Fields are written to the underlying writer as soon as they are supplied,
+/// so the structure never needs to be held in memory as a whole.
+pub struct Encoder<'module> {
+    context: ItemContext<'module>,
+    fields: slice::Iter<'module, core::TypeField>,
+    rest: Option<&'module core::Label>,
+    writer: ddl_rt::FormatWriter,
+}
+
+impl<'module> Encoder<'module> {
+    /// Begin encoding the structure with the given name in `module`.
+    pub fn new(
+        module: &'module core::Module,
+        name: &str,
+        writer: ddl_rt::FormatWriter,
+    ) -> Result<Encoder<'module>, WriteError> {
+        let mut context = ItemContext::new();
+
+        for item in &module.items {
+            match item {
+                core::Item::Struct(struct_ty) if struct_ty.name.0 == name => {
+                    return Ok(Encoder {
+                        context,
+                        fields: struct_ty.fields.iter(),
+                        rest: struct_ty.rest.as_ref(),
+                        writer,
+                    });
+                }
+                core::Item::Alias(_) | core::Item::Enum(_) if item.name().0 == name => {
+                    return Err(WriteError::InvalidDataDescription);
+                }
+                core::Item::Alias(alias) => {
+                    context.items.insert(alias.name.clone(), item);
+                }
+                core::Item::Struct(struct_ty) => {
+                    context.items.insert(struct_ty.name.clone(), item);
+                }
+                core::Item::Enum(enum_ty) => {
+                    context.items.insert(enum_ty.name.clone(), item);
+                }
+            }
+        }
+
+        Err(WriteError::InvalidDataDescription)
+    }
+
+    /// Write the next field of the structure.
+    ///
+    /// Fields must be supplied in the order that they are declared, followed
+    /// by the rest of the structure, if it has one.
+    ///
+    /// If the field can't be written, any bytes that were written for it are
+    /// discarded, leaving the encoder ready for the field to be supplied again.
+    pub fn field(&mut self, name: &str, term: &Term) -> Result<(), WriteError> {
+        self.write_reserved_fields()?;
+        match (self.fields.as_slice().first(), self.rest) {
+            (Some(field), _) if field.name.0 == name => {
+                let len = self.writer.buffer().len();
+                if let Err(error) = write_field(&self.context, field, term, &mut self.writer) {
+                    self.writer.truncate(len);
+                    return Err(error);
+                }
+                self.fields.next();
+                Ok(())
+            }
+            (Some(field), _) => Err(WriteError::UnexpectedField {
+                expected: Some(field.name.0.clone()),
+                found: name.to_owned(),
+            }),
+            (None, Some(rest)) if rest.0 == name => {
+                write_rest(term, &mut self.writer)?;
+                self.rest = None;
+                Ok(())
+            }
+            (None, Some(rest)) => Err(WriteError::UnexpectedField {
+                expected: Some(rest.0.clone()),
+                found: name.to_owned(),
+            }),
+            (None, None) => Err(WriteError::UnexpectedField {
+                expected: None,
+                found: name.to_owned(),
+            }),
+        }
+    }
+
+    /// Write any reserved fields that come next, as they are never supplied.
+    fn write_reserved_fields(&mut self) -> Result<(), WriteError> {
+        while let Some(field) = self
+            .fields
+            .as_slice()
+            .first()
+            .filter(|field| field.reserved)
+        {
+            write_reserved(&self.context, field, &mut self.writer)?;
+            self.fields.next();
+        }
+        Ok(())
+    }
+
+    /// Finish encoding, returning the writer if all of the fields were supplied.
+    pub fn finish(mut self) -> Result<ddl_rt::FormatWriter, WriteError> {
+        self.write_reserved_fields()?;
+        let missing = self.fields.as_slice();
+        if missing.is_empty() && self.rest.is_none() {
+            Ok(self.writer)
+        } else {
+            let missing = missing.iter().filter(|field| !field.reserved);
+            let names = missing.map(|field| &field.name).chain(self.rest);
+            let names = names.map(|name| name.0.clone()).collect();
+            Err(WriteError::MissingFields(names))
+        }
+    }
+}
+
+pub fn write_module_item(
+    module: &core::Module,
+    name: &str,
+    term: &Term,
+    writer: &mut ddl_rt::FormatWriter,
+) -> Result<(), WriteError> {
+    let mut context = ItemContext::new();
+
+    for item in &module.items {
+        match item {
+            core::Item::Alias(alias) if alias.name.0 == name => {
+                return write_ty(&context, &alias.term, term, writer);
+            }
+            core::Item::Struct(struct_ty) if struct_ty.name.0 == name => {
+                return write_struct_ty(&context, struct_ty, term, writer);
+            }
+            core::Item::Enum(enum_ty) if enum_ty.name.0 == name => {
+                return write_enum_ty(&context, enum_ty, term, writer);
+            }
+            core::Item::Alias(alias) => {
+                context.items.insert(alias.name.clone(), item);
+            }
+            core::Item::Struct(struct_ty) => {
+                context.items.insert(struct_ty.name.clone(), item);
+            }
+            core::Item::Enum(enum_ty) => {
+                context.items.insert(enum_ty.name.clone(), item);
+            }
+        }
+    }
+
+    Err(WriteError::InvalidDataDescription)
+}
+
+/// Write a struct, taking the values of its fields from `term`.
+///
+/// Every field is expected to be present, including those that are gated
+/// behind a feature with `@cfg`.
+pub fn write_struct_ty(
+    context: &ItemContext<'_>,
+    struct_ty: &core::StructType,
+    term: &Term,
+    writer: &mut ddl_rt::FormatWriter,
+) -> Result<(), WriteError> {
+    let fields = match term {
+        Term::Struct(fields) => fields,
+        _ => return Err(WriteError::TypeMismatch),
+    };
+
+    let is_rest = |name: &String| struct_ty.rest.iter().any(|rest| rest.0 == *name);
+    let is_field = |name: &String| {
+        (struct_ty.fields.iter()).any(|field| field.name.0 == *name && !field.reserved)
+    };
+    if let Some(name) = fields.keys().find(|name| !is_field(name) && !is_rest(name)) {
+        return Err(WriteError::UnexpectedField {
+            expected: None,
+            found: name.clone(),
+        });
+    }
+
+    let missing = struct_ty
+        .fields
+        .iter()
+        .filter(|field| !field.reserved)
+        .map(|field| &field.name)
+        .chain(&struct_ty.rest)
+        .filter(|name| !fields.contains_key(&name.0))
+        .map(|name| name.0.clone())
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(WriteError::MissingFields(missing));
+    }
+
+    for field in &struct_ty.fields {
+        match field.reserved {
+            true => write_reserved(context, field, writer)?,
+            false => write_field(context, field, &fields[&field.name.0], writer)?,
+        }
+    }
+    if let Some(rest) = &struct_ty.rest {
+        write_rest(&fields[&rest.0], writer)?;
+    }
+
+    Ok(())
+}
+
+/// Write an enum, checking that the term is the discriminant of one of its
+/// variants.
+pub fn write_enum_ty(
+    context: &ItemContext<'_>,
+    enum_ty: &core::EnumType,
+    term: &Term,
+    writer: &mut ddl_rt::FormatWriter,
+) -> Result<(), WriteError> {
+    let value = match term {
+        Term::Int(value) => value,
+        _ => return Err(WriteError::TypeMismatch),
+    };
+    let is_variant =
+        enum_ty
+            .variants
+            .iter()
+            .any(|variant| match core::semantics::eval(&variant.value) {
+                core::Value::IntConst(discriminant) => discriminant == *value,
+                _ => false,
+            });
+
+    match is_variant {
+        true => write_ty(context, &enum_ty.repr, term, writer),
+        false => Err(WriteError::UnknownDiscriminant(value.clone())),
+    }
+}
+
+/// Write a field of a struct, checking that the term is its magic number if
+/// it has one.
+fn write_field(
+    context: &ItemContext<'_>,
+    field: &core::TypeField,
+    term: &Term,
+    writer: &mut ddl_rt::FormatWriter,
+) -> Result<(), WriteError> {
+    if let Some(magic) = &field.magic {
+        check_magic(field, magic, term)?;
+    }
+    write_ty(context, &field.term, term, writer)
+}
+
+/// Check that the value supplied for a field is its magic number.
+fn check_magic(field: &core::TypeField, magic: &core::Term, term: &Term) -> Result<(), WriteError> {
+    let expected = magic_value(magic)?;
+    let found = match term {
+        Term::Int(found) => found,
+        _ => return Err(WriteError::TypeMismatch),
+    };
+    if expected != *found {
+        return Err(WriteError::UnexpectedMagic {
+            field: field.name.0.clone(),
+            expected,
+            found: found.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Evaluate the magic number of a field.
+fn magic_value(magic: &core::Term) -> Result<BigInt, WriteError> {
+    match core::semantics::eval(magic) {
+        core::Value::IntConst(value) => Ok(value),
+        _ => Err(WriteError::InvalidDataDescription),
+    }
+}
+
+/// Fill a reserved field with its magic number if it has one, or with zeros
+/// otherwise.
+fn write_reserved(
+    context: &ItemContext<'_>,
+    field: &core::TypeField,
+    writer: &mut ddl_rt::FormatWriter,
+) -> Result<(), WriteError> {
+    if let Some(magic) = &field.magic {
+        return write_ty(
+            context,
+            &field.term,
+            &Term::Int(magic_value(magic)?),
+            writer,
+        );
+    }
+    let size = read::ty_size(context, &field.term).ok_or(WriteError::InvalidDataDescription)?;
+    for _ in 0..size {
+        writer.write_u8(0);
+    }
+    Ok(())
+}
+
+/// Write the bytes captured by the rest of a structure.
+fn write_rest(term: &Term, writer: &mut ddl_rt::FormatWriter) -> Result<(), WriteError> {
+    match term {
+        Term::Bytes(bytes) => {
+            for byte in bytes {
+                writer.write_u8(*byte);
+            }
+            Ok(())
+        }
+        _ => Err(WriteError::TypeMismatch),
+    }
+}
+
+pub fn write_ty(
+    context: &ItemContext<'_>,
+    ty: &core::Term,
+    term: &Term,
+    writer: &mut ddl_rt::FormatWriter,
+) -> Result<(), WriteError> {
+    match (ty, term) {
+        (core::Term::Item(_, label), _) => match context.items.get(label) {
+            Some(core::Item::Alias(alias)) => write_ty(context, &alias.term, term, writer)?,
+            Some(core::Item::Struct(struct_ty)) => {
+                write_struct_ty(context, struct_ty, term, writer)?;
+            }
+            Some(core::Item::Enum(enum_ty)) => write_enum_ty(context, enum_ty, term, writer)?,
+            None => return Err(WriteError::InvalidDataDescription),
+        },
+        (core::Term::Ann(ty, _), _) => write_ty(context, ty, term, writer)?,
+        (core::Term::U8Type(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U8>(int_value(value, BigInt::to_u8)?);
+        }
+        (core::Term::U16LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U16Le>(int_value(value, BigInt::to_u16)?);
+        }
+        (core::Term::U16BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U16Be>(int_value(value, BigInt::to_u16)?);
+        }
+        (core::Term::U32LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U32Le>(int_value(value, BigInt::to_u32)?);
+        }
+        (core::Term::U32BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U32Be>(int_value(value, BigInt::to_u32)?);
+        }
+        (core::Term::U64LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U64Le>(int_value(value, BigInt::to_u64)?);
+        }
+        (core::Term::U64BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U64Be>(int_value(value, BigInt::to_u64)?);
+        }
+        (core::Term::U128LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U128Le>(int_value(value, BigInt::to_u128)?);
+        }
+        (core::Term::U128BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::U128Be>(int_value(value, BigInt::to_u128)?);
+        }
+        (core::Term::S8Type(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I8>(int_value(value, BigInt::to_i8)?);
+        }
+        (core::Term::S16LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I16Le>(int_value(value, BigInt::to_i16)?);
+        }
+        (core::Term::S16BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I16Be>(int_value(value, BigInt::to_i16)?);
+        }
+        (core::Term::S32LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I32Le>(int_value(value, BigInt::to_i32)?);
+        }
+        (core::Term::S32BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I32Be>(int_value(value, BigInt::to_i32)?);
+        }
+        (core::Term::S64LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I64Le>(int_value(value, BigInt::to_i64)?);
+        }
+        (core::Term::S64BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I64Be>(int_value(value, BigInt::to_i64)?);
+        }
+        (core::Term::S128LeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I128Le>(int_value(value, BigInt::to_i128)?);
+        }
+        (core::Term::S128BeType(_), Term::Int(value)) => {
+            writer.write::<ddl_rt::I128Be>(int_value(value, BigInt::to_i128)?);
+        }
+        (core::Term::F16LeType(_), Term::F32(value)) => {
+            writer.write::<ddl_rt::F16Le>(*value);
+        }
+        (core::Term::F16BeType(_), Term::F32(value)) => {
+            writer.write::<ddl_rt::F16Be>(*value);
+        }
+        (core::Term::F32LeType(_), Term::F32(value)) => {
+            writer.write::<ddl_rt::F32Le>(*value);
+        }
+        (core::Term::F32BeType(_), Term::F32(value)) => {
+            writer.write::<ddl_rt::F32Be>(*value);
+        }
+        (core::Term::F64LeType(_), Term::F64(value)) => {
+            writer.write::<ddl_rt::F64Le>(*value);
+        }
+        (core::Term::F64BeType(_), Term::F64(value)) => {
+            writer.write::<ddl_rt::F64Be>(*value);
+        }
+        (core::Term::CStrType(_), Term::Bytes(bytes)) => {
+            let value = CString::new(bytes.as_slice()).map_err(|_| WriteError::NullInString)?;
+            writer.write::<ddl_rt::CStr>(value);
+        }
+        (core::Term::BoolElim(_, cond, if_true, if_false), _) => {
+            match context.eval_condition(cond) {
+                Some(true) => write_ty(context, if_true, term, writer)?,
+                Some(false) => write_ty(context, if_false, term, writer)?,
+                None => return Err(WriteError::InvalidDataDescription),
+            }
+        }
+        (core::Term::U8Type(_), _)
+        | (core::Term::U16LeType(_), _)
+        | (core::Term::U16BeType(_), _)
+        | (core::Term::U32LeType(_), _)
+        | (core::Term::U32BeType(_), _)
+        | (core::Term::U64LeType(_), _)
+        | (core::Term::U64BeType(_), _)
+        | (core::Term::U128LeType(_), _)
+        | (core::Term::U128BeType(_), _)
+        | (core::Term::S8Type(_), _)
+        | (core::Term::S16LeType(_), _)
+        | (core::Term::S16BeType(_), _)
+        | (core::Term::S32LeType(_), _)
+        | (core::Term::S32BeType(_), _)
+        | (core::Term::S64LeType(_), _)
+        | (core::Term::S64BeType(_), _)
+        | (core::Term::S128LeType(_), _)
+        | (core::Term::S128BeType(_), _)
+        | (core::Term::F16LeType(_), _)
+        | (core::Term::F16BeType(_), _)
+        | (core::Term::F32LeType(_), _)
+        | (core::Term::F32BeType(_), _)
+        | (core::Term::F64LeType(_), _)
+        | (core::Term::F64BeType(_), _)
+        | (core::Term::CStrType(_), _) => return Err(WriteError::TypeMismatch),
+        (core::Term::Universe(_, _), _)
+        | (core::Term::BoolType(_), _)
+        | (core::Term::IntType(_), _)
+        | (core::Term::F32Type(_), _)
+        | (core::Term::F64Type(_), _)
+        | (core::Term::BoolConst(_, _), _)
+        | (core::Term::IntConst(_, _), _)
+        | (core::Term::F32Const(_, _), _)
+        | (core::Term::F64Const(_, _), _)
+        | (core::Term::Error(_), _) => return Err(WriteError::InvalidDataDescription),
+    }
+
+    Ok(())
+}
+
+fn int_value<T>(value: &BigInt, convert: impl Fn(&BigInt) -> Option<T>) -> Result<T, WriteError> {
+    convert(value).ok_or_else(|| WriteError::IntOutOfRange(value.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use codespan::Files;
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::lexer::{Lexer, CORE_KEYWORDS};
+
+    fn parse_module(source: &str) -> core::Module {
+        let mut files = Files::new();
+        let file_id = files.add("test", source);
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = core::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+        module
+    }
+
+    #[test]
+    fn encode_field_error_discards_partial_write() {
+        let module = parse_module(
+            "struct Point { x : U8, y : U8, } struct Line { tag : U8, start : item Point, }",
+        );
+        let point = |x: i32, y: i32| {
+            let fields = vec![
+                ("x".to_owned(), Term::Int(x.into())),
+                ("y".to_owned(), Term::Int(y.into())),
+            ];
+            Term::Struct(fields.into_iter().collect::<BTreeMap<_, _>>())
+        };
+
+        let writer = ddl_rt::FormatWriter::new(vec![]);
+        let mut encoder = Encoder::new(&module, "Line", writer).unwrap();
+        encoder.field("tag", &Term::Int(1.into())).unwrap();
+        assert_eq!(
+            encoder.field("start", &point(2, 256)),
+            Err(WriteError::IntOutOfRange(256.into())),
+        );
+        encoder.field("start", &point(2, 3)).unwrap();
+        let writer = encoder.finish().unwrap();
+
+        assert_eq!(writer.buffer(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn write_struct_magic() {
+        let module = parse_module(
+            "struct Header { @magic(int 66) tag : U8, @reserved @magic(int 1) version : U8, size : U8, }",
+        );
+        let header = |tag: i32| {
+            let fields = vec![
+                ("tag".to_owned(), Term::Int(tag.into())),
+                ("size".to_owned(), Term::Int(7.into())),
+            ];
+            Term::Struct(fields.into_iter().collect::<BTreeMap<_, _>>())
+        };
+
+        let mut writer = ddl_rt::FormatWriter::new(vec![]);
+        write_module_item(&module, "Header", &header(66), &mut writer).unwrap();
+        assert_eq!(writer.buffer(), &[66, 1, 7]);
+
+        let mut writer = ddl_rt::FormatWriter::new(vec![]);
+        assert_eq!(
+            write_module_item(&module, "Header", &header(67), &mut writer),
+            Err(WriteError::UnexpectedMagic {
+                field: "tag".to_owned(),
+                expected: 66.into(),
+                found: 67.into(),
+            }),
+        );
+    }
+}
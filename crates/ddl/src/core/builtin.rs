@@ -0,0 +1,282 @@
+//! Built-in global names.
+//!
+//! This is the single list of names that are understood by the compiler
+//! without being defined in a module. Elaboration, delaboration, and the
+//! core syntax all consult it, so adding a primitive here makes it available
+//! everywhere at once.
+
+use codespan::Span;
+
+use crate::core::{Term, Universe};
+
+/// A built-in global.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Builtin {
+    Type,
+    Format,
+    Kind,
+    U8,
+    U16Le,
+    U16Be,
+    U32Le,
+    U32Be,
+    U64Le,
+    U64Be,
+    U128Le,
+    U128Be,
+    S8,
+    S16Le,
+    S16Be,
+    S32Le,
+    S32Be,
+    S64Le,
+    S64Be,
+    S128Le,
+    S128Be,
+    F16Le,
+    F16Be,
+    F32Le,
+    F32Be,
+    F64Le,
+    F64Be,
+    CStr,
+    Bool,
+    Int,
+    F32,
+    F64,
+    True,
+    False,
+}
+
+/// The sort of term that a built-in global refers to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BuiltinKind {
+    /// A universe of types.
+    Universe,
+    /// A binary format type.
+    Format,
+    /// A host type.
+    Type,
+    /// A host boolean constant.
+    BoolConst,
+}
+
+const BUILTINS: &[(&str, Builtin)] = &[
+    ("Type", Builtin::Type),
+    ("Format", Builtin::Format),
+    ("Kind", Builtin::Kind),
+    ("U8", Builtin::U8),
+    ("U16Le", Builtin::U16Le),
+    ("U16Be", Builtin::U16Be),
+    ("U32Le", Builtin::U32Le),
+    ("U32Be", Builtin::U32Be),
+    ("U64Le", Builtin::U64Le),
+    ("U64Be", Builtin::U64Be),
+    ("U128Le", Builtin::U128Le),
+    ("U128Be", Builtin::U128Be),
+    ("S8", Builtin::S8),
+    ("S16Le", Builtin::S16Le),
+    ("S16Be", Builtin::S16Be),
+    ("S32Le", Builtin::S32Le),
+    ("S32Be", Builtin::S32Be),
+    ("S64Le", Builtin::S64Le),
+    ("S64Be", Builtin::S64Be),
+    ("S128Le", Builtin::S128Le),
+    ("S128Be", Builtin::S128Be),
+    ("F16Le", Builtin::F16Le),
+    ("F16Be", Builtin::F16Be),
+    ("F32Le", Builtin::F32Le),
+    ("F32Be", Builtin::F32Be),
+    ("F64Le", Builtin::F64Le),
+    ("F64Be", Builtin::F64Be),
+    ("CStr", Builtin::CStr),
+    ("Bool", Builtin::Bool),
+    ("Int", Builtin::Int),
+    ("F32", Builtin::F32),
+    ("F64", Builtin::F64),
+    ("true", Builtin::True),
+    ("false", Builtin::False),
+];
+
+/// The names of all of the built-in globals.
+pub fn builtins() -> &'static [(&'static str, Builtin)] {
+    BUILTINS
+}
+
+impl Builtin {
+    /// Look up a built-in global by name.
+    pub fn from_name(name: &str) -> Option<Builtin> {
+        BUILTINS
+            .iter()
+            .find(|(builtin_name, _)| *builtin_name == name)
+            .map(|(_, builtin)| *builtin)
+    }
+
+    /// Find the built-in global that a term refers to, if any.
+    pub fn from_term(term: &Term) -> Option<Builtin> {
+        match term {
+            Term::Universe(_, Universe::Type) => Some(Builtin::Type),
+            Term::Universe(_, Universe::Format) => Some(Builtin::Format),
+            Term::Universe(_, Universe::Kind) => Some(Builtin::Kind),
+            Term::U8Type(_) => Some(Builtin::U8),
+            Term::U16LeType(_) => Some(Builtin::U16Le),
+            Term::U16BeType(_) => Some(Builtin::U16Be),
+            Term::U32LeType(_) => Some(Builtin::U32Le),
+            Term::U32BeType(_) => Some(Builtin::U32Be),
+            Term::U64LeType(_) => Some(Builtin::U64Le),
+            Term::U64BeType(_) => Some(Builtin::U64Be),
+            Term::U128LeType(_) => Some(Builtin::U128Le),
+            Term::U128BeType(_) => Some(Builtin::U128Be),
+            Term::S8Type(_) => Some(Builtin::S8),
+            Term::S16LeType(_) => Some(Builtin::S16Le),
+            Term::S16BeType(_) => Some(Builtin::S16Be),
+            Term::S32LeType(_) => Some(Builtin::S32Le),
+            Term::S32BeType(_) => Some(Builtin::S32Be),
+            Term::S64LeType(_) => Some(Builtin::S64Le),
+            Term::S64BeType(_) => Some(Builtin::S64Be),
+            Term::S128LeType(_) => Some(Builtin::S128Le),
+            Term::S128BeType(_) => Some(Builtin::S128Be),
+            Term::F16LeType(_) => Some(Builtin::F16Le),
+            Term::F16BeType(_) => Some(Builtin::F16Be),
+            Term::F32LeType(_) => Some(Builtin::F32Le),
+            Term::F32BeType(_) => Some(Builtin::F32Be),
+            Term::F64LeType(_) => Some(Builtin::F64Le),
+            Term::F64BeType(_) => Some(Builtin::F64Be),
+            Term::CStrType(_) => Some(Builtin::CStr),
+            Term::BoolType(_) => Some(Builtin::Bool),
+            Term::IntType(_) => Some(Builtin::Int),
+            Term::F32Type(_) => Some(Builtin::F32),
+            Term::F64Type(_) => Some(Builtin::F64),
+            Term::BoolConst(_, true) => Some(Builtin::True),
+            Term::BoolConst(_, false) => Some(Builtin::False),
+            Term::Item(_, _)
+            | Term::Ann(_, _)
+            | Term::IntConst(_, _)
+            | Term::F32Const(_, _)
+            | Term::F64Const(_, _)
+            | Term::BoolElim(_, _, _, _)
+            | Term::Error(_) => None,
+        }
+    }
+
+    /// The name of the built-in global.
+    pub fn name(self) -> &'static str {
+        BUILTINS
+            .iter()
+            .find(|(_, builtin)| *builtin == self)
+            .map(|(name, _)| *name)
+            .expect("builtin missing from the list of builtins")
+    }
+
+    /// The sort of term that the built-in global refers to.
+    pub fn kind(self) -> BuiltinKind {
+        match self {
+            Builtin::Type | Builtin::Format | Builtin::Kind => BuiltinKind::Universe,
+            Builtin::U8
+            | Builtin::U16Le
+            | Builtin::U16Be
+            | Builtin::U32Le
+            | Builtin::U32Be
+            | Builtin::U64Le
+            | Builtin::U64Be
+            | Builtin::U128Le
+            | Builtin::U128Be
+            | Builtin::S8
+            | Builtin::S16Le
+            | Builtin::S16Be
+            | Builtin::S32Le
+            | Builtin::S32Be
+            | Builtin::S64Le
+            | Builtin::S64Be
+            | Builtin::S128Le
+            | Builtin::S128Be
+            | Builtin::F16Le
+            | Builtin::F16Be
+            | Builtin::F32Le
+            | Builtin::F32Be
+            | Builtin::F64Le
+            | Builtin::F64Be
+            | Builtin::CStr => BuiltinKind::Format,
+            Builtin::Bool | Builtin::Int | Builtin::F32 | Builtin::F64 => BuiltinKind::Type,
+            Builtin::True | Builtin::False => BuiltinKind::BoolConst,
+        }
+    }
+
+    /// Construct the term that the built-in global refers to.
+    pub fn term(self, span: Span) -> Term {
+        match self {
+            Builtin::Type => Term::Universe(span, Universe::Type),
+            Builtin::Format => Term::Universe(span, Universe::Format),
+            Builtin::Kind => Term::Universe(span, Universe::Kind),
+            Builtin::U8 => Term::U8Type(span),
+            Builtin::U16Le => Term::U16LeType(span),
+            Builtin::U16Be => Term::U16BeType(span),
+            Builtin::U32Le => Term::U32LeType(span),
+            Builtin::U32Be => Term::U32BeType(span),
+            Builtin::U64Le => Term::U64LeType(span),
+            Builtin::U64Be => Term::U64BeType(span),
+            Builtin::U128Le => Term::U128LeType(span),
+            Builtin::U128Be => Term::U128BeType(span),
+            Builtin::S8 => Term::S8Type(span),
+            Builtin::S16Le => Term::S16LeType(span),
+            Builtin::S16Be => Term::S16BeType(span),
+            Builtin::S32Le => Term::S32LeType(span),
+            Builtin::S32Be => Term::S32BeType(span),
+            Builtin::S64Le => Term::S64LeType(span),
+            Builtin::S64Be => Term::S64BeType(span),
+            Builtin::S128Le => Term::S128LeType(span),
+            Builtin::S128Be => Term::S128BeType(span),
+            Builtin::F16Le => Term::F16LeType(span),
+            Builtin::F16Be => Term::F16BeType(span),
+            Builtin::F32Le => Term::F32LeType(span),
+            Builtin::F32Be => Term::F32BeType(span),
+            Builtin::F64Le => Term::F64LeType(span),
+            Builtin::F64Be => Term::F64BeType(span),
+            Builtin::CStr => Term::CStrType(span),
+            Builtin::Bool => Term::BoolType(span),
+            Builtin::Int => Term::IntType(span),
+            Builtin::F32 => Term::F32Type(span),
+            Builtin::F64 => Term::F64Type(span),
+            Builtin::True => Term::BoolConst(span, true),
+            Builtin::False => Term::BoolConst(span, false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use codespan::Files;
+
+    use super::*;
+    use crate::core::{Item, Module};
+    use crate::lexer::{Lexer, CORE_KEYWORDS};
+    use crate::surface;
+    use crate::surface::delaborate::delaborate_term;
+
+    #[test]
+    fn delaborated_names_are_parseable() {
+        for (name, builtin) in builtins() {
+            let term = builtin.term(Span::initial());
+            assert_eq!(Builtin::from_term(&term), Some(*builtin));
+            assert_eq!(Builtin::from_name(name), Some(*builtin));
+
+            let delaborated_name = match delaborate_term(&term) {
+                surface::Term::Name(_, delaborated_name) => delaborated_name,
+                surface_term => panic!("name expected, found: {:?}", surface_term),
+            };
+            assert_eq!(delaborated_name, *name);
+
+            let mut files = Files::new();
+            let file_id = files.add("test", format!("Test = {};", delaborated_name));
+            let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+            let mut diagnostics = Vec::new();
+            let module = Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+
+            assert!(diagnostics.is_empty(), "`{}` did not parse", name);
+            match module.items.as_slice() {
+                [Item::Alias(alias)] => assert_eq!(Builtin::from_term(&alias.term), Some(*builtin)),
+                items => panic!("single alias expected, found: {:?}", items),
+            }
+        }
+    }
+}
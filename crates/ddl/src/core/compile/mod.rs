@@ -1,3 +1,4 @@
 //! Compiler backends for the core language.
 
+pub mod python;
 pub mod rust;
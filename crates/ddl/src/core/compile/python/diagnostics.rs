@@ -0,0 +1,62 @@
+//! Diagnostics.
+
+use codespan::{FileId, Span};
+use codespan_reporting::diagnostic::{Diagnostic, Label, Severity};
+
+pub mod error {
+    use super::*;
+
+    pub fn unsupported_format(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "cannot compile format to a ctypes type".to_owned(),
+            primary_label: Label::new(file_id, span, "unsupported format"),
+            secondary_labels: vec![],
+            notes: vec!["ctypes has no 128-bit integer or half-precision float types".to_owned()],
+        }
+    }
+
+    pub fn dynamically_sized_field(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "cannot compile dynamically sized field".to_owned(),
+            primary_label: Label::new(file_id, span, "dynamically sized field"),
+            secondary_labels: vec![],
+            notes: vec![
+                "The Python compiler back-end only supports structures with a fixed layout"
+                    .to_owned(),
+            ],
+        }
+    }
+
+    pub fn conditional_field(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "cannot compile conditional field".to_owned(),
+            primary_label: Label::new(file_id, span, "conditional field"),
+            secondary_labels: vec![],
+            notes: vec![
+                "The Python compiler back-end only supports structures with a fixed layout"
+                    .to_owned(),
+            ],
+        }
+    }
+}
+
+pub mod bug {
+    pub use super::*;
+
+    pub fn non_format_field(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Bug,
+            code: None,
+            message: "attempted to compile a non-format field".to_owned(),
+            primary_label: Label::new(file_id, span, "not a format"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+}
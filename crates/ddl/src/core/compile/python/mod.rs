@@ -0,0 +1,526 @@
+//! Compile structure types to Python [`ctypes`] structures.
+//!
+//! Only formats with a fixed layout can be described by a `ctypes`
+//! structure, so fields that depend on the length of the data, or on which
+//! features are enabled, are reported as errors. Checks that happen while
+//! reading, like alignment assertions and `eof`, are not carried over.
+//! Enums are compiled to the `ctypes` type of their representation, along
+//! with a constant for each variant, so their discriminants are not checked
+//! either.
+//!
+//! [`ctypes`]: https://docs.python.org/3/library/ctypes.html
+
+use codespan::FileId;
+use codespan_reporting::diagnostic::Diagnostic;
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io;
+use std::io::prelude::*;
+
+use crate::core;
+
+mod diagnostics;
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub fn compile_module(
+    writer: &mut impl Write,
+    module: &core::Module,
+    report: &mut dyn FnMut(Diagnostic),
+) -> io::Result<()> {
+    let mut context = ModuleContext {
+        file_id: module.file_id,
+        items: HashMap::new(),
+    };
+
+    writeln!(
+        writer,
+        "# This file is automatically @generated by {} {}",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+    )?;
+    writeln!(writer, "# It is not intended for manual editing.")?;
+    writeln!(writer)?;
+    if !module.doc.is_empty() {
+        compile_doc_lines(writer, "", &module.doc)?;
+        writeln!(writer)?;
+    }
+    writeln!(writer, "import ctypes")?;
+
+    for item in &module.items {
+        let compiled_item = match item {
+            core::Item::Alias(alias) => compile_alias(writer, &context, alias, report)?,
+            core::Item::Struct(struct_ty) => {
+                compile_struct_ty(writer, &context, struct_ty, report)?
+            }
+            core::Item::Enum(enum_ty) => compile_enum_ty(writer, &context, enum_ty, report)?,
+        };
+        context.items.insert(item.name().clone(), compiled_item);
+    }
+
+    Ok(())
+}
+
+/// Items that have previously been compiled, for use in later items.
+enum CompiledItem {
+    /// A format, along with the Python expression for its `ctypes` type.
+    Format(String),
+    /// A boolean constant, for selecting between conditional formats.
+    Bool(bool),
+    /// Items that are not needed by structures, or that failed to compile.
+    Erased,
+}
+
+struct ModuleContext {
+    file_id: FileId,
+    items: HashMap<core::Label, CompiledItem>,
+}
+
+fn compile_alias(
+    writer: &mut impl Write,
+    context: &ModuleContext,
+    alias: &core::Alias,
+    report: &mut dyn FnMut(Diagnostic),
+) -> io::Result<CompiledItem> {
+    if let core::Value::BoolConst(value) = core::semantics::eval(&alias.term) {
+        return Ok(CompiledItem::Bool(value));
+    }
+    if !is_format(context, &alias.term) {
+        return Ok(CompiledItem::Erased);
+    }
+
+    match compile_ty(context, &alias.term, report) {
+        Some(ty) => {
+            let name = mangle(&alias.name.0);
+            writeln!(writer)?;
+            if !alias.doc.is_empty() {
+                compile_doc_lines(writer, "", &alias.doc)?;
+            }
+            writeln!(writer, "{} = {}", name, ty)?;
+            Ok(CompiledItem::Format(name))
+        }
+        None => Ok(CompiledItem::Erased),
+    }
+}
+
+fn compile_struct_ty(
+    writer: &mut impl Write,
+    context: &ModuleContext,
+    struct_ty: &core::StructType,
+    report: &mut dyn FnMut(Diagnostic),
+) -> io::Result<CompiledItem> {
+    let mut fields = Vec::with_capacity(struct_ty.fields.len());
+
+    for field in &struct_ty.fields {
+        if field.cfg.is_some() {
+            report(diagnostics::error::conditional_field(
+                context.file_id,
+                field.span(),
+            ));
+        } else if field.default.is_some() {
+            report(diagnostics::error::dynamically_sized_field(
+                context.file_id,
+                field.span(),
+            ));
+        } else if let Some(ty) = compile_ty(context, &field.term, report) {
+            let name = field.rename.as_ref().unwrap_or(&field.name);
+            fields.push((mangle(&name.0), ty));
+        }
+    }
+    if struct_ty.rest.is_some() {
+        report(diagnostics::error::dynamically_sized_field(
+            context.file_id,
+            struct_ty.span,
+        ));
+    }
+
+    if fields.len() < struct_ty.fields.len() || struct_ty.rest.is_some() {
+        return Ok(CompiledItem::Erased);
+    }
+
+    let name = mangle(&struct_ty.rename.as_ref().unwrap_or(&struct_ty.name).0);
+
+    writeln!(writer)?;
+    writeln!(writer)?;
+    writeln!(writer, "class {}(ctypes.Structure):", name)?;
+    if !struct_ty.doc.is_empty() {
+        compile_doc_lines(writer, "    ", &struct_ty.doc)?;
+        writeln!(writer)?;
+    }
+    writeln!(writer, "    _pack_ = 1")?;
+    if fields.is_empty() {
+        writeln!(writer, "    _fields_ = []")?;
+    } else {
+        writeln!(writer, "    _fields_ = [")?;
+        for (field_name, ty) in fields {
+            writeln!(writer, "        ({:?}, {}),", field_name, ty)?;
+        }
+        writeln!(writer, "    ]")?;
+    }
+
+    Ok(CompiledItem::Format(name))
+}
+
+fn compile_enum_ty(
+    writer: &mut impl Write,
+    context: &ModuleContext,
+    enum_ty: &core::EnumType,
+    report: &mut dyn FnMut(Diagnostic),
+) -> io::Result<CompiledItem> {
+    let ty = match compile_ty(context, &enum_ty.repr, report) {
+        Some(ty) => ty,
+        None => return Ok(CompiledItem::Erased),
+    };
+    let name = mangle(&enum_ty.name.0);
+
+    writeln!(writer)?;
+    if !enum_ty.doc.is_empty() {
+        compile_doc_lines(writer, "", &enum_ty.doc)?;
+    }
+    writeln!(writer, "{} = {}", name, ty)?;
+    for variant in &enum_ty.variants {
+        if let core::Value::IntConst(value) = core::semantics::eval(&variant.value) {
+            let variant_name = format!("{}_{}", enum_ty.name.0, variant.name.0);
+            if !variant.doc.is_empty() {
+                compile_doc_lines(writer, "", &variant.doc)?;
+            }
+            writeln!(
+                writer,
+                "{} = {}",
+                variant_name.to_screaming_snake_case(),
+                value,
+            )?;
+        }
+    }
+
+    Ok(CompiledItem::Format(name))
+}
+
+/// Returns `true` if the term is a format, as opposed to a host type or a
+/// constant.
+fn is_format(context: &ModuleContext, term: &core::Term) -> bool {
+    match term {
+        core::Term::Item(_, label) => match context.items.get(label) {
+            Some(CompiledItem::Format(_)) => true,
+            Some(CompiledItem::Bool(_)) | Some(CompiledItem::Erased) | None => false,
+        },
+        core::Term::Ann(term, _) => is_format(context, term),
+        core::Term::BoolElim(_, _, if_true, if_false) => {
+            is_format(context, if_true) || is_format(context, if_false)
+        }
+        core::Term::U8Type(_)
+        | core::Term::U16LeType(_)
+        | core::Term::U16BeType(_)
+        | core::Term::U32LeType(_)
+        | core::Term::U32BeType(_)
+        | core::Term::U64LeType(_)
+        | core::Term::U64BeType(_)
+        | core::Term::U128LeType(_)
+        | core::Term::U128BeType(_)
+        | core::Term::S8Type(_)
+        | core::Term::S16LeType(_)
+        | core::Term::S16BeType(_)
+        | core::Term::S32LeType(_)
+        | core::Term::S32BeType(_)
+        | core::Term::S64LeType(_)
+        | core::Term::S64BeType(_)
+        | core::Term::S128LeType(_)
+        | core::Term::S128BeType(_)
+        | core::Term::F16LeType(_)
+        | core::Term::F16BeType(_)
+        | core::Term::F32LeType(_)
+        | core::Term::F32BeType(_)
+        | core::Term::F64LeType(_)
+        | core::Term::F64BeType(_)
+        | core::Term::CStrType(_) => true,
+        core::Term::Universe(_, _)
+        | core::Term::BoolType(_)
+        | core::Term::IntType(_)
+        | core::Term::F32Type(_)
+        | core::Term::F64Type(_)
+        | core::Term::BoolConst(_, _)
+        | core::Term::IntConst(_, _)
+        | core::Term::F32Const(_, _)
+        | core::Term::F64Const(_, _)
+        | core::Term::Error(_) => false,
+    }
+}
+
+/// Compile a format to a Python expression for the corresponding `ctypes`
+/// type, reporting an error if it has no fixed layout.
+fn compile_ty(
+    context: &ModuleContext,
+    term: &core::Term,
+    report: &mut dyn FnMut(Diagnostic),
+) -> Option<String> {
+    let (ty, byte_order) = match term {
+        core::Term::Item(_, label) => match context.items.get(label) {
+            Some(CompiledItem::Format(name)) => return Some(name.clone()),
+            // Errors were reported when the item was compiled
+            Some(CompiledItem::Bool(_)) | Some(CompiledItem::Erased) | None => return None,
+        },
+        core::Term::Ann(term, _) => return compile_ty(context, term, report),
+        core::Term::BoolElim(span, term, if_true, if_false) => {
+            return match compile_condition(context, term) {
+                Some(true) => compile_ty(context, if_true, report),
+                Some(false) => compile_ty(context, if_false, report),
+                None => {
+                    report(diagnostics::error::conditional_field(
+                        context.file_id,
+                        *span,
+                    ));
+                    None
+                }
+            };
+        }
+        core::Term::U8Type(_) => ("c_uint8", ""),
+        core::Term::U16LeType(_) => ("c_uint16", ".__ctype_le__"),
+        core::Term::U16BeType(_) => ("c_uint16", ".__ctype_be__"),
+        core::Term::U32LeType(_) => ("c_uint32", ".__ctype_le__"),
+        core::Term::U32BeType(_) => ("c_uint32", ".__ctype_be__"),
+        core::Term::U64LeType(_) => ("c_uint64", ".__ctype_le__"),
+        core::Term::U64BeType(_) => ("c_uint64", ".__ctype_be__"),
+        core::Term::S8Type(_) => ("c_int8", ""),
+        core::Term::S16LeType(_) => ("c_int16", ".__ctype_le__"),
+        core::Term::S16BeType(_) => ("c_int16", ".__ctype_be__"),
+        core::Term::S32LeType(_) => ("c_int32", ".__ctype_le__"),
+        core::Term::S32BeType(_) => ("c_int32", ".__ctype_be__"),
+        core::Term::S64LeType(_) => ("c_int64", ".__ctype_le__"),
+        core::Term::S64BeType(_) => ("c_int64", ".__ctype_be__"),
+        core::Term::F32LeType(_) => ("c_float", ".__ctype_le__"),
+        core::Term::F32BeType(_) => ("c_float", ".__ctype_be__"),
+        core::Term::F64LeType(_) => ("c_double", ".__ctype_le__"),
+        core::Term::F64BeType(_) => ("c_double", ".__ctype_be__"),
+        core::Term::U128LeType(span)
+        | core::Term::U128BeType(span)
+        | core::Term::S128LeType(span)
+        | core::Term::S128BeType(span)
+        | core::Term::F16LeType(span)
+        | core::Term::F16BeType(span) => {
+            report(diagnostics::error::unsupported_format(
+                context.file_id,
+                *span,
+            ));
+            return None;
+        }
+        core::Term::CStrType(span) => {
+            report(diagnostics::error::dynamically_sized_field(
+                context.file_id,
+                *span,
+            ));
+            return None;
+        }
+        core::Term::Universe(span, _)
+        | core::Term::BoolType(span)
+        | core::Term::IntType(span)
+        | core::Term::F32Type(span)
+        | core::Term::F64Type(span)
+        | core::Term::BoolConst(span, _)
+        | core::Term::IntConst(span, _)
+        | core::Term::F32Const(span, _)
+        | core::Term::F64Const(span, _) => {
+            report(diagnostics::bug::non_format_field(context.file_id, *span));
+            return None;
+        }
+        core::Term::Error(_) => return None,
+    };
+
+    Some(format!("ctypes.{}{}", ty, byte_order))
+}
+
+/// Evaluate the condition of a conditional format, if it is a constant.
+fn compile_condition(context: &ModuleContext, term: &core::Term) -> Option<bool> {
+    match core::semantics::eval(term) {
+        core::Value::BoolConst(value) => Some(value),
+        core::Value::Neutral(core::Head::Item(label), elims) if elims.is_empty() => {
+            match context.items.get(&label) {
+                Some(CompiledItem::Bool(value)) => Some(*value),
+                Some(CompiledItem::Format(_)) | Some(CompiledItem::Erased) | None => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Rename identifiers that collide with Python keywords.
+fn mangle(name: &str) -> String {
+    match PYTHON_KEYWORDS.contains(&name) {
+        true => format!("{}_", name),
+        false => name.to_owned(),
+    }
+}
+
+fn compile_doc_lines(writer: &mut impl Write, prefix: &str, doc: &[String]) -> io::Result<()> {
+    let mut lines = doc.iter().map(|line| {
+        let line = match line {
+            line if line.starts_with(" ") => &line[" ".len()..],
+            line => &line[..],
+        };
+        line.replace('\\', "\\\\").replace("\"\"\"", "\\\"\"\"")
+    });
+
+    let mut first = match lines.next() {
+        Some(first) => first,
+        None => return Ok(()),
+    };
+
+    if doc.len() == 1 {
+        // Escape a trailing quote so that it doesn't run into the closing quotes
+        if first.ends_with('"') {
+            first.pop();
+            first.push_str("\\\"");
+        }
+        return writeln!(writer, "{}\"\"\"{}\"\"\"", prefix, first);
+    }
+
+    writeln!(writer, "{}\"\"\"{}", prefix, first)?;
+    for line in lines {
+        match line.is_empty() {
+            true => writeln!(writer)?,
+            false => writeln!(writer, "{}{}", prefix, line)?,
+        }
+    }
+    writeln!(writer, "{}\"\"\"", prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use codespan::Files;
+
+    use super::*;
+    use crate::lexer::{Lexer, CORE_KEYWORDS};
+
+    #[test]
+    fn compile_struct() {
+        let mut files = Files::new();
+        let file_id = files.add("test", "struct Pair { first : U8, second : U16Be, }");
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = core::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let mut output = Vec::new();
+        compile_module(&mut output, &module, &mut |d| diagnostics.push(d)).unwrap();
+        assert!(diagnostics.is_empty());
+
+        let expected = format!(
+            r#"# This file is automatically @generated by ddl {}
+# It is not intended for manual editing.
+
+import ctypes
+
+
+class Pair(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("first", ctypes.c_uint8),
+        ("second", ctypes.c_uint16.__ctype_be__),
+    ]
+"#,
+            env!("CARGO_PKG_VERSION"),
+        );
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn compile_struct_keyword_field() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "/// An object of some \"class\"\nstruct Object { class : U8, }",
+        );
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = core::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let mut output = Vec::new();
+        compile_module(&mut output, &module, &mut |d| diagnostics.push(d)).unwrap();
+        assert!(diagnostics.is_empty());
+
+        let expected = format!(
+            r#"# This file is automatically @generated by ddl {}
+# It is not intended for manual editing.
+
+import ctypes
+
+
+class Object(ctypes.Structure):
+    """An object of some "class\""""
+
+    _pack_ = 1
+    _fields_ = [
+        ("class_", ctypes.c_uint8),
+    ]
+"#,
+            env!("CARGO_PKG_VERSION"),
+        );
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn compile_struct_rest() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "struct Packet { len : U8, ...payload } struct Outer { packet : item Packet, }",
+        );
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = core::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let mut output = Vec::new();
+        compile_module(&mut output, &module, &mut |d| diagnostics.push(d)).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "cannot compile dynamically sized field",
+        );
+        assert!(!String::from_utf8(output).unwrap().contains("class"));
+    }
+
+    #[test]
+    fn compile_enum() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "enum ByteOrder : U16Be { Little = int 18761, Big = int 19789, } \
+             struct Header { byte_order : item ByteOrder, }",
+        );
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = core::Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let mut output = Vec::new();
+        compile_module(&mut output, &module, &mut |d| diagnostics.push(d)).unwrap();
+        assert!(diagnostics.is_empty());
+
+        let expected = format!(
+            r#"# This file is automatically @generated by ddl {}
+# It is not intended for manual editing.
+
+import ctypes
+
+ByteOrder = ctypes.c_uint16.__ctype_be__
+BYTE_ORDER_LITTLE = 18761
+BYTE_ORDER_BIG = 19789
+
+
+class Header(ctypes.Structure):
+    _pack_ = 1
+    _fields_ = [
+        ("byte_order", ByteOrder),
+    ]
+"#,
+            env!("CARGO_PKG_VERSION"),
+        );
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+}
@@ -21,6 +21,17 @@ pub mod error {
         }
     }
 
+    pub fn invalid_identifier(file_id: FileId, span: Span, name: &core::Label) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: format!("`{}` is not a valid Rust identifier", name),
+            primary_label: Label::new(file_id, span, "invalid name override"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
     pub fn unconstrained_int(file_id: FileId, span: Span) -> Diagnostic {
         Diagnostic {
             severity: Severity::Error,
@@ -102,4 +113,15 @@ pub mod bug {
             notes: vec!["only format types can appear in `struct` fields".to_owned()],
         }
     }
+
+    pub fn invalid_field_value(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Bug,
+            code: None,
+            message: "attempted to compile an invalid field value".to_owned(),
+            primary_label: Label::new(file_id, span, "cannot be read by the field's format"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
 }
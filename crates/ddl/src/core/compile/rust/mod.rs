@@ -1,4 +1,4 @@
-use codespan::{FileId, Span};
+use codespan::{ByteIndex, FileId, Files, Span};
 use codespan_reporting::diagnostic::Diagnostic;
 use inflector::Inflector;
 use num_bigint::BigInt;
@@ -8,9 +8,40 @@ use std::sync::Arc;
 use crate::{core, rust};
 
 mod diagnostics;
+mod names;
 
-pub fn compile_module(module: &core::Module, report: &mut dyn FnMut(Diagnostic)) -> rust::Module {
+pub use self::names::NameMangling;
+
+/// Options that control the generated code.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Options {
+    /// How to rename identifiers that collide with Rust keywords.
+    pub mangling: NameMangling,
+    /// Generate a `read_bounded` method on each structure type, for reading
+    /// it within a maximum number of bytes.
+    pub read_bounded: bool,
+    /// Generate a `Visit` trait for traversing the structure types in the
+    /// module, along with `accept` and `walk` methods on each of them.
+    pub visitor: bool,
+}
+
+pub fn compile_module(
+    files: &Files,
+    module: &core::Module,
+    report: &mut dyn FnMut(Diagnostic),
+) -> rust::Module {
+    compile_module_with_options(files, module, &Options::default(), report)
+}
+
+pub fn compile_module_with_options(
+    files: &Files,
+    module: &core::Module,
+    options: &Options,
+    report: &mut dyn FnMut(Diagnostic),
+) -> rust::Module {
     let mut context = ModuleContext {
+        files,
+        options: *options,
         file_id: module.file_id,
         items: HashMap::new(),
     };
@@ -61,6 +92,12 @@ enum CompiledItem {
     Error(Span),
 }
 
+impl ModuleContext<'_> {
+    fn mangle(&self, name: String) -> String {
+        names::mangle(self.options.mangling, name)
+    }
+}
+
 impl CompiledItem {
     fn span(&self) -> Span {
         match self {
@@ -72,24 +109,27 @@ impl CompiledItem {
     }
 }
 
-struct ModuleContext {
+struct ModuleContext<'files> {
+    files: &'files Files,
+    options: Options,
     file_id: FileId,
     items: HashMap<core::Label, CompiledItem>,
 }
 
 fn compile_item(
-    context: &ModuleContext,
+    context: &ModuleContext<'_>,
     core_item: &core::Item,
     report: &mut dyn FnMut(Diagnostic),
 ) -> (core::Label, CompiledItem, Option<rust::Item>) {
     match core_item {
         core::Item::Alias(core_alias) => compile_alias(context, core_alias, report),
         core::Item::Struct(core_struct_ty) => compile_struct_ty(context, core_struct_ty, report),
+        core::Item::Enum(core_enum_ty) => compile_enum_ty(context, core_enum_ty, report),
     }
 }
 
 fn compile_alias(
-    context: &ModuleContext,
+    context: &ModuleContext<'_>,
     core_alias: &core::Alias,
     report: &mut dyn FnMut(Diagnostic),
 ) -> (core::Label, CompiledItem, Option<rust::Item>) {
@@ -98,7 +138,7 @@ fn compile_alias(
         CompiledTerm::Term { term, ty, is_const } => {
             let doc = core_alias.doc.clone();
             if is_const {
-                let name = core_alias.name.0.to_screaming_snake_case(); // TODO: name avoidance
+                let name = context.mangle(core_alias.name.0.to_screaming_snake_case());
                 (
                     core_alias.name.clone(),
                     CompiledItem::Term {
@@ -116,7 +156,7 @@ fn compile_alias(
                     })),
                 )
             } else {
-                let name = core_alias.name.0.to_snake_case(); // TODO: name avoidance
+                let name = context.mangle(core_alias.name.0.to_snake_case());
                 (
                     core_alias.name.clone(),
                     CompiledItem::Term {
@@ -144,7 +184,8 @@ fn compile_alias(
             host_ty,
         } => {
             let doc = core_alias.doc.clone();
-            let name = core_alias.name.0.to_pascal_case(); // TODO: name avoidance
+            let name = context.mangle(core_alias.name.0.to_pascal_case());
+            let visit_method = compile_visit_method(context, &name);
             let mut derives = Vec::new();
             if is_copy {
                 derives.push("Copy".to_owned());
@@ -168,11 +209,23 @@ fn compile_alias(
                             name,
                             fields: vec![rust::TypeField {
                                 doc: Arc::new([]),
+                                align: None,
+                                cfg: None,
+                                default: None,
+                                magic: None,
+                                location: None,
+                                label: "inner".to_owned(),
                                 name: "inner".to_owned(),
                                 format_ty: ty,
                                 host_ty,
                                 by_ref: !is_copy,
+                                reserved: false,
                             }],
+                            eof: false,
+                            rest: None,
+                            read_bounded: context.options.read_bounded,
+                            transparent: false,
+                            visit_method,
                         })),
                     ),
                 },
@@ -192,7 +245,7 @@ fn compile_alias(
 }
 
 fn compile_struct_ty(
-    context: &ModuleContext,
+    context: &ModuleContext<'_>,
     core_struct_ty: &core::StructType,
     report: &mut dyn FnMut(Diagnostic),
 ) -> (core::Label, CompiledItem, Option<rust::Item>) {
@@ -239,18 +292,43 @@ fn compile_struct_ty(
             CompiledTerm::Error => (INVALID_TYPE, INVALID_TYPE, true),
         };
 
-        is_copy &= is_field_copy;
+        let default = field
+            .default
+            .as_ref()
+            .and_then(|default| compile_field_value(context, default, &host_ty, report));
+        let magic = field
+            .magic
+            .as_ref()
+            .and_then(|magic| compile_field_value(context, magic, &host_ty, report));
+
+        is_copy &= is_field_copy || field.reserved;
         fields.push(rust::TypeField {
             doc: field.doc.clone(),
-            name: field.name.0.clone(),
+            align: field.align,
+            cfg: field.cfg.as_ref().map(|feature| feature.0.clone()),
+            default,
+            magic,
+            location: compile_location(context, field.start),
+            label: field.name.0.clone(),
+            name: compile_rename(context, &field.rename, field.span(), report)
+                .unwrap_or_else(|| context.mangle(field.name.0.clone())),
             format_ty,
             host_ty,
             by_ref: !is_field_copy,
+            reserved: field.reserved,
         })
     }
 
+    let rest = core_struct_ty
+        .rest
+        .as_ref()
+        .map(|rest| context.mangle(rest.0.clone()));
+    is_copy &= rest.is_none();
+
     let doc = core_struct_ty.doc.clone();
-    let name = core_struct_ty.name.0.to_pascal_case(); // TODO: name avoidance
+    let name = compile_rename(context, &core_struct_ty.rename, core_struct_ty.span, report)
+        .unwrap_or_else(|| context.mangle(core_struct_ty.name.0.to_pascal_case()));
+    let visit_method = compile_visit_method(context, &name);
     let mut derives = Vec::new();
     if is_copy {
         derives.push("Copy".to_owned());
@@ -270,10 +348,103 @@ fn compile_struct_ty(
             doc,
             name,
             fields,
+            eof: core_struct_ty.eof,
+            rest,
+            read_bounded: context.options.read_bounded,
+            transparent: core_struct_ty.transparent,
+            visit_method,
+        })),
+    )
+}
+
+fn compile_enum_ty(
+    context: &ModuleContext<'_>,
+    core_enum_ty: &core::EnumType,
+    report: &mut dyn FnMut(Diagnostic),
+) -> (core::Label, CompiledItem, Option<rust::Item>) {
+    let span = core_enum_ty.span;
+    let (format_ty, host_ty) = match compile_term(context, &core_enum_ty.repr, report) {
+        CompiledTerm::Type {
+            ty,
+            host_ty: Some(host_ty),
+            ..
+        } => (ty, host_ty),
+        CompiledTerm::Type { host_ty: None, .. } => {
+            report(diagnostics::bug::host_type_found_in_field(
+                context.file_id,
+                span,
+                core_enum_ty.repr.span(),
+            ));
+            return (core_enum_ty.name.clone(), CompiledItem::Error(span), None);
+        }
+        CompiledTerm::Term { .. } | CompiledTerm::Erased => {
+            report(diagnostics::bug::non_format_type_as_host_type(
+                context.file_id,
+                core_enum_ty.repr.span(),
+            ));
+            return (core_enum_ty.name.clone(), CompiledItem::Error(span), None);
+        }
+        CompiledTerm::Error => return (core_enum_ty.name.clone(), CompiledItem::Error(span), None),
+    };
+
+    let variants = core_enum_ty.variants.iter().filter_map(|variant| {
+        Some(rust::EnumVariant {
+            doc: variant.doc.clone(),
+            name: context.mangle(variant.name.0.to_pascal_case()),
+            value: compile_field_value(context, &variant.value, &host_ty, report)?,
+        })
+    });
+    let variants = variants.collect();
+
+    let doc = core_enum_ty.doc.clone();
+    let name = context.mangle(core_enum_ty.name.0.to_pascal_case());
+
+    (
+        core_enum_ty.name.clone(),
+        CompiledItem::Type {
+            span,
+            name: name.clone(),
+            is_copy: true,
+            host_ty: Some(rust::Type::Var(name.clone())),
+        },
+        Some(rust::Item::Enum(rust::EnumType {
+            doc,
+            name,
+            format_ty,
+            host_ty,
+            variants,
         })),
     )
 }
 
+/// Compile the identifier given by a `@name(ident)` attribute. Overrides are
+/// used verbatim, so they are rejected if they would need to be mangled.
+fn compile_rename(
+    context: &ModuleContext<'_>,
+    rename: &Option<core::Label>,
+    span: Span,
+    report: &mut dyn FnMut(Diagnostic),
+) -> Option<String> {
+    let rename = rename.as_ref()?;
+    if names::is_identifier(&rename.0) {
+        Some(rename.0.clone())
+    } else {
+        report(diagnostics::error::invalid_identifier(
+            context.file_id,
+            span,
+            rename,
+        ));
+        None
+    }
+}
+
+fn compile_visit_method(context: &ModuleContext<'_>, struct_name: &str) -> Option<String> {
+    match context.options.visitor {
+        true => Some(format!("visit_{}", struct_name.to_snake_case())),
+        false => None,
+    }
+}
+
 enum CompiledTerm {
     Term {
         term: rust::Term,
@@ -289,8 +460,55 @@ enum CompiledTerm {
     Error,
 }
 
+/// Compile a default value or magic number for a field, or the discriminant
+/// of an enum variant, with the given host type.
+fn compile_field_value(
+    context: &ModuleContext<'_>,
+    core_term: &core::Term,
+    host_ty: &rust::Type,
+    report: &mut dyn FnMut(Diagnostic),
+) -> Option<rust::Term> {
+    use num_traits::cast::ToPrimitive;
+
+    let term = match (core::semantics::eval(core_term), host_ty) {
+        (core::Value::IntConst(value), rust::Type::U8) => value.to_u8().map(rust::Term::U8),
+        (core::Value::IntConst(value), rust::Type::U16) => value.to_u16().map(rust::Term::U16),
+        (core::Value::IntConst(value), rust::Type::U32) => value.to_u32().map(rust::Term::U32),
+        (core::Value::IntConst(value), rust::Type::U64) => value.to_u64().map(rust::Term::U64),
+        (core::Value::IntConst(value), rust::Type::U128) => value.to_u128().map(rust::Term::U128),
+        (core::Value::IntConst(value), rust::Type::I8) => value.to_i8().map(rust::Term::I8),
+        (core::Value::IntConst(value), rust::Type::I16) => value.to_i16().map(rust::Term::I16),
+        (core::Value::IntConst(value), rust::Type::I32) => value.to_i32().map(rust::Term::I32),
+        (core::Value::IntConst(value), rust::Type::I64) => value.to_i64().map(rust::Term::I64),
+        (core::Value::IntConst(value), rust::Type::I128) => value.to_i128().map(rust::Term::I128),
+        (core::Value::F32Const(value), rust::Type::F32) => Some(rust::Term::F32(value)),
+        (core::Value::F64Const(value), rust::Type::F64) => Some(rust::Term::F64(value)),
+        (core::Value::Error, _) => return None,
+        (_, _) => None,
+    };
+
+    if term.is_none() {
+        report(diagnostics::bug::invalid_field_value(
+            context.file_id,
+            core_term.span(),
+        ));
+    }
+
+    term
+}
+
+fn compile_location(context: &ModuleContext<'_>, start: ByteIndex) -> Option<rust::FieldLocation> {
+    let location = context.files.location(context.file_id, start).ok()?;
+
+    Some(rust::FieldLocation {
+        file: context.files.name(context.file_id).to_owned(),
+        line: location.line.to_usize() + 1,
+        column: location.column.to_usize() + 1,
+    })
+}
+
 fn compile_term(
-    context: &ModuleContext,
+    context: &ModuleContext<'_>,
     core_term: &core::Term,
     report: &mut dyn FnMut(Diagnostic),
 ) -> CompiledTerm {
@@ -349,6 +567,12 @@ fn compile_term(
         core::Term::U32BeType(_) => format_ty(rust::Type::Rt(rust::RtType::U32Be), rust::Type::U32),
         core::Term::U64LeType(_) => format_ty(rust::Type::Rt(rust::RtType::U64Le), rust::Type::U64),
         core::Term::U64BeType(_) => format_ty(rust::Type::Rt(rust::RtType::U64Be), rust::Type::U64),
+        core::Term::U128LeType(_) => {
+            format_ty(rust::Type::Rt(rust::RtType::U128Le), rust::Type::U128)
+        }
+        core::Term::U128BeType(_) => {
+            format_ty(rust::Type::Rt(rust::RtType::U128Be), rust::Type::U128)
+        }
         core::Term::S8Type(_) => format_ty(rust::Type::Rt(rust::RtType::I8), rust::Type::I8),
         core::Term::S16LeType(_) => format_ty(rust::Type::Rt(rust::RtType::I16Le), rust::Type::I16),
         core::Term::S16BeType(_) => format_ty(rust::Type::Rt(rust::RtType::I16Be), rust::Type::I16),
@@ -356,10 +580,23 @@ fn compile_term(
         core::Term::S32BeType(_) => format_ty(rust::Type::Rt(rust::RtType::I32Be), rust::Type::I32),
         core::Term::S64LeType(_) => format_ty(rust::Type::Rt(rust::RtType::I64Le), rust::Type::I64),
         core::Term::S64BeType(_) => format_ty(rust::Type::Rt(rust::RtType::I64Be), rust::Type::I64),
+        core::Term::S128LeType(_) => {
+            format_ty(rust::Type::Rt(rust::RtType::I128Le), rust::Type::I128)
+        }
+        core::Term::S128BeType(_) => {
+            format_ty(rust::Type::Rt(rust::RtType::I128Be), rust::Type::I128)
+        }
+        core::Term::F16LeType(_) => format_ty(rust::Type::Rt(rust::RtType::F16Le), rust::Type::F32),
+        core::Term::F16BeType(_) => format_ty(rust::Type::Rt(rust::RtType::F16Be), rust::Type::F32),
         core::Term::F32LeType(_) => format_ty(rust::Type::Rt(rust::RtType::F32Le), rust::Type::F32),
         core::Term::F32BeType(_) => format_ty(rust::Type::Rt(rust::RtType::F32Be), rust::Type::F32),
         core::Term::F64LeType(_) => format_ty(rust::Type::Rt(rust::RtType::F64Le), rust::Type::F64),
         core::Term::F64BeType(_) => format_ty(rust::Type::Rt(rust::RtType::F64Be), rust::Type::F64),
+        core::Term::CStrType(_) => CompiledTerm::Type {
+            ty: rust::Type::Rt(rust::RtType::CStr),
+            is_copy: false,
+            host_ty: Some(rust::Type::CString),
+        },
         core::Term::BoolType(_) => host_ty(rust::Type::Bool),
         core::Term::IntType(span) => {
             report(diagnostics::error::unconstrained_int(file_id, *span));
@@ -375,21 +612,41 @@ fn compile_term(
         core::Term::IntConst(span, value) => {
             use num_traits::cast::ToPrimitive;
 
-            match value.to_i64() {
-                // TODO: don't default to I64.
-                Some(value) => CompiledTerm::Term {
+            // TODO: don't default to I64.
+            match (value.to_i64(), value.to_u64()) {
+                (Some(value), _) => CompiledTerm::Term {
                     term: rust::Term::I64(value),
                     ty: rust::Type::I64,
                     is_const: true,
                 },
-                None => {
-                    report(crate::diagnostics::bug::not_yet_implemented(
-                        context.file_id,
-                        *span,
-                        "non-i64 types",
-                    ));
-                    CompiledTerm::Error
-                }
+                // Fall back to U64 so that the top half of its range is not lost.
+                (None, Some(value)) => CompiledTerm::Term {
+                    term: rust::Term::U64(value),
+                    ty: rust::Type::U64,
+                    is_const: true,
+                },
+                // Widen to the 128-bit types for anything that does not fit
+                // in 64 bits, for example magic numbers in 128-bit fields.
+                (None, None) => match (value.to_i128(), value.to_u128()) {
+                    (Some(value), _) => CompiledTerm::Term {
+                        term: rust::Term::I128(value),
+                        ty: rust::Type::I128,
+                        is_const: true,
+                    },
+                    (None, Some(value)) => CompiledTerm::Term {
+                        term: rust::Term::U128(value),
+                        ty: rust::Type::U128,
+                        is_const: true,
+                    },
+                    (None, None) => {
+                        report(crate::diagnostics::bug::not_yet_implemented(
+                            context.file_id,
+                            *span,
+                            "integers outside of the 128-bit range",
+                        ));
+                        CompiledTerm::Error
+                    }
+                },
             }
         }
         core::Term::F32Const(_, value) => CompiledTerm::Term {
@@ -0,0 +1,115 @@
+//! Turning names from data descriptions into valid Rust identifiers.
+
+/// How to rename identifiers that would collide with Rust keywords.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NameMangling {
+    /// Use raw identifiers, for example `r#type`.
+    ///
+    /// Keywords that can't be used as raw identifiers (`self`, `Self`,
+    /// `super`, and `crate`) fall back to a suffix.
+    RawIdentifier,
+    /// Append an underscore, for example `type_`.
+    Suffix,
+}
+
+impl Default for NameMangling {
+    fn default() -> NameMangling {
+        NameMangling::RawIdentifier
+    }
+}
+
+/// Strict and reserved keywords, as of the 2018 edition.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Keywords that are not permitted as raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Turn a name into an identifier that can be used in the generated code.
+pub fn mangle(mangling: NameMangling, name: String) -> String {
+    let name = sanitize(name);
+
+    if !KEYWORDS.contains(&name.as_str()) {
+        name
+    } else if mangling == NameMangling::RawIdentifier && !NON_RAW_KEYWORDS.contains(&name.as_str())
+    {
+        format!("r#{}", name)
+    } else {
+        format!("{}_", name)
+    }
+}
+
+/// Returns `true` if the name can be used as an identifier as-is, without
+/// any mangling.
+pub fn is_identifier(name: &str) -> bool {
+    sanitize(name.to_owned()) == name && !KEYWORDS.contains(&name)
+}
+
+/// Replace characters that can't appear in Rust identifiers, and make sure
+/// the result does not start with a digit or consist only of an underscore.
+fn sanitize(name: String) -> String {
+    let mut name = if name
+        .chars()
+        .all(|ch| ch == '_' || ch.is_ascii_alphanumeric())
+    {
+        name
+    } else {
+        name.chars()
+            .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+            .collect()
+    };
+
+    match name.chars().next() {
+        None => name.push('_'),
+        Some(ch) if ch.is_ascii_digit() => name.insert(0, '_'),
+        Some(_) => {}
+    }
+    if name == "_" {
+        name.push('_');
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        let mangle_raw = |name: &str| mangle(NameMangling::RawIdentifier, name.to_owned());
+        let mangle_suffix = |name: &str| mangle(NameMangling::Suffix, name.to_owned());
+
+        assert_eq!(mangle_raw("type"), "r#type");
+        assert_eq!(mangle_raw("self"), "self_");
+        assert_eq!(mangle_raw("Self"), "Self_");
+        assert_eq!(mangle_raw("field_type"), "field_type");
+        assert_eq!(mangle_suffix("type"), "type_");
+        assert_eq!(mangle_suffix("match"), "match_");
+    }
+
+    #[test]
+    fn invalid_identifiers() {
+        let mangle = |name: &str| mangle(NameMangling::default(), name.to_owned());
+
+        assert_eq!(mangle(""), "__");
+        assert_eq!(mangle("_"), "__");
+        assert_eq!(mangle("1st"), "_1st");
+        assert_eq!(mangle("foo-bar"), "foo_bar");
+    }
+
+    #[test]
+    fn identifiers() {
+        assert!(is_identifier("field_type"));
+        assert!(is_identifier("_private"));
+        assert!(!is_identifier("type"));
+        assert!(!is_identifier("_"));
+        assert!(!is_identifier("1st"));
+        assert!(!is_identifier("foo-bar"));
+    }
+}
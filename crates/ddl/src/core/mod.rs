@@ -5,9 +5,13 @@ use codespan_reporting::diagnostic::Diagnostic;
 use num_bigint::BigInt;
 use pretty::{DocAllocator, DocBuilder};
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
 use std::sync::Arc;
 
+use crate::core::builtin::Builtin;
 use crate::lexer::SpannedToken;
 use crate::{diagnostics, ieee754};
 
@@ -15,6 +19,7 @@ mod grammar {
     include!(concat!(env!("OUT_DIR"), "/core/grammar.rs"));
 }
 
+pub mod builtin;
 pub mod compile;
 pub mod semantics;
 pub mod validate;
@@ -74,6 +79,137 @@ impl Module {
             })
     }
 
+    /// Render this module as source code in the surface syntax.
+    pub fn to_source(&self) -> String {
+        let arena = pretty::Arena::new();
+        let surface_module = crate::surface::delaborate::delaborate_module(self);
+        let pretty::DocBuilder(_, doc) = surface_module.doc(&arena);
+
+        doc.pretty(100).to_string()
+    }
+
+    /// The names of the items that each item in the module refers to.
+    ///
+    /// References are listed in the order in which they first appear in the
+    /// body of the item.
+    pub fn dependency_graph(&self) -> HashMap<String, Vec<String>> {
+        self.items
+            .iter()
+            .map(|item| {
+                let mut references = Vec::new();
+                match item {
+                    Item::Alias(alias) => alias.term.item_references(&mut references),
+                    Item::Struct(struct_ty) => {
+                        for field in &struct_ty.fields {
+                            field.term.item_references(&mut references);
+                        }
+                    }
+                    Item::Enum(enum_ty) => enum_ty.repr.item_references(&mut references),
+                }
+                (item.name().0.clone(), references)
+            })
+            .collect()
+    }
+
+    /// The items in the module, ordered so that each item comes after the
+    /// items that it refers to. Items are otherwise kept in the order that
+    /// they were defined.
+    ///
+    /// If some of the items refer to each other in a cycle, the names of the
+    /// items in the cycle are returned instead.
+    pub fn items_topologically_sorted(&self) -> Result<Vec<&Item>, Vec<String>> {
+        fn visit<'module>(
+            name: &str,
+            graph: &HashMap<String, Vec<String>>,
+            items: &HashMap<&str, &'module Item>,
+            path: &mut Vec<String>,
+            sorted: &mut Vec<&'module Item>,
+        ) -> Result<(), Vec<String>> {
+            if let Some(start) = path.iter().position(|visiting| visiting == name) {
+                return Err(path[start..].to_vec());
+            }
+            let item = match items.get(name) {
+                Some(item) if !sorted.iter().any(|sorted| std::ptr::eq(*sorted, *item)) => *item,
+                // Already sorted, or not defined in this module
+                Some(_) | None => return Ok(()),
+            };
+
+            path.push(name.to_owned());
+            for reference in &graph[name] {
+                visit(reference, graph, items, path, sorted)?;
+            }
+            path.pop();
+            sorted.push(item);
+
+            Ok(())
+        }
+
+        let graph = self.dependency_graph();
+        let items = self
+            .items
+            .iter()
+            .map(|item| (item.name().0.as_str(), item))
+            .collect::<HashMap<_, _>>();
+        let mut sorted = Vec::with_capacity(self.items.len());
+
+        for item in &self.items {
+            visit(&item.name().0, &graph, &items, &mut Vec::new(), &mut sorted)?;
+        }
+
+        Ok(sorted)
+    }
+
+    /// The integer constants that appear in the module, in the order that
+    /// they were defined.
+    ///
+    /// This includes constants that appear in the format of a field, along
+    /// with field default values and the values of enum variants.
+    pub fn constants(&self) -> Vec<ConstantRef> {
+        let mut constants = Vec::new();
+
+        for item in &self.items {
+            let item_name = &item.name().0;
+            let mut push_constant = |field: Option<&Label>, span: Span, value: &BigInt| {
+                constants.push(ConstantRef {
+                    span,
+                    value: value.clone(),
+                    item: item_name.clone(),
+                    field: field.map(|field| field.0.clone()),
+                });
+            };
+
+            match item {
+                Item::Alias(alias) => alias
+                    .term
+                    .int_constants(&mut |span, value| push_constant(None, span, value)),
+                Item::Struct(struct_ty) => {
+                    for field in &struct_ty.fields {
+                        let mut push_field_constant =
+                            |span, value: &BigInt| push_constant(Some(&field.name), span, value);
+                        field.term.int_constants(&mut push_field_constant);
+                        if let Some(default) = &field.default {
+                            default.int_constants(&mut push_field_constant);
+                        }
+                        if let Some(magic) = &field.magic {
+                            magic.int_constants(&mut push_field_constant);
+                        }
+                    }
+                }
+                Item::Enum(enum_ty) => {
+                    (enum_ty.repr)
+                        .int_constants(&mut |span, value| push_constant(None, span, value));
+                    for variant in &enum_ty.variants {
+                        (variant.value).int_constants(&mut |span, value| {
+                            push_constant(Some(&variant.name), span, value)
+                        });
+                    }
+                }
+            }
+        }
+
+        constants
+    }
+
     pub fn doc<'core, D>(&'core self, alloc: &'core D) -> DocBuilder<'core, D>
     where
         D: DocAllocator<'core>,
@@ -103,6 +239,20 @@ impl PartialEq for Module {
     }
 }
 
+/// An integer constant found in a module.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantRef {
+    /// The location of the constant.
+    pub span: Span,
+    /// The value of the constant.
+    pub value: BigInt,
+    /// The name of the item that the constant appears in.
+    pub item: String,
+    /// The name of the field or variant that the constant appears in, if the
+    /// item is a structure or an enum.
+    pub field: Option<String>,
+}
+
 /// Items in a module.
 #[derive(Debug, Clone)]
 pub enum Item {
@@ -110,6 +260,8 @@ pub enum Item {
     Alias(Alias),
     /// Struct definitions.
     Struct(StructType),
+    /// Enum definitions.
+    Enum(EnumType),
 }
 
 impl Item {
@@ -117,6 +269,15 @@ impl Item {
         match self {
             Item::Struct(struct_ty) => struct_ty.span,
             Item::Alias(alias) => alias.span,
+            Item::Enum(enum_ty) => enum_ty.span,
+        }
+    }
+
+    pub fn name(&self) -> &Label {
+        match self {
+            Item::Struct(struct_ty) => &struct_ty.name,
+            Item::Alias(alias) => &alias.name,
+            Item::Enum(enum_ty) => &enum_ty.name,
         }
     }
 
@@ -128,6 +289,7 @@ impl Item {
         match self {
             Item::Alias(alias) => alias.doc(alloc),
             Item::Struct(struct_ty) => struct_ty.doc(alloc),
+            Item::Enum(enum_ty) => enum_ty.doc(alloc),
         }
     }
 }
@@ -137,6 +299,7 @@ impl PartialEq for Item {
         match (self, other) {
             (Item::Alias(alias0), Item::Alias(alias1)) => *alias0 == *alias1,
             (Item::Struct(struct_ty0), Item::Struct(struct_ty1)) => *struct_ty0 == *struct_ty1,
+            (Item::Enum(enum_ty0), Item::Enum(enum_ty1)) => *enum_ty0 == *enum_ty1,
             (_, _) => false,
         }
     }
@@ -197,10 +360,19 @@ pub struct StructType {
     pub span: Span,
     /// Doc comment.
     pub doc: Arc<[String]>,
+    /// Whether the struct is represented as a newtype around its only field.
+    pub transparent: bool,
+    /// The identifier to use in generated code, in place of the name.
+    pub rename: Option<Label>,
     /// Name of this definition.
     pub name: Label,
     /// Fields in the struct.
     pub fields: Vec<TypeField>,
+    /// Whether the data must end after the last field.
+    pub eof: bool,
+    /// The name of a field that captures any bytes remaining after the last
+    /// field, up to the end of the data.
+    pub rest: Option<Label>,
 }
 
 impl StructType {
@@ -215,13 +387,36 @@ impl StructType {
                 .append(alloc.newline())
         }));
 
+        let transparent = match self.transparent {
+            true => (alloc.nil()).append("@transparent").append(alloc.newline()),
+            false => alloc.nil(),
+        };
+        let rename = match &self.rename {
+            None => alloc.nil(),
+            Some(rename) => (alloc.nil())
+                .append(format!("@name({})", rename))
+                .append(alloc.newline()),
+        };
+
         let struct_prefix = (alloc.nil())
             .append("struct")
             .append(alloc.space())
             .append(self.name.doc(alloc))
             .append(alloc.space());
 
-        let struct_ty = if self.fields.is_empty() {
+        let eof = match self.eof {
+            true => (alloc.nil()).append(alloc.newline()).append("eof,").nest(4),
+            false => alloc.nil(),
+        };
+        let rest = match &self.rest {
+            None => alloc.nil(),
+            Some(rest) => (alloc.nil())
+                .append(alloc.newline())
+                .append(format!("...{},", rest))
+                .nest(4),
+        };
+
+        let struct_ty = if self.fields.is_empty() && !self.eof && self.rest.is_none() {
             (alloc.nil()).append(struct_prefix).append("{}").group()
         } else {
             (alloc.nil())
@@ -235,17 +430,28 @@ impl StructType {
                         .nest(4)
                         .group()
                 })))
+                .append(eof)
+                .append(rest)
                 .append(alloc.newline())
                 .append("}")
         };
 
-        (alloc.nil()).append(docs).append(struct_ty)
+        (alloc.nil())
+            .append(docs)
+            .append(transparent)
+            .append(rename)
+            .append(struct_ty)
     }
 }
 
 impl PartialEq for StructType {
     fn eq(&self, other: &StructType) -> bool {
-        self.name == other.name && self.fields == other.fields
+        self.transparent == other.transparent
+            && self.rename == other.rename
+            && self.name == other.name
+            && self.fields == other.fields
+            && self.eof == other.eof
+            && self.rest == other.rest
     }
 }
 
@@ -253,6 +459,18 @@ impl PartialEq for StructType {
 #[derive(Debug, Clone)]
 pub struct TypeField {
     pub doc: Arc<[String]>,
+    /// The alignment, in bytes, that the field is asserted to start at.
+    pub align: Option<u64>,
+    /// The feature that must be enabled for this field to be included.
+    pub cfg: Option<Label>,
+    /// The value to use if the data ends before this field.
+    pub default: Option<Term>,
+    /// The magic number that this field must contain.
+    pub magic: Option<Term>,
+    /// The identifier to use in generated code, in place of the name.
+    pub rename: Option<Label>,
+    /// Whether the field is read without its value being made available.
+    pub reserved: bool,
     pub start: ByteIndex,
     pub name: Label,
     pub term: Term,
@@ -274,8 +492,53 @@ impl TypeField {
                 .append(alloc.newline())
         }));
 
+        let align = match self.align {
+            None => alloc.nil(),
+            Some(align) => (alloc.nil())
+                .append(format!("@aligned({})", align))
+                .append(alloc.space()),
+        };
+        let cfg = match &self.cfg {
+            None => alloc.nil(),
+            Some(feature) => (alloc.nil())
+                .append(format!("@cfg({})", feature))
+                .append(alloc.space()),
+        };
+        let default = match &self.default {
+            None => alloc.nil(),
+            Some(default) => (alloc.nil())
+                .append("@default(")
+                .append(default.doc(alloc))
+                .append(")")
+                .append(alloc.space()),
+        };
+        let magic = match &self.magic {
+            None => alloc.nil(),
+            Some(magic) => (alloc.nil())
+                .append("@magic(")
+                .append(magic.doc(alloc))
+                .append(")")
+                .append(alloc.space()),
+        };
+        let rename = match &self.rename {
+            None => alloc.nil(),
+            Some(rename) => (alloc.nil())
+                .append(format!("@name({})", rename))
+                .append(alloc.space()),
+        };
+        let reserved = match self.reserved {
+            false => alloc.nil(),
+            true => (alloc.nil()).append("@reserved").append(alloc.space()),
+        };
+
         (alloc.nil())
             .append(docs)
+            .append(align)
+            .append(cfg)
+            .append(default)
+            .append(magic)
+            .append(rename)
+            .append(reserved)
             .append(
                 (alloc.nil())
                     .append(self.name.doc(alloc))
@@ -292,14 +555,152 @@ impl TypeField {
     }
 }
 
+/// An enum type definition.
+#[derive(Debug, Clone)]
+pub struct EnumType {
+    /// The full span of this definition.
+    pub span: Span,
+    /// Doc comment.
+    pub doc: Arc<[String]>,
+    /// Name of this definition.
+    pub name: Label,
+    /// The integer format that the variants are read from.
+    pub repr: Term,
+    /// Variants in the enum.
+    pub variants: Vec<EnumVariant>,
+}
+
+impl EnumType {
+    pub fn doc<'core, D>(&'core self, alloc: &'core D) -> DocBuilder<'core, D>
+    where
+        D: DocAllocator<'core>,
+        D::Doc: Clone,
+    {
+        let docs = alloc.concat(self.doc.iter().map(|line| {
+            (alloc.nil())
+                .append(format!("///{}", line))
+                .append(alloc.newline())
+        }));
+
+        let enum_prefix = (alloc.nil())
+            .append("enum")
+            .append(alloc.space())
+            .append(self.name.doc(alloc))
+            .append(alloc.space())
+            .append(":")
+            .append(alloc.space())
+            .append(self.repr.doc(alloc))
+            .append(alloc.space());
+
+        let enum_ty = if self.variants.is_empty() {
+            (alloc.nil()).append(enum_prefix).append("{}").group()
+        } else {
+            (alloc.nil())
+                .append(enum_prefix)
+                .append("{")
+                .group()
+                .append(alloc.concat(self.variants.iter().map(|variant| {
+                    (alloc.nil())
+                        .append(alloc.newline())
+                        .append(variant.doc(alloc))
+                        .nest(4)
+                        .group()
+                })))
+                .append(alloc.newline())
+                .append("}")
+        };
+
+        (alloc.nil()).append(docs).append(enum_ty)
+    }
+}
+
+impl PartialEq for EnumType {
+    fn eq(&self, other: &EnumType) -> bool {
+        self.name == other.name && self.repr == other.repr && self.variants == other.variants
+    }
+}
+
+/// A variant in an enum type definition.
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    pub doc: Arc<[String]>,
+    pub start: ByteIndex,
+    pub name: Label,
+    /// The integer that the variant is represented by.
+    pub value: Term,
+}
+
+impl EnumVariant {
+    pub fn span(&self) -> Span {
+        Span::new(self.start, self.value.span().end())
+    }
+
+    pub fn doc<'core, D>(&'core self, alloc: &'core D) -> DocBuilder<'core, D>
+    where
+        D: DocAllocator<'core>,
+        D::Doc: Clone,
+    {
+        let docs = alloc.concat(self.doc.iter().map(|line| {
+            (alloc.nil())
+                .append(format!("///{}", line))
+                .append(alloc.newline())
+        }));
+
+        (alloc.nil())
+            .append(docs)
+            .append(
+                (alloc.nil())
+                    .append(self.name.doc(alloc))
+                    .append(alloc.space())
+                    .append("=")
+                    .group(),
+            )
+            .append(
+                (alloc.nil())
+                    .append(alloc.space())
+                    .append(self.value.doc(alloc))
+                    .append(","),
+            )
+    }
+}
+
+impl PartialEq for EnumVariant {
+    fn eq(&self, other: &EnumVariant) -> bool {
+        self.name == other.name && self.value == other.value
+    }
+}
+
+/// Attributes that can be attached to structure types in the core syntax.
+enum StructAttribute {
+    Transparent,
+    Name(Label),
+}
+
+/// Attributes that can be attached to fields in the core syntax.
+enum FieldAttribute {
+    Aligned(u64),
+    Cfg(Label),
+    Default(Term),
+    Magic(Term),
+    Name(Label),
+    Reserved,
+}
+
 impl PartialEq for TypeField {
     fn eq(&self, other: &TypeField) -> bool {
-        self.name == other.name && self.term == other.term
+        self.align == other.align
+            && self.cfg == other.cfg
+            && self.default == other.default
+            && self.magic == other.magic
+            && self.rename == other.rename
+            && self.reserved == other.reserved
+            && self.name == other.name
+            && self.term == other.term
     }
 }
 
 /// Universes.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Universe {
     Type,
     Format,
@@ -346,6 +747,10 @@ pub enum Term {
     U64LeType(Span),
     /// Unsigned 64-bit integer type (big endian).
     U64BeType(Span),
+    /// Unsigned 128-bit integer type (little endian).
+    U128LeType(Span),
+    /// Unsigned 128-bit integer type (big endian).
+    U128BeType(Span),
     /// Signed, two's complement 8-bit integer type.
     S8Type(Span),
     /// Signed, two's complement 16-bit integer type (little endian).
@@ -360,6 +765,14 @@ pub enum Term {
     S64LeType(Span),
     /// Signed, two's complement 64-bit integer type (big endian).
     S64BeType(Span),
+    /// Signed, two's complement 128-bit integer type (little endian).
+    S128LeType(Span),
+    /// Signed, two's complement 128-bit integer type (big endian).
+    S128BeType(Span),
+    /// IEEE-754 half-precision floating point number type (little endian).
+    F16LeType(Span),
+    /// IEEE-754 half-precision floating point number type (big endian).
+    F16BeType(Span),
     /// IEEE-754 single-precision floating point number type (little endian).
     F32LeType(Span),
     /// IEEE-754 single-precision floating point number type (big endian).
@@ -368,6 +781,8 @@ pub enum Term {
     F64LeType(Span),
     /// IEEE-754 double-precision floating point number type (big endian).
     F64BeType(Span),
+    /// Null-terminated byte string type.
+    CStrType(Span),
 
     /// Host boolean type.
     BoolType(Span),
@@ -406,6 +821,8 @@ impl Term {
             | Term::U32BeType(span)
             | Term::U64LeType(span)
             | Term::U64BeType(span)
+            | Term::U128LeType(span)
+            | Term::U128BeType(span)
             | Term::S8Type(span)
             | Term::S16LeType(span)
             | Term::S16BeType(span)
@@ -413,10 +830,15 @@ impl Term {
             | Term::S32BeType(span)
             | Term::S64LeType(span)
             | Term::S64BeType(span)
+            | Term::S128LeType(span)
+            | Term::S128BeType(span)
+            | Term::F16LeType(span)
+            | Term::F16BeType(span)
             | Term::F32LeType(span)
             | Term::F32BeType(span)
             | Term::F64LeType(span)
             | Term::F64BeType(span)
+            | Term::CStrType(span)
             | Term::BoolType(span)
             | Term::IntType(span)
             | Term::F32Type(span)
@@ -431,6 +853,113 @@ impl Term {
         }
     }
 
+    /// Add the names of the items referred to by this term to `references`,
+    /// skipping any that are already present.
+    fn item_references(&self, references: &mut Vec<String>) {
+        match self {
+            Term::Item(_, label) => {
+                if !references.contains(&label.0) {
+                    references.push(label.0.clone());
+                }
+            }
+            Term::Ann(term, ty) => {
+                term.item_references(references);
+                ty.item_references(references);
+            }
+            Term::BoolElim(_, term, if_true, if_false) => {
+                term.item_references(references);
+                if_true.item_references(references);
+                if_false.item_references(references);
+            }
+            Term::Universe(_, _)
+            | Term::U8Type(_)
+            | Term::U16LeType(_)
+            | Term::U16BeType(_)
+            | Term::U32LeType(_)
+            | Term::U32BeType(_)
+            | Term::U64LeType(_)
+            | Term::U64BeType(_)
+            | Term::U128LeType(_)
+            | Term::U128BeType(_)
+            | Term::S8Type(_)
+            | Term::S16LeType(_)
+            | Term::S16BeType(_)
+            | Term::S32LeType(_)
+            | Term::S32BeType(_)
+            | Term::S64LeType(_)
+            | Term::S64BeType(_)
+            | Term::S128LeType(_)
+            | Term::S128BeType(_)
+            | Term::F16LeType(_)
+            | Term::F16BeType(_)
+            | Term::F32LeType(_)
+            | Term::F32BeType(_)
+            | Term::F64LeType(_)
+            | Term::F64BeType(_)
+            | Term::CStrType(_)
+            | Term::BoolType(_)
+            | Term::IntType(_)
+            | Term::F32Type(_)
+            | Term::F64Type(_)
+            | Term::BoolConst(_, _)
+            | Term::IntConst(_, _)
+            | Term::F32Const(_, _)
+            | Term::F64Const(_, _)
+            | Term::Error(_) => {}
+        }
+    }
+
+    /// Call `on_constant` with each integer constant in this term.
+    fn int_constants(&self, on_constant: &mut dyn FnMut(Span, &BigInt)) {
+        match self {
+            Term::IntConst(span, value) => on_constant(*span, value),
+            Term::Ann(term, ty) => {
+                term.int_constants(on_constant);
+                ty.int_constants(on_constant);
+            }
+            Term::BoolElim(_, term, if_true, if_false) => {
+                term.int_constants(on_constant);
+                if_true.int_constants(on_constant);
+                if_false.int_constants(on_constant);
+            }
+            Term::Universe(_, _)
+            | Term::U8Type(_)
+            | Term::U16LeType(_)
+            | Term::U16BeType(_)
+            | Term::U32LeType(_)
+            | Term::U32BeType(_)
+            | Term::U64LeType(_)
+            | Term::U64BeType(_)
+            | Term::U128LeType(_)
+            | Term::U128BeType(_)
+            | Term::S8Type(_)
+            | Term::S16LeType(_)
+            | Term::S16BeType(_)
+            | Term::S32LeType(_)
+            | Term::S32BeType(_)
+            | Term::S64LeType(_)
+            | Term::S64BeType(_)
+            | Term::S128LeType(_)
+            | Term::S128BeType(_)
+            | Term::F16LeType(_)
+            | Term::F16BeType(_)
+            | Term::F32LeType(_)
+            | Term::F32BeType(_)
+            | Term::F64LeType(_)
+            | Term::F64BeType(_)
+            | Term::CStrType(_)
+            | Term::BoolType(_)
+            | Term::IntType(_)
+            | Term::F32Type(_)
+            | Term::F64Type(_)
+            | Term::BoolConst(_, _)
+            | Term::Item(_, _)
+            | Term::F32Const(_, _)
+            | Term::F64Const(_, _)
+            | Term::Error(_) => {}
+        }
+    }
+
     pub fn doc<'core, D>(&'core self, alloc: &'core D) -> DocBuilder<'core, D>
     where
         D: DocAllocator<'core>,
@@ -480,31 +1009,39 @@ impl Term {
                             .nest(4),
                     ),
             ),
-            Term::Universe(_, universe) => universe.doc(alloc),
-            Term::U8Type(_) => alloc.text("U8"),
-            Term::U16LeType(_) => alloc.text("U16Le"),
-            Term::U16BeType(_) => alloc.text("U16Be"),
-            Term::U32LeType(_) => alloc.text("U32Le"),
-            Term::U32BeType(_) => alloc.text("U32Be"),
-            Term::U64LeType(_) => alloc.text("U64Le"),
-            Term::U64BeType(_) => alloc.text("U64Be"),
-            Term::S8Type(_) => alloc.text("S8"),
-            Term::S16LeType(_) => alloc.text("S16Le"),
-            Term::S16BeType(_) => alloc.text("S16Be"),
-            Term::S32LeType(_) => alloc.text("S32Le"),
-            Term::S32BeType(_) => alloc.text("S32Be"),
-            Term::S64LeType(_) => alloc.text("S64Le"),
-            Term::S64BeType(_) => alloc.text("S64Be"),
-            Term::F32LeType(_) => alloc.text("F32Le"),
-            Term::F32BeType(_) => alloc.text("F32Be"),
-            Term::F64LeType(_) => alloc.text("F64Le"),
-            Term::F64BeType(_) => alloc.text("F64Be"),
-            Term::BoolType(_) => alloc.text("Bool"),
-            Term::IntType(_) => alloc.text("Int"),
-            Term::F32Type(_) => alloc.text("F32"),
-            Term::F64Type(_) => alloc.text("F64"),
-            Term::BoolConst(_, true) => alloc.text("true"),
-            Term::BoolConst(_, false) => alloc.text("false"),
+            Term::Universe(_, _)
+            | Term::U8Type(_)
+            | Term::U16LeType(_)
+            | Term::U16BeType(_)
+            | Term::U32LeType(_)
+            | Term::U32BeType(_)
+            | Term::U64LeType(_)
+            | Term::U64BeType(_)
+            | Term::U128LeType(_)
+            | Term::U128BeType(_)
+            | Term::S8Type(_)
+            | Term::S16LeType(_)
+            | Term::S16BeType(_)
+            | Term::S32LeType(_)
+            | Term::S32BeType(_)
+            | Term::S64LeType(_)
+            | Term::S64BeType(_)
+            | Term::S128LeType(_)
+            | Term::S128BeType(_)
+            | Term::F16LeType(_)
+            | Term::F16BeType(_)
+            | Term::F32LeType(_)
+            | Term::F32BeType(_)
+            | Term::F64LeType(_)
+            | Term::F64BeType(_)
+            | Term::CStrType(_)
+            | Term::BoolType(_)
+            | Term::IntType(_)
+            | Term::F32Type(_)
+            | Term::F64Type(_)
+            | Term::BoolConst(_, _) => {
+                alloc.text(Builtin::from_term(self).expect("builtin term").name())
+            }
             Term::IntConst(_, value) => (alloc.nil())
                 .append("int")
                 .append(alloc.space())
@@ -535,6 +1072,17 @@ impl Term {
     }
 }
 
+/// Renders the term in the core syntax, without delaborating it first. This
+/// keeps the `item` and literal prefixes that the surface syntax leaves out.
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arena = pretty::Arena::new();
+        let pretty::DocBuilder(_, doc) = self.doc(&arena);
+
+        doc.pretty(100).fmt(f)
+    }
+}
+
 impl PartialEq for Term {
     fn eq(&self, other: &Term) -> bool {
         match (self, other) {
@@ -556,6 +1104,8 @@ impl PartialEq for Term {
             | (Term::U32BeType(_), Term::U32BeType(_))
             | (Term::U64LeType(_), Term::U64LeType(_))
             | (Term::U64BeType(_), Term::U64BeType(_))
+            | (Term::U128LeType(_), Term::U128LeType(_))
+            | (Term::U128BeType(_), Term::U128BeType(_))
             | (Term::S8Type(_), Term::S8Type(_))
             | (Term::S16LeType(_), Term::S16LeType(_))
             | (Term::S16BeType(_), Term::S16BeType(_))
@@ -563,10 +1113,15 @@ impl PartialEq for Term {
             | (Term::S32BeType(_), Term::S32BeType(_))
             | (Term::S64LeType(_), Term::S64LeType(_))
             | (Term::S64BeType(_), Term::S64BeType(_))
+            | (Term::S128LeType(_), Term::S128LeType(_))
+            | (Term::S128BeType(_), Term::S128BeType(_))
+            | (Term::F16LeType(_), Term::F16LeType(_))
+            | (Term::F16BeType(_), Term::F16BeType(_))
             | (Term::F32LeType(_), Term::F32LeType(_))
             | (Term::F32BeType(_), Term::F32BeType(_))
             | (Term::F64LeType(_), Term::F64LeType(_))
             | (Term::F64BeType(_), Term::F64BeType(_))
+            | (Term::CStrType(_), Term::CStrType(_))
             | (Term::BoolType(_), Term::BoolType(_))
             | (Term::IntType(_), Term::IntType(_))
             | (Term::F64Type(_), Term::F64Type(_))
@@ -577,8 +1132,36 @@ impl PartialEq for Term {
     }
 }
 
+impl Eq for Term {}
+
+impl Hash for Term {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Term::Item(_, label) => label.hash(state),
+            Term::Ann(term, ty) => {
+                term.hash(state);
+                ty.hash(state);
+            }
+            Term::BoolConst(_, value) => value.hash(state),
+            Term::IntConst(_, value) => value.hash(state),
+            Term::F32Const(_, value) => ieee754::logical_hash(*value, state),
+            Term::F64Const(_, value) => ieee754::logical_hash(*value, state),
+            Term::Universe(_, universe) => universe.hash(state),
+            Term::BoolElim(_, head, if_true, if_false) => {
+                head.hash(state);
+                if_true.hash(state);
+                if_false.hash(state);
+            }
+            // Spans are ignored, so the remaining terms are distinguished
+            // only by their discriminant.
+            _ => {}
+        }
+    }
+}
+
 /// The head of a neutral term.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Head {
     /// Item references.
     Item(Label),
@@ -587,14 +1170,14 @@ pub enum Head {
 }
 
 /// An eliminator that is 'stuck' on some head.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Elim {
     // FIXME: environment?
     Bool(Arc<Term>, Arc<Term>),
 }
 
 /// Values.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub enum Value {
     /// Neutral terms
     Neutral(Head, Vec<Elim>),
@@ -616,6 +1199,10 @@ pub enum Value {
     U64LeType,
     /// Unsigned 64-bit integer type (big endian).
     U64BeType,
+    /// Unsigned 128-bit integer type (little endian).
+    U128LeType,
+    /// Unsigned 128-bit integer type (big endian).
+    U128BeType,
     /// Signed, two's complement 8-bit integer type.
     S8Type,
     /// Signed, two's complement 16-bit integer type (little endian).
@@ -630,6 +1217,14 @@ pub enum Value {
     S64LeType,
     /// Signed, two's complement 64-bit integer type (big endian).
     S64BeType,
+    /// Signed, two's complement 128-bit integer type (little endian).
+    S128LeType,
+    /// Signed, two's complement 128-bit integer type (big endian).
+    S128BeType,
+    /// IEEE-754 half-precision floating point number type (little endian).
+    F16LeType,
+    /// IEEE-754 half-precision floating point number type (big endian).
+    F16BeType,
     /// IEEE-754 single-precision floating point number type (little endian).
     F32LeType,
     /// IEEE-754 single-precision floating point number type (big endian).
@@ -638,6 +1233,8 @@ pub enum Value {
     F64LeType,
     /// IEEE-754 double-precision floating point number type (big endian).
     F64BeType,
+    /// Null-terminated byte string type.
+    CStrType,
 
     /// Host boolean type.
     BoolType,
@@ -660,3 +1257,212 @@ pub enum Value {
     /// Error sentinel.
     Error,
 }
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Neutral(head0, elims0), Value::Neutral(head1, elims1)) => {
+                head0 == head1 && elims0 == elims1
+            }
+            (Value::BoolConst(val0), Value::BoolConst(val1)) => val0 == val1,
+            (Value::IntConst(val0), Value::IntConst(val1)) => val0 == val1,
+            (Value::F32Const(val0), Value::F32Const(val1)) => ieee754::logical_eq(*val0, *val1),
+            (Value::F64Const(val0), Value::F64Const(val1)) => ieee754::logical_eq(*val0, *val1),
+            (Value::Universe(universe0), Value::Universe(universe1)) => universe0 == universe1,
+            (Value::U8Type, Value::U8Type)
+            | (Value::U16LeType, Value::U16LeType)
+            | (Value::U16BeType, Value::U16BeType)
+            | (Value::U32LeType, Value::U32LeType)
+            | (Value::U32BeType, Value::U32BeType)
+            | (Value::U64LeType, Value::U64LeType)
+            | (Value::U64BeType, Value::U64BeType)
+            | (Value::U128LeType, Value::U128LeType)
+            | (Value::U128BeType, Value::U128BeType)
+            | (Value::S8Type, Value::S8Type)
+            | (Value::S16LeType, Value::S16LeType)
+            | (Value::S16BeType, Value::S16BeType)
+            | (Value::S32LeType, Value::S32LeType)
+            | (Value::S32BeType, Value::S32BeType)
+            | (Value::S64LeType, Value::S64LeType)
+            | (Value::S64BeType, Value::S64BeType)
+            | (Value::S128LeType, Value::S128LeType)
+            | (Value::S128BeType, Value::S128BeType)
+            | (Value::F16LeType, Value::F16LeType)
+            | (Value::F16BeType, Value::F16BeType)
+            | (Value::F32LeType, Value::F32LeType)
+            | (Value::F32BeType, Value::F32BeType)
+            | (Value::F64LeType, Value::F64LeType)
+            | (Value::F64BeType, Value::F64BeType)
+            | (Value::CStrType, Value::CStrType)
+            | (Value::BoolType, Value::BoolType)
+            | (Value::IntType, Value::IntType)
+            | (Value::F32Type, Value::F32Type)
+            | (Value::F64Type, Value::F64Type)
+            | (Value::Error, Value::Error) => true,
+            (_, _) => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Value::Neutral(head, elims) => {
+                head.hash(state);
+                elims.hash(state);
+            }
+            Value::BoolConst(value) => value.hash(state),
+            Value::IntConst(value) => value.hash(state),
+            Value::F32Const(value) => ieee754::logical_hash(*value, state),
+            Value::F64Const(value) => ieee754::logical_hash(*value, state),
+            Value::Universe(universe) => universe.hash(state),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use codespan::Files;
+
+    use super::*;
+    use crate::lexer::{Lexer, CORE_KEYWORDS};
+
+    #[test]
+    fn dependency_graph() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "A = U8; struct B { first : item A, second : item A, } C = item B : Format; D = U16Be;",
+        );
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let graph = module.dependency_graph();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph["A"], Vec::<String>::new());
+        assert_eq!(graph["B"], vec!["A".to_owned()]);
+        assert_eq!(graph["C"], vec!["B".to_owned()]);
+        assert_eq!(graph["D"], Vec::<String>::new());
+    }
+
+    #[test]
+    fn items_topologically_sorted() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "struct B { first : item A, } A = U8; C = item B : Format;",
+        );
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let names = module
+            .items_topologically_sorted()
+            .unwrap()
+            .into_iter()
+            .map(|item| item.name().0.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn items_topologically_sorted_cycle() {
+        let mut files = Files::new();
+        let file_id = files.add("test", "D = U8; A = item B; B = item C; C = item A;");
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let cycle = module.items_topologically_sorted().unwrap_err();
+        assert_eq!(cycle, ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn constants() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "Magic = int 137 : Int; struct Header { id : U16Be, @default(int 2) version : U8, }",
+        );
+        let lexer = Lexer::new(&files, file_id, &CORE_KEYWORDS);
+        let mut diagnostics = Vec::new();
+        let module = Module::parse(file_id, lexer, &mut |d| diagnostics.push(d));
+        assert!(diagnostics.is_empty());
+
+        let constants = module.constants();
+        assert_eq!(
+            constants,
+            vec![
+                ConstantRef {
+                    span: Span::new(8, 15),
+                    value: 137.into(),
+                    item: "Magic".to_owned(),
+                    field: None,
+                },
+                ConstantRef {
+                    span: Span::new(60, 65),
+                    value: 2.into(),
+                    item: "Header".to_owned(),
+                    field: Some("version".to_owned()),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn hash_ignores_spans() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(term: &Term) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            term.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let term = |start: u32| {
+            let span = Span::new(start, start + 1);
+            Term::Ann(
+                Arc::new(Term::F64Const(span, f64::NAN)),
+                Arc::new(Term::F64Type(span)),
+            )
+        };
+        let term0 = term(0);
+        let term1 = term(10);
+
+        assert_eq!(term0, term1);
+        assert_eq!(hash(&term0), hash(&term1));
+
+        let mut cache = HashMap::new();
+        cache.insert(term0, "first");
+        cache.insert(term1, "second");
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache[&term(20)], "second");
+    }
+
+    #[test]
+    fn display_core_term() {
+        let span = Span::initial();
+        let term = Term::Ann(
+            Arc::new(Term::Ann(
+                Arc::new(Term::IntConst(span, 42.into())),
+                Arc::new(Term::IntType(span)),
+            )),
+            Arc::new(Term::Item(span, Label("Size".to_owned()))),
+        );
+
+        let arena = pretty::Arena::new();
+        let surface_term = crate::surface::delaborate::delaborate_term(&term);
+        let pretty::DocBuilder(_, surface_doc) = surface_term.doc(&arena);
+
+        assert_eq!(term.to_string(), "(int 42 : Int) : item Size");
+        assert_eq!(surface_doc.pretty(100).to_string(), "(42 : Int) : Size");
+    }
+}
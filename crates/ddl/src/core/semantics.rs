@@ -1,6 +1,7 @@
 //! Operational semantics of the data description language.
 
 use codespan::Span;
+use num_bigint::BigInt;
 use std::sync::Arc;
 
 use crate::core::{Elim, Head, Term, Value};
@@ -19,6 +20,8 @@ pub fn eval(term: &Term) -> Value {
         Term::U32BeType(_) => Value::U32BeType,
         Term::U64LeType(_) => Value::U64LeType,
         Term::U64BeType(_) => Value::U64BeType,
+        Term::U128LeType(_) => Value::U128LeType,
+        Term::U128BeType(_) => Value::U128BeType,
         Term::S8Type(_) => Value::S8Type,
         Term::S16LeType(_) => Value::S16LeType,
         Term::S16BeType(_) => Value::S16BeType,
@@ -26,10 +29,15 @@ pub fn eval(term: &Term) -> Value {
         Term::S32BeType(_) => Value::S32BeType,
         Term::S64LeType(_) => Value::S64LeType,
         Term::S64BeType(_) => Value::S64BeType,
+        Term::S128LeType(_) => Value::S128LeType,
+        Term::S128BeType(_) => Value::S128BeType,
+        Term::F16LeType(_) => Value::F16LeType,
+        Term::F16BeType(_) => Value::F16BeType,
         Term::F32LeType(_) => Value::F32LeType,
         Term::F32BeType(_) => Value::F32BeType,
         Term::F64LeType(_) => Value::F64LeType,
         Term::F64BeType(_) => Value::F64BeType,
+        Term::CStrType(_) => Value::CStrType,
         Term::BoolType(_) => Value::BoolType,
         Term::IntType(_) => Value::IntType,
         Term::F32Type(_) => Value::F32Type,
@@ -84,6 +92,8 @@ pub fn readback(value: &Value) -> Term {
         Value::U32BeType => Term::U32BeType(Span::initial()),
         Value::U64LeType => Term::U64LeType(Span::initial()),
         Value::U64BeType => Term::U64BeType(Span::initial()),
+        Value::U128LeType => Term::U128LeType(Span::initial()),
+        Value::U128BeType => Term::U128BeType(Span::initial()),
         Value::S8Type => Term::S8Type(Span::initial()),
         Value::S16LeType => Term::S16LeType(Span::initial()),
         Value::S16BeType => Term::S16BeType(Span::initial()),
@@ -91,10 +101,15 @@ pub fn readback(value: &Value) -> Term {
         Value::S32BeType => Term::S32BeType(Span::initial()),
         Value::S64LeType => Term::S64LeType(Span::initial()),
         Value::S64BeType => Term::S64BeType(Span::initial()),
+        Value::S128LeType => Term::S128LeType(Span::initial()),
+        Value::S128BeType => Term::S128BeType(Span::initial()),
+        Value::F16LeType => Term::F16LeType(Span::initial()),
+        Value::F16BeType => Term::F16BeType(Span::initial()),
         Value::F32LeType => Term::F32LeType(Span::initial()),
         Value::F32BeType => Term::F32BeType(Span::initial()),
         Value::F64LeType => Term::F64LeType(Span::initial()),
         Value::F64BeType => Term::F64BeType(Span::initial()),
+        Value::CStrType => Term::CStrType(Span::initial()),
         Value::BoolType => Term::BoolType(Span::initial()),
         Value::IntType => Term::IntType(Span::initial()),
         Value::F32Type => Term::F32Type(Span::initial()),
@@ -107,6 +122,69 @@ pub fn readback(value: &Value) -> Term {
     }
 }
 
+/// The host type that a primitive format type is read as, or `None` if the
+/// value is not a primitive format type.
+pub fn format_host_ty(format_ty: &Value) -> Option<Value> {
+    match format_ty {
+        Value::U8Type
+        | Value::U16LeType
+        | Value::U16BeType
+        | Value::U32LeType
+        | Value::U32BeType
+        | Value::U64LeType
+        | Value::U64BeType
+        | Value::U128LeType
+        | Value::U128BeType
+        | Value::S8Type
+        | Value::S16LeType
+        | Value::S16BeType
+        | Value::S32LeType
+        | Value::S32BeType
+        | Value::S64LeType
+        | Value::S64BeType
+        | Value::S128LeType
+        | Value::S128BeType => Some(Value::IntType),
+        Value::F16LeType | Value::F16BeType => Some(Value::F32Type),
+        Value::F32LeType | Value::F32BeType => Some(Value::F32Type),
+        Value::F64LeType | Value::F64BeType => Some(Value::F64Type),
+        _ => None,
+    }
+}
+
+/// The smallest and largest integers that can be read by a primitive integer
+/// format type, or `None` if the value is not a primitive integer format type.
+pub fn int_format_bounds(format_ty: &Value) -> Option<(BigInt, BigInt)> {
+    match format_ty {
+        Value::U8Type => Some((u8::min_value().into(), u8::max_value().into())),
+        Value::U16LeType | Value::U16BeType => {
+            Some((u16::min_value().into(), u16::max_value().into()))
+        }
+        Value::U32LeType | Value::U32BeType => {
+            Some((u32::min_value().into(), u32::max_value().into()))
+        }
+        Value::U64LeType | Value::U64BeType => {
+            Some((u64::min_value().into(), u64::max_value().into()))
+        }
+        Value::U128LeType | Value::U128BeType => {
+            Some((u128::min_value().into(), u128::max_value().into()))
+        }
+        Value::S8Type => Some((i8::min_value().into(), i8::max_value().into())),
+        Value::S16LeType | Value::S16BeType => {
+            Some((i16::min_value().into(), i16::max_value().into()))
+        }
+        Value::S32LeType | Value::S32BeType => {
+            Some((i32::min_value().into(), i32::max_value().into()))
+        }
+        Value::S64LeType | Value::S64BeType => {
+            Some((i64::min_value().into(), i64::max_value().into()))
+        }
+        Value::S128LeType | Value::S128BeType => {
+            Some((i128::min_value().into(), i128::max_value().into()))
+        }
+        _ => None,
+    }
+}
+
 /// Check that two values are equal.
 pub fn equal(val1: &Value, val2: &Value) -> bool {
     match (val1, val2) {
@@ -125,6 +203,8 @@ pub fn equal(val1: &Value, val2: &Value) -> bool {
         | (Value::U32BeType, Value::U32BeType)
         | (Value::U64LeType, Value::U64LeType)
         | (Value::U64BeType, Value::U64BeType)
+        | (Value::U128LeType, Value::U128LeType)
+        | (Value::U128BeType, Value::U128BeType)
         | (Value::S8Type, Value::S8Type)
         | (Value::S16LeType, Value::S16LeType)
         | (Value::S16BeType, Value::S16BeType)
@@ -132,10 +212,15 @@ pub fn equal(val1: &Value, val2: &Value) -> bool {
         | (Value::S32BeType, Value::S32BeType)
         | (Value::S64LeType, Value::S64LeType)
         | (Value::S64BeType, Value::S64BeType)
+        | (Value::S128LeType, Value::S128LeType)
+        | (Value::S128BeType, Value::S128BeType)
+        | (Value::F16LeType, Value::F16LeType)
+        | (Value::F16BeType, Value::F16BeType)
         | (Value::F32LeType, Value::F32LeType)
         | (Value::F32BeType, Value::F32BeType)
         | (Value::F64LeType, Value::F64LeType)
         | (Value::F64BeType, Value::F64BeType)
+        | (Value::CStrType, Value::CStrType)
         | (Value::BoolType, Value::BoolType)
         | (Value::IntType, Value::IntType)
         | (Value::F32Type, Value::F32Type)
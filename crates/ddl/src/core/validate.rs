@@ -7,7 +7,7 @@ use codespan::{FileId, Span};
 use codespan_reporting::diagnostic::{Diagnostic, Severity};
 use std::collections::HashMap;
 
-use crate::core::{semantics, Item, Label, Module, Term, TypeField, Universe, Value};
+use crate::core::{semantics, EnumType, Item, Label, Module, Term, TypeField, Universe, Value};
 use crate::diagnostics;
 
 /// Validate a module.
@@ -72,6 +72,17 @@ pub fn validate_items(
             }
             Item::Struct(struct_ty) => {
                 validate_struct_ty_fields(context.field_context(), &struct_ty.fields, report);
+                if let Some(rest) = &struct_ty.rest {
+                    if let Some(field) = struct_ty.fields.iter().find(|f| f.name == *rest) {
+                        report(diagnostics::field_redeclaration(
+                            Severity::Bug,
+                            context.file_id,
+                            rest,
+                            struct_ty.span,
+                            field.span(),
+                        ));
+                    }
+                }
 
                 match context.items.entry(struct_ty.name.clone()) {
                     Entry::Vacant(entry) => {
@@ -86,6 +97,100 @@ pub fn validate_items(
                     )),
                 }
             }
+            Item::Enum(enum_ty) => {
+                validate_enum_ty(&context.term_context(), enum_ty, report);
+
+                match context.items.entry(enum_ty.name.clone()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert((enum_ty.span, Value::Universe(Universe::Format)));
+                    }
+                    Entry::Occupied(entry) => report(diagnostics::item_redefinition(
+                        Severity::Bug,
+                        context.file_id,
+                        &enum_ty.name,
+                        enum_ty.span,
+                        entry.get().0,
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// Validate that the variants of an enum type are distinct, and can be read
+/// by its integer format.
+fn validate_enum_ty(
+    context: &TermContext<'_>,
+    enum_ty: &EnumType,
+    report: &mut dyn FnMut(Diagnostic),
+) {
+    use std::collections::hash_map::Entry;
+
+    check_term(
+        context,
+        &enum_ty.repr,
+        &Value::Universe(Universe::Format),
+        report,
+    );
+    let repr_ty = semantics::eval(&enum_ty.repr);
+    let bounds = match semantics::int_format_bounds(&repr_ty) {
+        Some((min, max)) if min >= i64::min_value().into() && max <= u64::max_value().into() => {
+            Some((min, max))
+        }
+        Some(_) | None => {
+            if repr_ty != Value::Error {
+                report(diagnostics::unsupported_enum_repr(
+                    Severity::Bug,
+                    context.file_id,
+                    enum_ty.repr.span(),
+                ));
+            }
+            None
+        }
+    };
+
+    let mut names = HashMap::new();
+    let mut values = HashMap::new();
+
+    for variant in &enum_ty.variants {
+        check_term(context, &variant.value, &Value::IntType, report);
+
+        match names.entry(variant.name.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(variant.span());
+            }
+            Entry::Occupied(entry) => report(diagnostics::variant_redeclaration(
+                Severity::Bug,
+                context.file_id,
+                &variant.name,
+                variant.span(),
+                *entry.get(),
+            )),
+        }
+
+        if let Value::IntConst(value) = semantics::eval(&variant.value) {
+            if let Some((min, max)) = &bounds {
+                if value < *min || value > *max {
+                    report(diagnostics::enum_variant_out_of_range(
+                        Severity::Bug,
+                        context.file_id,
+                        variant.value.span(),
+                        &repr_ty,
+                    ));
+                }
+            }
+            match values.entry(value) {
+                Entry::Vacant(entry) => {
+                    entry.insert(variant.span());
+                }
+                Entry::Occupied(entry) => report(diagnostics::duplicate_discriminant(
+                    Severity::Bug,
+                    context.file_id,
+                    entry.key(),
+                    variant.span(),
+                    *entry.get(),
+                )),
+            }
         }
     }
 }
@@ -135,6 +240,12 @@ pub fn validate_struct_ty_fields(
             &Value::Universe(Universe::Format),
             report,
         );
+        if let Some(default) = &field.default {
+            validate_field_default(&context.term_context(), field, default, report);
+        }
+        if let Some(magic) = &field.magic {
+            validate_field_magic(&context.term_context(), field, magic, report);
+        }
 
         match context.fields.entry(field.name.clone()) {
             Entry::Vacant(entry) => {
@@ -151,6 +262,80 @@ pub fn validate_struct_ty_fields(
     }
 }
 
+/// Validate that the default value of a field can be read by the field's format.
+fn validate_field_default(
+    context: &TermContext<'_>,
+    field: &TypeField,
+    default: &Term,
+    report: &mut dyn FnMut(Diagnostic),
+) {
+    let field_ty = semantics::eval(&field.term);
+    let host_ty = match semantics::format_host_ty(&field_ty) {
+        Some(host_ty) => host_ty,
+        None => {
+            report(diagnostics::unsupported_field_default(
+                Severity::Bug,
+                context.file_id,
+                default.span(),
+                field.term.span(),
+            ));
+            return;
+        }
+    };
+
+    check_term(context, default, &host_ty, report);
+
+    if let (Value::IntConst(value), Some((min, max))) = (
+        semantics::eval(default),
+        semantics::int_format_bounds(&field_ty),
+    ) {
+        if value < min || value > max {
+            report(diagnostics::field_default_out_of_range(
+                Severity::Bug,
+                context.file_id,
+                default.span(),
+                &field_ty,
+            ));
+        }
+    }
+}
+
+/// Validate that the magic number of a field can be read by the field's
+/// format, which must be an unsigned integer format.
+fn validate_field_magic(
+    context: &TermContext<'_>,
+    field: &TypeField,
+    magic: &Term,
+    report: &mut dyn FnMut(Diagnostic),
+) {
+    let field_ty = semantics::eval(&field.term);
+    let (min, max) = match semantics::int_format_bounds(&field_ty) {
+        Some((min, max)) if min == 0.into() && max <= u64::max_value().into() => (min, max),
+        Some(_) | None => {
+            report(diagnostics::unsupported_field_magic(
+                Severity::Bug,
+                context.file_id,
+                magic.span(),
+                field.term.span(),
+            ));
+            return;
+        }
+    };
+
+    check_term(context, magic, &Value::IntType, report);
+
+    if let Value::IntConst(value) = semantics::eval(magic) {
+        if value < min || value > max {
+            report(diagnostics::field_magic_out_of_range(
+                Severity::Bug,
+                context.file_id,
+                magic.span(),
+                &field_ty,
+            ));
+        }
+    }
+}
+
 /// Contextual information to be used when validating terms.
 pub struct TermContext<'items> {
     /// The file where the term is defined (for error reporting).
@@ -261,6 +446,8 @@ pub fn synth_term(
         | Term::U32BeType(_)
         | Term::U64LeType(_)
         | Term::U64BeType(_)
+        | Term::U128LeType(_)
+        | Term::U128BeType(_)
         | Term::S8Type(_)
         | Term::S16LeType(_)
         | Term::S16BeType(_)
@@ -268,10 +455,15 @@ pub fn synth_term(
         | Term::S32BeType(_)
         | Term::S64LeType(_)
         | Term::S64BeType(_)
+        | Term::S128LeType(_)
+        | Term::S128BeType(_)
+        | Term::F16LeType(_)
+        | Term::F16BeType(_)
         | Term::F32LeType(_)
         | Term::F32BeType(_)
         | Term::F64LeType(_)
-        | Term::F64BeType(_) => Value::Universe(Universe::Format),
+        | Term::F64BeType(_)
+        | Term::CStrType(_) => Value::Universe(Universe::Format),
         Term::BoolType(_) | Term::IntType(_) | Term::F32Type(_) | Term::F64Type(_) => {
             Value::Universe(Universe::Type)
         }
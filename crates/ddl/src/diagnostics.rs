@@ -2,6 +2,7 @@
 
 use codespan::{ByteIndex, FileId, Span};
 use codespan_reporting::diagnostic::{Diagnostic, Label, Severity};
+use num_bigint::BigInt;
 
 use crate::core;
 use crate::surface::delaborate;
@@ -27,6 +28,48 @@ pub fn field_redeclaration(
     }
 }
 
+pub fn variant_redeclaration(
+    severity: Severity,
+    file_id: FileId,
+    name: &core::Label,
+    found: Span,
+    original: Span,
+) -> Diagnostic {
+    Diagnostic {
+        severity,
+        code: None,
+        message: format!("variant `{}` is already declared", name),
+        primary_label: Label::new(file_id, found, "variant already declared"),
+        secondary_labels: vec![Label::new(
+            file_id,
+            original,
+            "previous variant declaration here",
+        )],
+        notes: vec![format!("`{}` must be defined only once per enum", name)],
+    }
+}
+
+pub fn duplicate_discriminant(
+    severity: Severity,
+    file_id: FileId,
+    value: &BigInt,
+    found: Span,
+    original: Span,
+) -> Diagnostic {
+    Diagnostic {
+        severity,
+        code: None,
+        message: format!("discriminant `{}` is used by more than one variant", value),
+        primary_label: Label::new(file_id, found, "discriminant already used"),
+        secondary_labels: vec![Label::new(
+            file_id,
+            original,
+            "previously used by this variant",
+        )],
+        notes: vec![],
+    }
+}
+
 pub fn item_redefinition(
     severity: Severity,
     file_id: FileId,
@@ -111,6 +154,136 @@ pub fn universe_mismatch(
     }
 }
 
+pub fn unsupported_field_default(
+    severity: Severity,
+    file_id: FileId,
+    default_span: Span,
+    field_ty_span: Span,
+) -> Diagnostic {
+    Diagnostic {
+        severity,
+        code: None,
+        message: "default values are only supported for numeric formats".to_owned(),
+        primary_label: Label::new(file_id, default_span, "unsupported default value"),
+        secondary_labels: vec![Label::new(
+            file_id,
+            field_ty_span,
+            "expected a numeric format here",
+        )],
+        notes: vec![],
+    }
+}
+
+pub fn field_default_out_of_range(
+    severity: Severity,
+    file_id: FileId,
+    default_span: Span,
+    field_ty: &core::Value,
+) -> Diagnostic {
+    let arena = pretty::Arena::new();
+
+    let field_ty = delaborate::delaborate_term(&core::semantics::readback(field_ty));
+    let pretty::DocBuilder(_, field_ty) = field_ty.doc(&arena);
+    let field_ty = field_ty.pretty(100);
+
+    Diagnostic {
+        severity,
+        code: None,
+        message: "default value is out of range".to_owned(),
+        primary_label: Label::new(
+            file_id,
+            default_span,
+            format!("cannot be read as `{}`", field_ty),
+        ),
+        secondary_labels: vec![],
+        notes: vec![],
+    }
+}
+
+pub fn unsupported_field_magic(
+    severity: Severity,
+    file_id: FileId,
+    magic_span: Span,
+    field_ty_span: Span,
+) -> Diagnostic {
+    Diagnostic {
+        severity,
+        code: None,
+        message: "magic numbers are only supported for unsigned integer formats of up to 64 bits"
+            .to_owned(),
+        primary_label: Label::new(file_id, magic_span, "unsupported magic number"),
+        secondary_labels: vec![Label::new(
+            file_id,
+            field_ty_span,
+            "expected an unsigned integer format here",
+        )],
+        notes: vec![],
+    }
+}
+
+pub fn field_magic_out_of_range(
+    severity: Severity,
+    file_id: FileId,
+    magic_span: Span,
+    field_ty: &core::Value,
+) -> Diagnostic {
+    let arena = pretty::Arena::new();
+
+    let field_ty = delaborate::delaborate_term(&core::semantics::readback(field_ty));
+    let pretty::DocBuilder(_, field_ty) = field_ty.doc(&arena);
+    let field_ty = field_ty.pretty(100);
+
+    Diagnostic {
+        severity,
+        code: None,
+        message: "magic number is out of range".to_owned(),
+        primary_label: Label::new(
+            file_id,
+            magic_span,
+            format!("cannot be read as `{}`", field_ty),
+        ),
+        secondary_labels: vec![],
+        notes: vec![],
+    }
+}
+
+pub fn unsupported_enum_repr(severity: Severity, file_id: FileId, repr_span: Span) -> Diagnostic {
+    Diagnostic {
+        severity,
+        code: None,
+        message: "enums are only supported for integer formats of up to 64 bits".to_owned(),
+        primary_label: Label::new(file_id, repr_span, "expected an integer format here"),
+        secondary_labels: vec![],
+        notes: vec![],
+    }
+}
+
+pub fn enum_variant_out_of_range(
+    severity: Severity,
+    file_id: FileId,
+    value_span: Span,
+    repr_ty: &core::Value,
+) -> Diagnostic {
+    let arena = pretty::Arena::new();
+
+    let repr_ty = delaborate::delaborate_term(&core::semantics::readback(repr_ty));
+    let pretty::DocBuilder(_, repr_ty) = repr_ty.doc(&arena);
+    let repr_ty = repr_ty.pretty(100);
+
+    Diagnostic {
+        severity,
+        code: None,
+        message: "enum variant is out of range".to_owned(),
+        primary_label: Label::new(
+            file_id,
+            value_span,
+            format!("cannot be read as `{}`", repr_ty),
+        ),
+        secondary_labels: vec![],
+        notes: vec![],
+    }
+}
+
 pub fn kind_has_no_type(severity: Severity, file_id: FileId, span: Span) -> Diagnostic {
     Diagnostic {
         severity,
@@ -123,6 +296,20 @@ pub fn kind_has_no_type(severity: Severity, file_id: FileId, span: Span) -> Diag
     }
 }
 
+pub fn missing_doc(severity: Severity, file_id: FileId, span: Span, name: &str) -> Diagnostic {
+    Diagnostic {
+        severity,
+        code: None,
+        message: format!("missing documentation for `{}`", name),
+        primary_label: Label::new(file_id, span, "no doc comment"),
+        secondary_labels: vec![],
+        notes: vec![format!(
+            "add a `///` comment before `{}` to describe it",
+            name,
+        )],
+    }
+}
+
 pub mod error {
     use codespan::ByteOffset;
     use lalrpop_util::ParseError;
@@ -254,6 +441,162 @@ pub mod error {
         }
     }
 
+    pub fn unknown_attribute(
+        file_id: FileId,
+        name: &str,
+        span: Span,
+        expected_names: &[&str],
+    ) -> Diagnostic {
+        let expected_names = expected_names
+            .iter()
+            .map(|name| format!("`{}`", name))
+            .collect::<Vec<_>>();
+
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: format!("unknown attribute `{}`", name),
+            primary_label: Label::new(file_id, span, "unknown attribute"),
+            secondary_labels: vec![],
+            notes: vec![format!("expected one of {}", expected_names.join(", "))],
+        }
+    }
+
+    pub fn duplicate_attribute(
+        file_id: FileId,
+        name: &str,
+        found: Span,
+        original: Span,
+    ) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: format!("attribute `{}` is used multiple times", name),
+            primary_label: Label::new(file_id, found, "duplicate attribute"),
+            secondary_labels: vec![Label::new(file_id, original, "previous use here")],
+            notes: vec![],
+        }
+    }
+
+    pub fn attribute_arg_count_mismatch(
+        file_id: FileId,
+        name: &str,
+        span: Span,
+        expected_count: usize,
+        found_count: usize,
+    ) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: format!("wrong number of arguments for attribute `{}`", name),
+            primary_label: Label::new(
+                file_id,
+                span,
+                format!(
+                    "expected {} arguments, found {}",
+                    expected_count, found_count
+                ),
+            ),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    pub fn invalid_alignment(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "invalid alignment".to_owned(),
+            primary_label: Label::new(file_id, span, "alignment must be a positive integer"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    pub fn transparent_field_count(file_id: FileId, span: Span, found_count: usize) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "transparent structs must have exactly one field".to_owned(),
+            primary_label: Label::new(
+                file_id,
+                span,
+                format!("expected 1 field, found {}", found_count),
+            ),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    pub fn transparent_conditional_field(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "the field of a transparent struct cannot be conditional".to_owned(),
+            primary_label: Label::new(file_id, span, "conditional field"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    pub fn transparent_reserved_field(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "the field of a transparent struct cannot be reserved".to_owned(),
+            primary_label: Label::new(file_id, span, "reserved field"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    pub fn invalid_cfg_feature(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "invalid feature name".to_owned(),
+            primary_label: Label::new(file_id, span, "expected a feature name"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
+    pub fn include_non_struct(file_id: FileId, name: &str, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: format!("cannot include `{}`", name),
+            primary_label: Label::new(file_id, span, "not a structure type"),
+            secondary_labels: vec![],
+            notes: vec!["only the fields of structure types can be included".to_owned()],
+        }
+    }
+
+    pub fn expected_format(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "expected a format, found a host type".to_owned(),
+            primary_label: Label::new(file_id, span, "host types cannot be read from binary data"),
+            secondary_labels: vec![],
+            notes: vec![
+                "use a format that reads this type, for example `U32Be` instead of `Int`"
+                    .to_owned(),
+            ],
+        }
+    }
+
+    pub fn invalid_name_override(file_id: FileId, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: "invalid name override".to_owned(),
+            primary_label: Label::new(file_id, span, "expected an identifier"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
     pub fn ambiguous_numeric_literal(file_id: FileId, span: Span) -> Diagnostic {
         Diagnostic {
             severity: Severity::Error,
@@ -264,6 +607,22 @@ pub mod error {
             notes: vec![],
         }
     }
+
+    /// An error encountered while reading binary data, where `file_id` refers
+    /// to the data itself, and `offset` is the offset of the reader when the
+    /// error occurred.
+    pub fn read_failure(file_id: FileId, offset: usize, error: &ddl_rt::ReadError) -> Diagnostic {
+        let offset = ByteIndex::from(offset as u32);
+
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: error.to_string(),
+            primary_label: Label::new(file_id, Span::new(offset, offset), "failed to read here"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
 }
 
 pub mod bug {
@@ -292,6 +651,17 @@ pub mod bug {
         }
     }
 
+    pub fn unknown_attribute(file_id: FileId, name: &str, span: Span) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Bug,
+            code: None,
+            message: format!("unknown attribute `{}`", name),
+            primary_label: Label::new(file_id, span, "unknown attribute"),
+            secondary_labels: vec![],
+            notes: vec![],
+        }
+    }
+
     pub fn unknown_global(file_id: FileId, name: &str, span: Span) -> Diagnostic {
         Diagnostic {
             severity: Severity::Bug,
@@ -304,3 +674,30 @@ pub mod bug {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use codespan::Files;
+
+    use super::*;
+    use crate::lexer::{Lexer, CORE_KEYWORDS};
+
+    #[test]
+    fn read_failure_offset() {
+        let mut files = Files::new();
+        let ddl_file_id = files.add("test.ddl", "struct Pair { first : U16Be, second : U32Be, }");
+        let lexer = Lexer::new(&files, ddl_file_id, &CORE_KEYWORDS);
+        let module = core::Module::parse(ddl_file_id, lexer, &mut |_| {});
+
+        let data = [0x01, 0x02, 0x03];
+        let data_file_id = files.add("test.bin", String::from_utf8_lossy(&data));
+        let mut reader = ddl_rt::ReadScope::new(&data).reader();
+        let error =
+            crate::binary::read::read_module_item(&module, "Pair", &mut reader).unwrap_err();
+
+        let diagnostic = error::read_failure(data_file_id, reader.offset(), &error);
+        assert_eq!(diagnostic.message, error.to_string());
+        assert_eq!(diagnostic.primary_label.file_id, data_file_id);
+        assert_eq!(diagnostic.primary_label.span, Span::new(2, 2));
+    }
+}
@@ -1,7 +1,9 @@
 //! Operations related to IEEE 754 floating point numbers.
 
+use std::hash::{Hash, Hasher};
+
 pub trait Ieee754: Copy {
-    type Bits: PartialEq;
+    type Bits: PartialEq + Hash;
 
     fn is_nan(self) -> bool;
     fn to_bits(self) -> Self::Bits;
@@ -50,13 +52,32 @@ pub fn logical_eq<T: Ieee754>(value0: T, value1: T) -> bool {
     value0.to_bits() == value1.to_bits() || value0.is_nan() && value1.is_nan()
 }
 
+/// Hash a floating point number by its bit pattern, consistently with
+/// `logical_eq`. All NaNs hash to the same value, regardless of their sign
+/// or payload.
+pub fn logical_hash<T: Ieee754, H: Hasher>(value: T, state: &mut H) {
+    if value.is_nan() {
+        None
+    } else {
+        Some(value.to_bits())
+    }
+    .hash(state)
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::num::{f32, f64};
-    use proptest::{prop_assert, proptest};
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+    use std::collections::hash_map::DefaultHasher;
 
     use super::*;
 
+    fn hash<T: Ieee754>(value: T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        logical_hash(value, &mut hasher);
+        hasher.finish()
+    }
+
     proptest! {
         #[test]
         fn f32_reflexive(value in f32::ANY) {
@@ -77,6 +98,14 @@ mod tests {
             prop_assert!(logical_eq(value0, value1));
         }
 
+        #[test]
+        fn f32_nan_hash_equiv(
+            value0 in f32::QUIET_NAN | f32::SIGNALING_NAN,
+            value1 in f32::QUIET_NAN | f32::SIGNALING_NAN,
+        ) {
+            prop_assert_eq!(hash(value0), hash(value1));
+        }
+
         #[test]
         fn f64_reflexive(value in f64::ANY) {
             prop_assert!(logical_eq(value, value));
@@ -95,5 +124,13 @@ mod tests {
         ) {
             prop_assert!(logical_eq(value0, value1));
         }
+
+        #[test]
+        fn f64_nan_hash_equiv(
+            value0 in f64::QUIET_NAN | f64::SIGNALING_NAN,
+            value1 in f64::QUIET_NAN | f64::SIGNALING_NAN,
+        ) {
+            prop_assert_eq!(hash(value0), hash(value1));
+        }
     }
 }
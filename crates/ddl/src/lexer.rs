@@ -11,6 +11,9 @@ type Keywords = HashMap<String, Token>;
 
 lazy_static::lazy_static! {
     pub static ref SURFACE_KEYWORDS: Keywords = hashmap! {
+        "enum".to_owned() => Token::Enum,
+        "eof".to_owned() => Token::Eof,
+        "include".to_owned() => Token::Include,
         "struct".to_owned() => Token::Struct,
         "if".to_owned() => Token::If,
         "else".to_owned() => Token::Else,
@@ -18,6 +21,8 @@ lazy_static::lazy_static! {
 
     pub static ref CORE_KEYWORDS: Keywords = hashmap! {
         "bool_elim".to_owned() => Token::BoolElim,
+        "enum".to_owned() => Token::Enum,
+        "eof".to_owned() => Token::Eof,
         "f32".to_owned() => Token::F32,
         "f64".to_owned() => Token::F64,
         "int".to_owned() => Token::Int,
@@ -46,12 +51,18 @@ pub enum Token {
     BoolElim,
     /// Keyword `else`
     Else,
+    /// Keyword `enum`
+    Enum,
+    /// Keyword `eof`
+    Eof,
     /// Keyword `f32`
     F32,
     /// Keyword `f64`
     F64,
     /// Keyword `if`
     If,
+    /// Keyword `include`
+    Include,
     /// Keyword `int`
     Int,
     /// Keyword `item`
@@ -68,12 +79,16 @@ pub enum Token {
     /// Close parenthesis:  `)`
     CloseParen,
 
+    /// At: `@`
+    At,
     /// Bang: `!`
     Bang,
     /// Colon: `:`
     Colon,
     /// Comma: `,`
     Comma,
+    /// Ellipsis: `...`
+    DotDotDot,
     /// Equals: `=`
     Equals,
     /// Semicolon: `;`
@@ -92,9 +107,12 @@ impl<'a> fmt::Display for Token {
 
             Token::BoolElim => write!(f, "bool_elim"),
             Token::Else => write!(f, "else"),
+            Token::Enum => write!(f, "enum"),
+            Token::Eof => write!(f, "eof"),
             Token::F32 => write!(f, "f32"),
             Token::F64 => write!(f, "f64"),
             Token::If => write!(f, "if"),
+            Token::Include => write!(f, "include"),
             Token::Int => write!(f, "int"),
             Token::Item => write!(f, "item"),
             Token::Struct => write!(f, "struct"),
@@ -104,9 +122,11 @@ impl<'a> fmt::Display for Token {
             Token::OpenParen => write!(f, "("),
             Token::CloseParen => write!(f, ")"),
 
+            Token::At => write!(f, "@"),
             Token::Bang => write!(f, "!"),
             Token::Colon => write!(f, ":"),
             Token::Comma => write!(f, ","),
+            Token::DotDotDot => write!(f, "..."),
             Token::Equals => write!(f, "="),
             Token::Semi => write!(f, ";"),
         }
@@ -295,9 +315,19 @@ impl<'input, 'keywords> Iterator for Lexer<'input, 'keywords> {
                 '}' => self.emit(Token::CloseBrace),
                 '(' => self.emit(Token::OpenParen),
                 ')' => self.emit(Token::CloseParen),
+                '@' => self.emit(Token::At),
                 '!' => self.emit(Token::Bang),
                 ':' => self.emit(Token::Colon),
                 ',' => self.emit(Token::Comma),
+                '.' => match self.advance() {
+                    Some('.') => match self.advance() {
+                        Some('.') => self.emit(Token::DotDotDot),
+                        Some(ch) => self.unexpected_char(self.token_end, ch, &["`.`"]),
+                        None => self.unexpected_eof(&["`.`"]),
+                    },
+                    Some(ch) => self.unexpected_char(self.token_end, ch, &["`.`"]),
+                    None => self.unexpected_eof(&["`.`"]),
+                },
                 '=' => self.emit(Token::Equals),
                 ';' => self.emit(Token::Semi),
                 '+' => match self.advance()? {
@@ -320,9 +350,11 @@ impl<'input, 'keywords> Iterator for Lexer<'input, 'keywords> {
                         "}",
                         "(",
                         ")",
+                        "@",
                         "!",
                         ":",
                         ",",
+                        "...",
                         "=",
                         ";",
                         "comment",    // `/`
@@ -1,7 +1,7 @@
 use std::io;
 use std::io::prelude::*;
 
-use crate::rust::{Alias, Const, Function, Item, Module, RtType, StructType, Term, Type};
+use crate::rust::{Alias, Const, EnumType, Function, Item, Module, RtType, StructType, Term, Type};
 
 // TODO: Make this path configurable
 const RT_NAME: &str = "ddl_rt";
@@ -24,10 +24,42 @@ pub fn emit_module(writer: &mut impl Write, module: &Module) -> io::Result<()> {
         }
     }
 
+    // Keep the lints of downstream crates from firing on generated code
+    writeln!(writer)?;
+    writeln!(writer, "#![allow(clippy::all)]")?;
+
     for item in &module.items {
         emit_item(writer, &item)?;
     }
 
+    let visited_structs = module
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(struct_ty) => Some((struct_ty.visit_method.as_ref()?, struct_ty)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !visited_structs.is_empty() {
+        writeln!(writer)?;
+        writeln!(writer, "/// Visitors over the structures in this module.")?;
+        writeln!(writer, "pub trait Visit {{")?;
+        for (i, (visit_method, struct_ty)) in visited_structs.iter().enumerate() {
+            if i != 0 {
+                writeln!(writer)?;
+            }
+            writeln!(
+                writer,
+                "    fn {}(&mut self, value: &{}) {{",
+                visit_method, struct_ty.name,
+            )?;
+            writeln!(writer, "        value.walk(self);")?;
+            writeln!(writer, "    }}")?;
+        }
+        writeln!(writer, "}}")?;
+    }
+
     Ok(())
 }
 
@@ -37,6 +69,7 @@ fn emit_item(writer: &mut impl Write, item: &Item) -> io::Result<()> {
         Item::Function(function) => emit_function(writer, function),
         Item::Alias(ty_alias) => emit_alias(writer, ty_alias),
         Item::Struct(struct_ty) => emit_struct_ty(writer, struct_ty),
+        Item::Enum(enum_ty) => emit_enum_ty(writer, enum_ty),
     }
 }
 
@@ -108,25 +141,41 @@ fn emit_struct_ty(writer: &mut impl Write, struct_ty: &StructType) -> io::Result
             struct_ty.derives.iter().format(", "),
         )?;
     }
-    if struct_ty.fields.is_empty() {
+    if let Some(field) = struct_ty.transparent_field() {
+        writeln!(writer, "#[repr(transparent)]")?;
+        write!(writer, "pub struct {}(pub ", struct_ty.name)?;
+        emit_ty(writer, &field.host_ty)?;
+        writeln!(writer, ");")?;
+    } else if struct_ty.stored_fields().next().is_none() && struct_ty.rest.is_none() {
         writeln!(writer, "pub struct {} {{}}", struct_ty.name)?;
     } else {
         writeln!(writer, "pub struct {} {{", struct_ty.name)?;
-        for field in &struct_ty.fields {
+        for field in struct_ty.stored_fields() {
+            emit_cfg(writer, "    ", &field.cfg)?;
             write!(writer, "    {}: ", field.name)?;
             emit_ty(writer, &field.host_ty)?;
             write!(writer, ",")?;
             writeln!(writer)?;
         }
+        if let Some(rest) = &struct_ty.rest {
+            writeln!(writer, "    {}: Vec<u8>,", rest)?;
+        }
         writeln!(writer, "}}")?;
     }
     writeln!(writer)?;
 
-    // Field accessors
+    // Field accessors and bounded reads
+
+    let accessor_fields = match struct_ty.transparent_field() {
+        Some(_) => Vec::new(),
+        None => struct_ty.stored_fields().collect(),
+    };
+
+    let has_accessors = !accessor_fields.is_empty() || struct_ty.rest.is_some();
 
-    if !struct_ty.fields.is_empty() {
+    if has_accessors || struct_ty.read_bounded || struct_ty.visit_method.is_some() {
         writeln!(writer, "impl {} {{", struct_ty.name,)?;
-        for (i, field) in struct_ty.fields.iter().enumerate() {
+        for (i, field) in accessor_fields.iter().enumerate() {
             let sigil = match field.by_ref {
                 true => "&",
                 false => "",
@@ -139,12 +188,61 @@ fn emit_struct_ty(writer: &mut impl Write, struct_ty: &StructType) -> io::Result
             for doc_line in field.doc.iter() {
                 writeln!(writer, "    ///{}", doc_line)?;
             }
+            emit_cfg(writer, "    ", &field.cfg)?;
             write!(writer, "    pub fn {}(&self) -> {}", field.name, sigil)?;
             emit_ty(writer, &field.host_ty)?;
             writeln!(writer, " {{")?;
             writeln!(writer, "        {}self.{}", sigil, field.name)?;
             writeln!(writer, "    }}")?;
         }
+        if let Some(rest) = &struct_ty.rest {
+            if !accessor_fields.is_empty() {
+                writeln!(writer)?;
+            }
+            writeln!(writer, "    pub fn {}(&self) -> &[u8] {{", rest)?;
+            writeln!(writer, "        &self.{}", rest)?;
+            writeln!(writer, "    }}")?;
+        }
+        if struct_ty.read_bounded {
+            if has_accessors {
+                writeln!(writer)?;
+            }
+            writeln!(
+                writer,
+                "    pub fn read_bounded(reader: &mut {rt}::FormatReader<'_>, max_bytes: usize) -> Result<{struct_ty}, {rt}::ReadError> {{",
+                rt = RT_NAME,
+                struct_ty = struct_ty.name,
+            )?;
+            writeln!(
+                writer,
+                "        reader.read_bounded::<{}>(max_bytes)",
+                struct_ty.name,
+            )?;
+            writeln!(writer, "    }}")?;
+        }
+        if let Some(visit_method) = &struct_ty.visit_method {
+            if has_accessors || struct_ty.read_bounded {
+                writeln!(writer)?;
+            }
+            emit_visitor_methods(writer, struct_ty, visit_method)?;
+        }
+        writeln!(writer, "}}")?;
+        writeln!(writer)?;
+    }
+
+    // Deref impl
+
+    if let Some(field) = struct_ty.transparent_field() {
+        writeln!(writer, "impl std::ops::Deref for {} {{", struct_ty.name)?;
+        write!(writer, "    type Target = ")?;
+        emit_ty(writer, &field.host_ty)?;
+        writeln!(writer, ";")?;
+        writeln!(writer)?;
+        write!(writer, "    fn deref(&self) -> &")?;
+        emit_ty(writer, &field.host_ty)?;
+        writeln!(writer, " {{")?;
+        writeln!(writer, "        &self.0")?;
+        writeln!(writer, "    }}")?;
         writeln!(writer, "}}")?;
         writeln!(writer)?;
     }
@@ -169,7 +267,7 @@ fn emit_struct_ty(writer: &mut impl Write, struct_ty: &StructType) -> io::Result
         rt = RT_NAME,
         struct_ty = struct_ty.name,
     )?;
-    if struct_ty.fields.is_empty() {
+    if struct_ty.fields.is_empty() && !struct_ty.eof && struct_ty.rest.is_none() {
         writeln!(
             writer,
             "    fn read(_: &mut {rt}::FormatReader<'data>) -> Result<{struct_ty}, {rt}::ReadError> {{",
@@ -185,18 +283,78 @@ fn emit_struct_ty(writer: &mut impl Write, struct_ty: &StructType) -> io::Result
             rt = RT_NAME,
             struct_ty = struct_ty.name,
         )?;
-        for field in &struct_ty.fields {
-            write!(writer, "        let {} = ", field.name)?;
-            emit_ty_read(writer, &field.format_ty)?;
+        for (index, field) in struct_ty.fields.iter().enumerate() {
+            if let Some(align) = field.align {
+                emit_cfg(writer, "        ", &field.cfg)?;
+                writeln!(
+                    writer,
+                    "        reader.check_aligned({:?}, {})?;",
+                    field.label, align,
+                )?;
+            }
+            let location_name = match &field.location {
+                None => None,
+                Some(location) => {
+                    // Named by index, as labels may only differ by case
+                    let location_name = format!("FIELD_{}_LOCATION", index);
+                    emit_cfg(writer, "        ", &field.cfg)?;
+                    writeln!(
+                        writer,
+                        "        const {}: {rt}::FieldLocation = {rt}::FieldLocation {{",
+                        location_name,
+                        rt = RT_NAME,
+                    )?;
+                    writeln!(writer, "            name: {:?},", field.label)?;
+                    writeln!(writer, "            file: {:?},", location.file)?;
+                    writeln!(writer, "            line: {},", location.line)?;
+                    writeln!(writer, "            column: {},", location.column)?;
+                    writeln!(writer, "        }};")?;
+                    Some(location_name)
+                }
+            };
+            let location_name = location_name.as_ref().map(String::as_str);
+            emit_cfg(writer, "        ", &field.cfg)?;
+            match field.reserved && field.magic.is_none() {
+                true => write!(writer, "        ")?,
+                false => write!(writer, "        let {} = ", field.name)?,
+            }
+            match &field.default {
+                None => emit_ty_read(writer, &field.format_ty, location_name)?,
+                Some(default) => emit_ty_read_or(writer, &field.format_ty, location_name, default)?,
+            }
             write!(writer, ";")?;
             writeln!(writer)?;
+            if let Some(magic) = &field.magic {
+                emit_cfg(writer, "        ", &field.cfg)?;
+                write!(writer, "        reader.check_magic({:?}, ", field.label)?;
+                emit_term(writer, magic)?;
+                writeln!(writer, ", {})?;", field.name)?;
+            }
+        }
+        if let Some(rest) = &struct_ty.rest {
+            writeln!(
+                writer,
+                "        let {} = reader.read_remaining().to_vec();",
+                rest
+            )?;
+        }
+        if struct_ty.eof {
+            writeln!(writer, "        reader.check_eof()?;")?;
         }
         writeln!(writer)?;
-        writeln!(writer, "        Ok({} {{", struct_ty.name)?;
-        for field in &struct_ty.fields {
-            writeln!(writer, "            {},", field.name)?;
+        if let Some(field) = struct_ty.transparent_field() {
+            writeln!(writer, "        Ok({}({}))", struct_ty.name, field.name)?;
+        } else {
+            writeln!(writer, "        Ok({} {{", struct_ty.name)?;
+            for field in struct_ty.stored_fields() {
+                emit_cfg(writer, "            ", &field.cfg)?;
+                writeln!(writer, "            {},", field.name)?;
+            }
+            if let Some(rest) = &struct_ty.rest {
+                writeln!(writer, "            {},", rest)?;
+            }
+            writeln!(writer, "        }})")?;
         }
-        writeln!(writer, "        }})")?;
         writeln!(writer, "    }}")?;
     }
     writeln!(writer, "}}")?;
@@ -204,6 +362,132 @@ fn emit_struct_ty(writer: &mut impl Write, struct_ty: &StructType) -> io::Result
     Ok(())
 }
 
+fn emit_enum_ty(writer: &mut impl Write, enum_ty: &EnumType) -> io::Result<()> {
+    writeln!(writer)?;
+
+    for doc_line in enum_ty.doc.iter() {
+        writeln!(writer, "///{}", doc_line)?;
+    }
+
+    writeln!(writer, "#[derive(Copy, Clone, Debug, PartialEq, Eq)]")?;
+    if enum_ty.variants.is_empty() {
+        writeln!(writer, "pub enum {} {{}}", enum_ty.name)?;
+    } else {
+        write!(writer, "#[repr(")?;
+        emit_ty(writer, &enum_ty.host_ty)?;
+        writeln!(writer, ")]")?;
+        writeln!(writer, "pub enum {} {{", enum_ty.name)?;
+        for variant in &enum_ty.variants {
+            for doc_line in variant.doc.iter() {
+                writeln!(writer, "    ///{}", doc_line)?;
+            }
+            write!(writer, "    {} = ", variant.name)?;
+            emit_term(writer, &variant.value)?;
+            writeln!(writer, ",")?;
+        }
+        writeln!(writer, "}}")?;
+    }
+    writeln!(writer)?;
+
+    // Format impl
+
+    writeln!(
+        writer,
+        "impl {rt}::Format for {enum_ty} {{",
+        rt = RT_NAME,
+        enum_ty = enum_ty.name,
+    )?;
+    writeln!(writer, "    type Host = {};", enum_ty.name)?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+
+    // ReadFormat impl
+
+    writeln!(
+        writer,
+        "impl<'data> {rt}::ReadFormat<'data> for {enum_ty} {{",
+        rt = RT_NAME,
+        enum_ty = enum_ty.name,
+    )?;
+    writeln!(
+        writer,
+        "    fn read(reader: &mut {rt}::FormatReader<'data>) -> Result<{enum_ty}, {rt}::ReadError> {{",
+        rt = RT_NAME,
+        enum_ty = enum_ty.name,
+    )?;
+    write!(writer, "        match ")?;
+    emit_ty_read(writer, &enum_ty.format_ty, None)?;
+    writeln!(writer, " {{")?;
+    for variant in &enum_ty.variants {
+        write!(writer, "            ")?;
+        emit_term(writer, &variant.value)?;
+        writeln!(writer, " => Ok({}::{}),", enum_ty.name, variant.name)?;
+    }
+    writeln!(
+        writer,
+        "            value => Err({rt}::ReadError::UnknownDiscriminant {{",
+        rt = RT_NAME,
+    )?;
+    writeln!(writer, "                ty: {:?}.to_owned(),", enum_ty.name)?;
+    writeln!(writer, "                value: value.into(),")?;
+    writeln!(writer, "            }}),")?;
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer, "}}")?;
+
+    Ok(())
+}
+
+fn emit_visitor_methods(
+    writer: &mut impl Write,
+    struct_ty: &StructType,
+    visit_method: &str,
+) -> io::Result<()> {
+    // Avoid an unused variable warning if there is nothing to walk
+    let visitor = match struct_ty.visited_fields().any(|field| field.cfg.is_none()) {
+        true => "visitor",
+        false => "_visitor",
+    };
+
+    writeln!(
+        writer,
+        "    pub fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {{",
+    )?;
+    writeln!(writer, "        visitor.{}(self);", visit_method)?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer)?;
+    if struct_ty.visited_fields().next().is_none() {
+        writeln!(
+            writer,
+            "    pub fn walk<V: Visit + ?Sized>(&self, _visitor: &mut V) {{}}",
+        )?;
+        return Ok(());
+    }
+    writeln!(
+        writer,
+        "    pub fn walk<V: Visit + ?Sized>(&self, {}: &mut V) {{",
+        visitor,
+    )?;
+    for field in struct_ty.visited_fields() {
+        let field_name = match struct_ty.transparent_field() {
+            Some(_) => "0",
+            None => &field.name,
+        };
+        emit_cfg(writer, "        ", &field.cfg)?;
+        writeln!(writer, "        self.{}.accept({});", field_name, visitor)?;
+    }
+    writeln!(writer, "    }}")?;
+
+    Ok(())
+}
+
+fn emit_cfg(writer: &mut impl Write, indent: &str, cfg: &Option<String>) -> io::Result<()> {
+    match cfg {
+        Some(feature) => writeln!(writer, "{}#[cfg(feature = {:?})]", indent, feature),
+        None => Ok(()),
+    }
+}
+
 fn emit_ty(writer: &mut impl Write, ty: &Type) -> io::Result<()> {
     match ty {
         Type::Var(name) => write!(writer, "{}", name),
@@ -212,13 +496,16 @@ fn emit_ty(writer: &mut impl Write, ty: &Type) -> io::Result<()> {
         Type::U16 => write!(writer, "u16"),
         Type::U32 => write!(writer, "u32"),
         Type::U64 => write!(writer, "u64"),
+        Type::U128 => write!(writer, "u128"),
         Type::I8 => write!(writer, "i8"),
         Type::I16 => write!(writer, "i16"),
         Type::I32 => write!(writer, "i32"),
         Type::I64 => write!(writer, "i64"),
+        Type::I128 => write!(writer, "i128"),
         Type::F32 => write!(writer, "f32"),
         Type::F64 => write!(writer, "f64"),
         Type::Bool => write!(writer, "bool"),
+        Type::CString => write!(writer, "std::ffi::CString"),
         Type::Rt(rt_ty) => match rt_ty {
             RtType::Either(lhs, rhs) => {
                 write!(writer, "{rt}::Either<", rt = RT_NAME)?;
@@ -234,6 +521,8 @@ fn emit_ty(writer: &mut impl Write, ty: &Type) -> io::Result<()> {
             RtType::U32Be => write!(writer, "{rt}::U32Be", rt = RT_NAME),
             RtType::U64Le => write!(writer, "{rt}::U64Le", rt = RT_NAME),
             RtType::U64Be => write!(writer, "{rt}::U64Be", rt = RT_NAME),
+            RtType::U128Le => write!(writer, "{rt}::U128Le", rt = RT_NAME),
+            RtType::U128Be => write!(writer, "{rt}::U128Be", rt = RT_NAME),
             RtType::I8 => write!(writer, "{rt}::I8", rt = RT_NAME),
             RtType::I16Le => write!(writer, "{rt}::I16Le", rt = RT_NAME),
             RtType::I16Be => write!(writer, "{rt}::I16Be", rt = RT_NAME),
@@ -241,10 +530,15 @@ fn emit_ty(writer: &mut impl Write, ty: &Type) -> io::Result<()> {
             RtType::I32Be => write!(writer, "{rt}::I32Be", rt = RT_NAME),
             RtType::I64Le => write!(writer, "{rt}::I64Le", rt = RT_NAME),
             RtType::I64Be => write!(writer, "{rt}::I64Be", rt = RT_NAME),
+            RtType::I128Le => write!(writer, "{rt}::I128Le", rt = RT_NAME),
+            RtType::I128Be => write!(writer, "{rt}::I128Be", rt = RT_NAME),
+            RtType::F16Le => write!(writer, "{rt}::F16Le", rt = RT_NAME),
+            RtType::F16Be => write!(writer, "{rt}::F16Be", rt = RT_NAME),
             RtType::F32Le => write!(writer, "{rt}::F32Le", rt = RT_NAME),
             RtType::F32Be => write!(writer, "{rt}::F32Be", rt = RT_NAME),
             RtType::F64Le => write!(writer, "{rt}::F64Le", rt = RT_NAME),
             RtType::F64Be => write!(writer, "{rt}::F64Be", rt = RT_NAME),
+            RtType::CStr => write!(writer, "{rt}::CStr", rt = RT_NAME),
             RtType::InvalidDataDescription => {
                 write!(writer, "{rt}::InvalidDataDescription", rt = RT_NAME)
             }
@@ -252,49 +546,85 @@ fn emit_ty(writer: &mut impl Write, ty: &Type) -> io::Result<()> {
     }
 }
 
-fn emit_ty_read(writer: &mut impl Write, ty: &Type) -> io::Result<()> {
-    match ty {
-        Type::Var(name) => write!(writer, "reader.read::<{}>()?", name),
+fn emit_ty_read(writer: &mut impl Write, ty: &Type, location: Option<&str>) -> io::Result<()> {
+    let rt_ty_name = match ty {
+        Type::Var(name) => return emit_read(writer, name, location),
         Type::If(cond, lhs, rhs) => {
             write!(writer, "if ")?;
             emit_term(writer, cond)?;
             write!(writer, " {{ {rt}::Either::Left(", rt = RT_NAME)?;
-            emit_ty_read(writer, lhs)?;
+            emit_ty_read(writer, lhs, location)?;
             write!(writer, ") }} else {{ {rt}::Either::Right(", rt = RT_NAME)?;
-            emit_ty_read(writer, rhs)?;
-            write!(writer, ") }}")
+            emit_ty_read(writer, rhs, location)?;
+            return write!(writer, ") }}");
         }
-        Type::Rt(rt_ty) => match rt_ty {
-            RtType::Either(_, _) => write!(
-                writer,
-                "reader.read::<{rt}::InvalidDataDescription>()?",
-                rt = RT_NAME,
-            ),
-            RtType::U8 => write!(writer, "reader.read::<{rt}::U8>()?", rt = RT_NAME),
-            RtType::U16Le => write!(writer, "reader.read::<{rt}::U16Le>()?", rt = RT_NAME),
-            RtType::U16Be => write!(writer, "reader.read::<{rt}::U16Be>()?", rt = RT_NAME),
-            RtType::U32Le => write!(writer, "reader.read::<{rt}::U32Le>()?", rt = RT_NAME),
-            RtType::U32Be => write!(writer, "reader.read::<{rt}::U32Be>()?", rt = RT_NAME),
-            RtType::U64Le => write!(writer, "reader.read::<{rt}::U64Le>()?", rt = RT_NAME),
-            RtType::U64Be => write!(writer, "reader.read::<{rt}::U64Be>()?", rt = RT_NAME),
-            RtType::I8 => write!(writer, "reader.read::<{rt}::I8>()?", rt = RT_NAME),
-            RtType::I16Le => write!(writer, "reader.read::<{rt}::I16Le>()?", rt = RT_NAME),
-            RtType::I16Be => write!(writer, "reader.read::<{rt}::I16Be>()?", rt = RT_NAME),
-            RtType::I32Le => write!(writer, "reader.read::<{rt}::I32Le>()?", rt = RT_NAME),
-            RtType::I32Be => write!(writer, "reader.read::<{rt}::I32Be>()?", rt = RT_NAME),
-            RtType::I64Le => write!(writer, "reader.read::<{rt}::I64Le>()?", rt = RT_NAME),
-            RtType::I64Be => write!(writer, "reader.read::<{rt}::I64Be>()?", rt = RT_NAME),
-            RtType::F32Le => write!(writer, "reader.read::<{rt}::F32Le>()?", rt = RT_NAME),
-            RtType::F32Be => write!(writer, "reader.read::<{rt}::F32Be>()?", rt = RT_NAME),
-            RtType::F64Le => write!(writer, "reader.read::<{rt}::F64Le>()?", rt = RT_NAME),
-            RtType::F64Be => write!(writer, "reader.read::<{rt}::F64Be>()?", rt = RT_NAME),
-            RtType::InvalidDataDescription => write!(
-                writer,
-                "reader.read::<{rt}::InvalidDataDescription>()?",
-                rt = RT_NAME,
-            ),
-        },
+        Type::Rt(rt_ty) => rt_format_name(rt_ty),
         _ => unimplemented!("unexpected host type"),
+    };
+
+    emit_read(writer, &format!("{}::{}", RT_NAME, rt_ty_name), location)
+}
+
+fn emit_ty_read_or(
+    writer: &mut impl Write,
+    ty: &Type,
+    location: Option<&str>,
+    default: &Term,
+) -> io::Result<()> {
+    let format_ty = match ty {
+        Type::Var(name) => name.clone(),
+        Type::Rt(rt_ty) => format!("{}::{}", RT_NAME, rt_format_name(rt_ty)),
+        _ => unimplemented!("unexpected format type for default"),
+    };
+
+    match location {
+        None => write!(writer, "reader.read_or::<{}>(", format_ty)?,
+        Some(location) => write!(
+            writer,
+            "reader.read_field_or::<{}>(&{}, ",
+            format_ty, location,
+        )?,
+    }
+    emit_term(writer, default)?;
+    write!(writer, ")?")
+}
+
+fn rt_format_name(rt_ty: &RtType) -> &'static str {
+    match rt_ty {
+        RtType::Either(_, _) => "InvalidDataDescription",
+        RtType::U8 => "U8",
+        RtType::U16Le => "U16Le",
+        RtType::U16Be => "U16Be",
+        RtType::U32Le => "U32Le",
+        RtType::U32Be => "U32Be",
+        RtType::U64Le => "U64Le",
+        RtType::U64Be => "U64Be",
+        RtType::U128Le => "U128Le",
+        RtType::U128Be => "U128Be",
+        RtType::I8 => "I8",
+        RtType::I16Le => "I16Le",
+        RtType::I16Be => "I16Be",
+        RtType::I32Le => "I32Le",
+        RtType::I32Be => "I32Be",
+        RtType::I64Le => "I64Le",
+        RtType::I64Be => "I64Be",
+        RtType::I128Le => "I128Le",
+        RtType::I128Be => "I128Be",
+        RtType::F16Le => "F16Le",
+        RtType::F16Be => "F16Be",
+        RtType::F32Le => "F32Le",
+        RtType::F32Be => "F32Be",
+        RtType::F64Le => "F64Le",
+        RtType::F64Be => "F64Be",
+        RtType::CStr => "CStr",
+        RtType::InvalidDataDescription => "InvalidDataDescription",
+    }
+}
+
+fn emit_read(writer: &mut impl Write, format_ty: &str, location: Option<&str>) -> io::Result<()> {
+    match location {
+        None => write!(writer, "reader.read::<{}>()?", format_ty),
+        Some(location) => write!(writer, "reader.read_field::<{}>(&{})?", format_ty, location,),
     }
 }
 
@@ -306,10 +636,12 @@ fn emit_term(writer: &mut impl Write, term: &Term) -> io::Result<()> {
         Term::U16(value) => write!(writer, "{}u16", value),
         Term::U32(value) => write!(writer, "{}u32", value),
         Term::U64(value) => write!(writer, "{}u64", value),
+        Term::U128(value) => write!(writer, "{}u128", value),
         Term::I8(value) => write!(writer, "{}i8", value),
         Term::I16(value) => write!(writer, "{}i16", value),
         Term::I32(value) => write!(writer, "{}i32", value),
         Term::I64(value) => write!(writer, "{}i64", value),
+        Term::I128(value) => write!(writer, "{}i128", value),
         Term::F32(value) => write!(writer, "{}f32", value),
         Term::F64(value) => write!(writer, "{}f64", value),
         Term::Call(term) => {
@@ -18,6 +18,7 @@ pub enum Item {
     Function(Function),
     Alias(Alias),
     Struct(StructType),
+    Enum(EnumType),
 }
 
 /// Compiled constants.
@@ -54,16 +55,88 @@ pub struct StructType {
     pub doc: Arc<[String]>,
     pub name: String,
     pub fields: Vec<TypeField>,
+    pub eof: bool,
+    /// The name of the field that captures the remaining bytes, if any.
+    pub rest: Option<String>,
+    pub read_bounded: bool,
+    pub transparent: bool,
+    /// The name of the method for this structure on the `Visit` trait, if
+    /// one is being generated.
+    pub visit_method: Option<String>,
+}
+
+impl StructType {
+    /// The fields that are kept in the host structure once read.
+    pub fn stored_fields(&self) -> impl Iterator<Item = &TypeField> {
+        self.fields.iter().filter(|field| !field.reserved)
+    }
+
+    /// The fields that hold other structures, to be visited when walking
+    /// this structure.
+    pub fn visited_fields(&self) -> impl Iterator<Item = &TypeField> {
+        self.stored_fields().filter(|field| match field.host_ty {
+            Type::Var(_) => true,
+            _ => false,
+        })
+    }
+
+    /// The field wrapped by a transparent struct.
+    pub fn transparent_field(&self) -> Option<&TypeField> {
+        match self.fields.as_slice() {
+            [field] if self.transparent => Some(field),
+            _ => None,
+        }
+    }
+}
+
+/// Compiled enum types.
+#[derive(Debug, Clone)]
+pub struct EnumType {
+    pub doc: Arc<[String]>,
+    pub name: String,
+    /// The format that the discriminant is read with.
+    pub format_ty: Type,
+    /// The integer type of the discriminant.
+    pub host_ty: Type,
+    pub variants: Vec<EnumVariant>,
+}
+
+/// Compiled enum variants.
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    pub doc: Arc<[String]>,
+    pub name: String,
+    pub value: Term,
 }
 
 /// Compiled type fields types.
 #[derive(Debug, Clone)]
 pub struct TypeField {
     pub doc: Arc<[String]>,
+    pub align: Option<u64>,
+    pub cfg: Option<String>,
+    /// The value to use if the data ends before the field.
+    pub default: Option<Term>,
+    /// The magic number that the field must contain.
+    pub magic: Option<Term>,
+    pub location: Option<FieldLocation>,
+    /// The name of the field in the original data description.
+    pub label: String,
+    /// The name of the field as a Rust identifier.
     pub name: String,
     pub format_ty: Type,
     pub host_ty: Type,
     pub by_ref: bool,
+    /// Whether the field is read without being stored.
+    pub reserved: bool,
+}
+
+/// The location of a field in the original data description.
+#[derive(Debug, Clone)]
+pub struct FieldLocation {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
 }
 
 /// Compiled types.
@@ -77,13 +150,16 @@ pub enum Type {
     U16,
     U32,
     U64,
+    U128,
     I8,
     I16,
     I32,
     I64,
+    I128,
     F32,
     F64,
     Bool,
+    CString,
 
     Rt(RtType),
 }
@@ -98,6 +174,8 @@ pub enum RtType {
     U32Be,
     U64Le,
     U64Be,
+    U128Le,
+    U128Be,
     I8,
     I16Le,
     I16Be,
@@ -105,10 +183,15 @@ pub enum RtType {
     I32Be,
     I64Le,
     I64Be,
+    I128Le,
+    I128Be,
+    F16Le,
+    F16Be,
     F32Le,
     F32Be,
     F64Le,
     F64Be,
+    CStr,
     InvalidDataDescription,
 }
 
@@ -121,10 +204,12 @@ pub enum Term {
     U16(u16),
     U32(u32),
     U64(u64),
+    U128(u128),
     I8(i8),
     I16(i16),
     I32(i32),
     I64(i64),
+    I128(i128),
     F32(f32),
     F64(f64),
 
@@ -1,19 +1,39 @@
-use codespan::FileId;
-use codespan_reporting::diagnostic::Diagnostic;
+use codespan::{FileId, Span};
+use codespan_reporting::diagnostic::{Diagnostic, Severity};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io;
 use std::io::prelude::*;
 
-use crate::surface;
+use crate::{diagnostics, surface};
+
+/// Options that control the generated documentation.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Options {
+    /// The severity to report items that have no doc comment with, or `None`
+    /// to allow undocumented items.
+    pub missing_docs: Option<Severity>,
+    /// Emit a list of links to each item before the items themselves.
+    pub table_of_contents: bool,
+}
 
 pub fn compile_module(
     writer: &mut impl Write,
     module: &surface::Module,
     report: &mut dyn FnMut(Diagnostic),
+) -> io::Result<()> {
+    compile_module_with_options(writer, module, &Options::default(), report)
+}
+
+pub fn compile_module_with_options(
+    writer: &mut impl Write,
+    module: &surface::Module,
+    options: &Options,
+    report: &mut dyn FnMut(Diagnostic),
 ) -> io::Result<()> {
     let mut context = ModuleContext {
-        _file_id: module.file_id,
+        options: *options,
+        file_id: module.file_id,
         items: HashMap::new(),
     };
 
@@ -53,14 +73,20 @@ pub fn compile_module(
         writeln!(writer, r##"      </section>"##)?;
     }
 
+    if context.options.table_of_contents {
+        compile_table_of_contents(writer, module)?;
+    }
+
     writeln!(writer, r##"      <dl class="items">"##)?;
 
     for item in &module.items {
         let (label, item) = match item {
             surface::Item::Alias(alias) => compile_alias(&context, writer, alias, report)?,
+            surface::Item::Struct(struct_ty) if is_doc_hidden(&struct_ty.attributes) => continue,
             surface::Item::Struct(struct_ty) => {
                 compile_struct_ty(&context, writer, struct_ty, report)?
             }
+            surface::Item::Enum(enum_ty) => compile_enum_ty(&context, writer, enum_ty, report)?,
         };
 
         context.items.insert(label, item);
@@ -79,7 +105,8 @@ pub fn compile_module(
 }
 
 struct ModuleContext {
-    _file_id: FileId,
+    options: Options,
+    file_id: FileId,
     items: HashMap<String, Item>,
 }
 
@@ -95,6 +122,7 @@ fn compile_alias(
 ) -> io::Result<(String, Item)> {
     let (_, name) = &alias.name;
     let id = format!("items[{}]", name);
+    check_doc(context, &alias.name, &alias.doc, report);
 
     writeln!(
         writer,
@@ -152,15 +180,18 @@ fn compile_struct_ty(
 ) -> io::Result<(String, Item)> {
     let (_, name) = &struct_ty.name;
     let id = format!("items[{}]", name);
+    check_doc(context, &struct_ty.name, &struct_ty.doc, report);
+    let attributes = compile_attributes(context, &struct_ty.attributes, report);
 
     write!(
         writer,
         r##"        <dt id="{id}" class="item struct">
-          struct <a href="#{id}">{name}</a>
+          {attributes}struct <a href="#{id}">{name}</a>
         </dt>
         <dd class="item struct">
 "##,
         id = id,
+        attributes = attributes,
         name = name
     )?;
 
@@ -170,41 +201,209 @@ fn compile_struct_ty(
         writeln!(writer, r##"          </section>"##)?;
     }
 
-    if !struct_ty.fields.is_empty() {
+    if !struct_ty.includes.is_empty() || !struct_ty.fields.is_empty() || struct_ty.rest.is_some() {
         writeln!(writer, r##"          <dl class="fields">"##)?;
+        for (_, include_name) in &struct_ty.includes {
+            write!(
+                writer,
+                r##"            <dt class="field include">
+              include {name}
+            </dt>
+            <dd class="field include">
+            </dd>
+"##,
+                name = compile_item_name(context, include_name),
+            )?;
+        }
         for field in &struct_ty.fields {
             let (_, field_name) = &field.name;
             let field_id = format!("{}.fields[{}]", id, field_name);
             let ty = compile_term(context, &field.term, report);
+            let attributes = compile_attributes(context, &field.attributes, report);
 
             write!(
                 writer,
                 r##"            <dt id="{id}" class="field">
-              <a href="#{id}">{name}</a> : {ty}
+              {attributes}<a href="#{id}">{name}</a> : {ty}
             </dt>
             <dd class="field">
-              <section class="doc">
 "##,
                 id = field_id,
+                attributes = attributes,
                 name = field_name,
                 ty = ty,
             )?;
-            compile_doc_lines(writer, "                ", &field.doc)?;
+            if !field.doc.is_empty() {
+                writeln!(writer, r##"              <section class="doc">"##)?;
+                compile_doc_lines(writer, "                ", &field.doc)?;
+                writeln!(writer, r##"              </section>"##)?;
+            }
+            writeln!(writer, r##"            </dd>"##)?;
+        }
+        if let Some((_, rest_name)) = &struct_ty.rest {
             write!(
                 writer,
-                r##"              </section>
+                r##"            <dt id="{id}" class="field rest">
+              ...<a href="#{id}">{name}</a>
+            </dt>
+            <dd class="field rest">
             </dd>
-"##
+"##,
+                id = format!("{}.fields[{}]", id, rest_name),
+                name = rest_name,
             )?;
         }
         writeln!(writer, r##"          </dl>"##)?;
     }
 
+    if struct_ty.eof {
+        writeln!(writer, r##"          <p class="eof">eof</p>"##)?;
+    }
+
     writeln!(writer, r##"        </dd>"##)?;
 
     Ok((name.clone(), Item { id }))
 }
 
+fn compile_enum_ty(
+    context: &ModuleContext,
+    writer: &mut impl Write,
+    enum_ty: &surface::EnumType,
+    report: &mut dyn FnMut(Diagnostic),
+) -> io::Result<(String, Item)> {
+    let (_, name) = &enum_ty.name;
+    let id = format!("items[{}]", name);
+    check_doc(context, &enum_ty.name, &enum_ty.doc, report);
+
+    write!(
+        writer,
+        r##"        <dt id="{id}" class="item enum">
+          enum <a href="#{id}">{name}</a> : {repr}
+        </dt>
+        <dd class="item enum">
+"##,
+        id = id,
+        name = name,
+        repr = compile_term(context, &enum_ty.repr, report),
+    )?;
+
+    if !enum_ty.doc.is_empty() {
+        writeln!(writer, r##"          <section class="doc">"##)?;
+        compile_doc_lines(writer, "            ", &enum_ty.doc)?;
+        writeln!(writer, r##"          </section>"##)?;
+    }
+
+    if !enum_ty.variants.is_empty() {
+        writeln!(writer, r##"          <dl class="fields">"##)?;
+        for variant in &enum_ty.variants {
+            let (_, variant_name) = &variant.name;
+            let variant_id = format!("{}.variants[{}]", id, variant_name);
+
+            write!(
+                writer,
+                r##"            <dt id="{id}" class="field variant">
+              <a href="#{id}">{name}</a> = {value}
+            </dt>
+            <dd class="field variant">
+"##,
+                id = variant_id,
+                name = variant_name,
+                value = compile_term(context, &variant.value, report),
+            )?;
+            if !variant.doc.is_empty() {
+                writeln!(writer, r##"              <section class="doc">"##)?;
+                compile_doc_lines(writer, "                ", &variant.doc)?;
+                writeln!(writer, r##"              </section>"##)?;
+            }
+            writeln!(writer, r##"            </dd>"##)?;
+        }
+        writeln!(writer, r##"          </dl>"##)?;
+    }
+
+    writeln!(writer, r##"        </dd>"##)?;
+
+    Ok((name.clone(), Item { id }))
+}
+
+fn compile_table_of_contents(writer: &mut impl Write, module: &surface::Module) -> io::Result<()> {
+    writeln!(writer, r##"      <nav class="contents">"##)?;
+    writeln!(writer, r##"        <ul>"##)?;
+    for item in &module.items {
+        let (_, name) = match item {
+            surface::Item::Alias(alias) => &alias.name,
+            surface::Item::Struct(struct_ty) if is_doc_hidden(&struct_ty.attributes) => continue,
+            surface::Item::Struct(struct_ty) => &struct_ty.name,
+            surface::Item::Enum(enum_ty) => &enum_ty.name,
+        };
+        writeln!(
+            writer,
+            r##"          <li><a href="#items[{name}]">{name}</a></li>"##,
+            name = name,
+        )?;
+    }
+    writeln!(writer, r##"        </ul>"##)?;
+    writeln!(writer, r##"      </nav>"##)?;
+
+    Ok(())
+}
+
+/// Hidden items are left out of the documentation, and references to them are
+/// not linked.
+fn is_doc_hidden(attributes: &[surface::Attribute]) -> bool {
+    attributes
+        .iter()
+        .any(|attribute| attribute.name.1 == "doc_hide")
+}
+
+fn check_doc(
+    context: &ModuleContext,
+    (span, name): &(Span, String),
+    doc: &[String],
+    report: &mut dyn FnMut(Diagnostic),
+) {
+    if let Some(severity) = context.options.missing_docs {
+        if doc.is_empty() {
+            report(diagnostics::missing_doc(
+                severity,
+                context.file_id,
+                *span,
+                name,
+            ));
+        }
+    }
+}
+
+fn compile_attributes(
+    context: &ModuleContext,
+    attributes: &[surface::Attribute],
+    report: &mut dyn FnMut(Diagnostic),
+) -> String {
+    use itertools::Itertools;
+
+    attributes
+        .iter()
+        .map(|attribute| match &attribute.args {
+            None => format!("@{} ", attribute.name.1),
+            Some(args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| compile_term(context, arg, report))
+                    .collect::<Vec<_>>();
+
+                format!("@{}({}) ", attribute.name.1, args.iter().format(", "))
+            }
+        })
+        .collect()
+}
+
+/// Refer to an item by name, linking to its documentation if it has any.
+fn compile_item_name(context: &ModuleContext, name: &str) -> String {
+    match context.items.get(name) {
+        Some(item) => format!(r##"<var><a href="#{}">{}</a></var>"##, item.id, name),
+        None => format!("<var>{}</var>", name),
+    }
+}
+
 fn compile_term<'term>(
     context: &ModuleContext,
     term: &'term surface::Term,
@@ -214,14 +413,7 @@ fn compile_term<'term>(
         surface::Term::Paren(_, term) => {
             format!("({})", compile_term(context, term, report)).into()
         }
-        surface::Term::Name(_, name) => {
-            let id = match context.items.get(name) {
-                Some(item) => item.id.as_str(),
-                None => "",
-            };
-
-            format!(r##"<var><a href="#{}">{}</a></var>"##, id, name).into()
-        }
+        surface::Term::Name(_, name) => compile_item_name(context, name).into(),
         surface::Term::Ann(term, ty) => {
             let term = compile_term(context, term, report);
             let ty = compile_term(context, ty, report);
@@ -257,3 +449,121 @@ fn compile_doc_lines(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use codespan::Files;
+
+    use super::*;
+    use crate::lexer::{Lexer, SURFACE_KEYWORDS};
+
+    fn compile(source: &str, options: &Options) -> Vec<Diagnostic> {
+        let mut files = Files::new();
+        let file_id = files.add("test", source);
+        let lexer = Lexer::new(&files, file_id, &SURFACE_KEYWORDS);
+        let module = surface::Module::parse(file_id, lexer, &mut |_| {});
+
+        let mut diagnostics = Vec::new();
+        compile_module_with_options(&mut Vec::new(), &module, options, &mut |d| {
+            diagnostics.push(d)
+        })
+        .unwrap();
+        diagnostics
+    }
+
+    #[test]
+    fn missing_docs() {
+        let source = "
+            /// A documented alias.
+            Byte = U8;
+
+            struct Point {
+                x : F32Be,
+                y : F32Be,
+            }
+        ";
+
+        assert!(compile(source, &Options::default()).is_empty());
+
+        let options = Options {
+            missing_docs: Some(Severity::Warning),
+            ..Options::default()
+        };
+        let diagnostics = compile(source, &options);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].message, "missing documentation for `Point`");
+    }
+
+    #[test]
+    fn doc_hide() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "@doc_hide struct Internal { x : U8 } struct Public { internal : Internal }",
+        );
+        let lexer = Lexer::new(&files, file_id, &SURFACE_KEYWORDS);
+        let module = surface::Module::parse(file_id, lexer, &mut |_| {});
+
+        let mut output = Vec::new();
+        compile_module(&mut output, &module, &mut |_| {}).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(!output.contains(r##"id="items[Internal]""##));
+        assert!(output.contains(r##"id="items[Public]""##));
+        assert!(output.contains("<var>Internal</var>"));
+        assert!(!output.contains(r##"<a href="#">"##));
+    }
+
+    #[test]
+    fn table_of_contents() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "Byte = U8; @doc_hide struct Internal { x : U8 } struct Public { byte : Byte }",
+        );
+        let lexer = Lexer::new(&files, file_id, &SURFACE_KEYWORDS);
+        let module = surface::Module::parse(file_id, lexer, &mut |_| {});
+
+        let compile = |options: &Options| {
+            let mut output = Vec::new();
+            compile_module_with_options(&mut output, &module, options, &mut |_| {}).unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        assert!(!compile(&Options::default()).contains(r##"<nav class="contents">"##));
+
+        let output = compile(&Options {
+            table_of_contents: true,
+            ..Options::default()
+        });
+        let contents = concat!(
+            r##"      <nav class="contents">"##,
+            "\n        <ul>\n",
+            r##"          <li><a href="#items[Byte]">Byte</a></li>"##,
+            "\n",
+            r##"          <li><a href="#items[Public]">Public</a></li>"##,
+            "\n        </ul>\n",
+            r##"      </nav>"##,
+        );
+        assert!(output.contains(contents));
+    }
+
+    #[test]
+    fn field_docs() {
+        let mut files = Files::new();
+        let file_id = files.add(
+            "test",
+            "struct Point {\n/// The horizontal position.\nx : F32Be, y : F32Be }",
+        );
+        let lexer = Lexer::new(&files, file_id, &SURFACE_KEYWORDS);
+        let module = surface::Module::parse(file_id, lexer, &mut |_| {});
+
+        let mut output = Vec::new();
+        compile_module(&mut output, &module, &mut |_| {}).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("The horizontal position."));
+        assert_eq!(output.matches(r##"<section class="doc">"##).count(), 1);
+    }
+}
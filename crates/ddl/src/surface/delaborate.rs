@@ -37,23 +37,113 @@ pub fn delaborate_item(item: &core::Item) -> surface::Item {
         core::Item::Struct(struct_ty) => surface::Item::Struct(surface::StructType {
             span: struct_ty.span,
             doc: struct_ty.doc.clone(),
+            attributes: delaborate_struct_attributes(struct_ty),
             name: (Span::initial(), struct_ty.name.to_string()),
+            includes: Vec::new(),
             fields: struct_ty
                 .fields
                 .iter()
                 .map(|ty_field| {
                     surface::TypeField {
                         doc: ty_field.doc.clone(),
+                        attributes: delaborate_field_attributes(ty_field),
                         // TODO: use `ty_field.start`
                         name: (Span::initial(), ty_field.name.to_string()),
                         term: delaborate_term(&ty_field.term),
                     }
                 })
                 .collect(),
+            eof: struct_ty.eof,
+            rest: struct_ty
+                .rest
+                .as_ref()
+                .map(|rest| (Span::initial(), rest.to_string())),
+        }),
+        core::Item::Enum(enum_ty) => surface::Item::Enum(surface::EnumType {
+            span: enum_ty.span,
+            doc: enum_ty.doc.clone(),
+            name: (Span::initial(), enum_ty.name.to_string()),
+            repr: delaborate_term(&enum_ty.repr),
+            variants: enum_ty
+                .variants
+                .iter()
+                .map(|variant| surface::EnumVariant {
+                    doc: variant.doc.clone(),
+                    name: (Span::initial(), variant.name.to_string()),
+                    value: delaborate_term(&variant.value),
+                })
+                .collect(),
         }),
     }
 }
 
+fn delaborate_struct_attributes(struct_ty: &core::StructType) -> Vec<surface::Attribute> {
+    let span = Span::initial();
+    let transparent = match struct_ty.transparent {
+        true => Some(surface::Attribute {
+            span,
+            name: (span, "transparent".to_owned()),
+            args: None,
+        }),
+        false => None,
+    };
+    let rename = struct_ty.rename.as_ref().map(|rename| surface::Attribute {
+        span,
+        name: (span, "name".to_owned()),
+        args: Some(vec![surface::Term::Name(span, rename.to_string())]),
+    });
+
+    transparent.into_iter().chain(rename).collect()
+}
+
+fn delaborate_field_attributes(ty_field: &core::TypeField) -> Vec<surface::Attribute> {
+    let span = Span::initial();
+    let attribute = |name: &str, arg| surface::Attribute {
+        span,
+        name: (span, name.to_owned()),
+        args: Some(vec![arg]),
+    };
+
+    let align = ty_field.align.map(|align| {
+        let align = num_bigint::BigInt::from(align);
+        let literal = literal::Number::from_signed(span, &align);
+        attribute("aligned", surface::Term::NumberLiteral(span, literal))
+    });
+    let cfg = ty_field
+        .cfg
+        .as_ref()
+        .map(|feature| attribute("cfg", surface::Term::Name(span, feature.to_string())));
+    let default = ty_field
+        .default
+        .as_ref()
+        .map(|default| attribute("default", delaborate_term(default)));
+    let magic = ty_field
+        .magic
+        .as_ref()
+        .map(|magic| attribute("magic", delaborate_term(magic)));
+    let rename = ty_field
+        .rename
+        .as_ref()
+        .map(|rename| attribute("name", surface::Term::Name(span, rename.to_string())));
+    let reserved = match ty_field.reserved {
+        true => Some(surface::Attribute {
+            span,
+            name: (span, "reserved".to_owned()),
+            args: None,
+        }),
+        false => None,
+    };
+
+    align
+        .into_iter()
+        .chain(cfg)
+        .chain(default)
+        .chain(magic)
+        .chain(rename)
+        .chain(reserved)
+        .collect()
+}
+
 pub fn delaborate_term(term: &core::Term) -> surface::Term {
     delaborate_term_prec(term, 0)
 }
@@ -73,35 +163,40 @@ pub fn delaborate_term_prec(term: &core::Term, prec: u8) -> surface::Term {
                 Box::new(delaborate_term_prec(ty, prec + 1)),
             ),
         ),
-        core::Term::Universe(span, universe) => match universe {
-            core::Universe::Type => surface::Term::Name(*span, "Type".to_owned()),
-            core::Universe::Format => surface::Term::Name(*span, "Format".to_owned()),
-            core::Universe::Kind => surface::Term::Name(*span, "Kind".to_owned()),
-        },
-        core::Term::U8Type(span) => surface::Term::Name(*span, "U8".to_owned()),
-        core::Term::U16LeType(span) => surface::Term::Name(*span, "U16Le".to_owned()),
-        core::Term::U16BeType(span) => surface::Term::Name(*span, "U16Be".to_owned()),
-        core::Term::U32LeType(span) => surface::Term::Name(*span, "U32Le".to_owned()),
-        core::Term::U32BeType(span) => surface::Term::Name(*span, "U32Be".to_owned()),
-        core::Term::U64LeType(span) => surface::Term::Name(*span, "U64Le".to_owned()),
-        core::Term::U64BeType(span) => surface::Term::Name(*span, "U64Be".to_owned()),
-        core::Term::S8Type(span) => surface::Term::Name(*span, "S8".to_owned()),
-        core::Term::S16LeType(span) => surface::Term::Name(*span, "S16Le".to_owned()),
-        core::Term::S16BeType(span) => surface::Term::Name(*span, "S16Be".to_owned()),
-        core::Term::S32LeType(span) => surface::Term::Name(*span, "S32Le".to_owned()),
-        core::Term::S32BeType(span) => surface::Term::Name(*span, "S32Be".to_owned()),
-        core::Term::S64LeType(span) => surface::Term::Name(*span, "S64Le".to_owned()),
-        core::Term::S64BeType(span) => surface::Term::Name(*span, "S64Be".to_owned()),
-        core::Term::F32LeType(span) => surface::Term::Name(*span, "F32Le".to_owned()),
-        core::Term::F32BeType(span) => surface::Term::Name(*span, "F32Be".to_owned()),
-        core::Term::F64LeType(span) => surface::Term::Name(*span, "F64Le".to_owned()),
-        core::Term::F64BeType(span) => surface::Term::Name(*span, "F64Be".to_owned()),
-        core::Term::BoolType(span) => surface::Term::Name(*span, "Bool".to_owned()),
-        core::Term::IntType(span) => surface::Term::Name(*span, "Int".to_owned()),
-        core::Term::F32Type(span) => surface::Term::Name(*span, "F32".to_owned()),
-        core::Term::F64Type(span) => surface::Term::Name(*span, "F64".to_owned()),
-        core::Term::BoolConst(span, true) => surface::Term::Name(*span, "true".to_owned()),
-        core::Term::BoolConst(span, false) => surface::Term::Name(*span, "false".to_owned()),
+        core::Term::Universe(span, _)
+        | core::Term::U8Type(span)
+        | core::Term::U16LeType(span)
+        | core::Term::U16BeType(span)
+        | core::Term::U32LeType(span)
+        | core::Term::U32BeType(span)
+        | core::Term::U64LeType(span)
+        | core::Term::U64BeType(span)
+        | core::Term::U128LeType(span)
+        | core::Term::U128BeType(span)
+        | core::Term::S8Type(span)
+        | core::Term::S16LeType(span)
+        | core::Term::S16BeType(span)
+        | core::Term::S32LeType(span)
+        | core::Term::S32BeType(span)
+        | core::Term::S64LeType(span)
+        | core::Term::S64BeType(span)
+        | core::Term::S128LeType(span)
+        | core::Term::S128BeType(span)
+        | core::Term::F16LeType(span)
+        | core::Term::F16BeType(span)
+        | core::Term::F32LeType(span)
+        | core::Term::F32BeType(span)
+        | core::Term::F64LeType(span)
+        | core::Term::F64BeType(span)
+        | core::Term::CStrType(span)
+        | core::Term::BoolType(span)
+        | core::Term::IntType(span)
+        | core::Term::F32Type(span)
+        | core::Term::F64Type(span)
+        | core::Term::BoolConst(span, _) => {
+            let builtin = core::builtin::Builtin::from_term(term).expect("builtin term");
+            surface::Term::Name(*span, builtin.name().to_owned())
+        }
         core::Term::IntConst(span, value) => {
             surface::Term::NumberLiteral(*span, literal::Number::from_signed(*span, value))
         }
@@ -105,17 +105,37 @@ pub fn elaborate_items(
             }
             surface::Item::Struct(struct_ty) => {
                 let label = core::Label(struct_ty.name.1.clone());
-                let field_context = context.field_context();
-                let core_fields =
-                    elaborate_struct_ty_fields(field_context, &struct_ty.fields, report);
+                let mut field_context = context.field_context();
+                let mut core_fields = elaborate_struct_ty_includes(
+                    &mut field_context,
+                    &struct_ty.includes,
+                    &core_items,
+                    report,
+                );
+                core_fields.extend(elaborate_struct_ty_fields(
+                    &mut field_context,
+                    &struct_ty.fields,
+                    report,
+                ));
+                let rest = elaborate_struct_ty_rest(&mut field_context, &struct_ty.rest, report);
+                let (transparent, rename) = elaborate_struct_attributes(
+                    &context.term_context(),
+                    struct_ty,
+                    &core_fields,
+                    report,
+                );
 
                 match context.items.entry(label) {
                     Entry::Vacant(entry) => {
                         let item = core::StructType {
                             span: struct_ty.span,
                             doc: struct_ty.doc.clone(),
+                            transparent,
+                            rename,
                             name: entry.key().clone(),
                             fields: core_fields,
+                            eof: struct_ty.eof,
+                            rest,
                         };
 
                         core_items.push(core::Item::Struct(item));
@@ -133,12 +153,129 @@ pub fn elaborate_items(
                     )),
                 }
             }
+            surface::Item::Enum(enum_ty) => {
+                let label = core::Label(enum_ty.name.1.clone());
+                let (repr, variants) = elaborate_enum_ty(&context.term_context(), enum_ty, report);
+
+                match context.items.entry(label) {
+                    Entry::Vacant(entry) => {
+                        let item = core::EnumType {
+                            span: enum_ty.span,
+                            doc: enum_ty.doc.clone(),
+                            name: entry.key().clone(),
+                            repr,
+                            variants,
+                        };
+
+                        core_items.push(core::Item::Enum(item));
+                        entry.insert((enum_ty.span, core::Value::Universe(core::Universe::Format)));
+                    }
+                    Entry::Occupied(entry) => report(diagnostics::item_redefinition(
+                        Severity::Error,
+                        context.file_id,
+                        entry.key(),
+                        enum_ty.span,
+                        entry.get().0,
+                    )),
+                }
+            }
         }
     }
 
     core_items
 }
 
+/// Elaborate the integer format and the variants of an enum type, checking
+/// that each variant has a distinct name and discriminant, and that the
+/// discriminants can be read by the integer format.
+pub fn elaborate_enum_ty(
+    context: &TermContext<'_>,
+    enum_ty: &surface::EnumType,
+    report: &mut dyn FnMut(Diagnostic),
+) -> (core::Term, Vec<core::EnumVariant>) {
+    use std::collections::hash_map::Entry;
+
+    let mut repr = check_term(
+        context,
+        &enum_ty.repr,
+        &core::Value::Universe(core::Universe::Format),
+        report,
+    );
+    let repr_ty = core::semantics::eval(&repr);
+    let bounds = match core::semantics::int_format_bounds(&repr_ty) {
+        Some((min, max)) if min >= i64::min_value().into() && max <= u64::max_value().into() => {
+            Some((min, max))
+        }
+        Some(_) | None => {
+            if repr_ty != core::Value::Error {
+                report(diagnostics::unsupported_enum_repr(
+                    Severity::Error,
+                    context.file_id,
+                    enum_ty.repr.span(),
+                ));
+                repr = core::Term::Error(enum_ty.repr.span());
+            }
+            None
+        }
+    };
+
+    let mut names = HashMap::new();
+    let mut values = HashMap::new();
+    let mut core_variants = Vec::with_capacity(enum_ty.variants.len());
+
+    for variant in &enum_ty.variants {
+        let label = core::Label(variant.name.1.clone());
+        let variant_span = Span::merge(variant.name.0, variant.value.span());
+        let core_value = check_term(context, &variant.value, &core::Value::IntType, report);
+
+        let value = match core::semantics::eval(&core_value) {
+            core::Value::IntConst(value) => value,
+            _ => continue,
+        };
+        if let Some((min, max)) = &bounds {
+            if value < *min || value > *max {
+                report(diagnostics::enum_variant_out_of_range(
+                    Severity::Error,
+                    context.file_id,
+                    variant.value.span(),
+                    &repr_ty,
+                ));
+                continue;
+            }
+        }
+
+        match (names.entry(label), values.entry(value)) {
+            (Entry::Occupied(entry), _) => report(diagnostics::variant_redeclaration(
+                Severity::Error,
+                context.file_id,
+                entry.key(),
+                variant_span,
+                *entry.get(),
+            )),
+            (_, Entry::Occupied(entry)) => report(diagnostics::duplicate_discriminant(
+                Severity::Error,
+                context.file_id,
+                entry.key(),
+                variant_span,
+                *entry.get(),
+            )),
+            (Entry::Vacant(name_entry), Entry::Vacant(value_entry)) => {
+                core_variants.push(core::EnumVariant {
+                    doc: variant.doc.clone(),
+                    start: variant_span.start(),
+                    name: name_entry.key().clone(),
+                    value: core_value,
+                });
+
+                name_entry.insert(variant_span);
+                value_entry.insert(variant_span);
+            }
+        }
+    }
+
+    (repr, core_variants)
+}
+
 /// Contextual information to be used when elaborating structure type fields.
 pub struct FieldContext<'items> {
     /// The file where these fields are defined (for error reporting).
@@ -169,10 +306,102 @@ impl<'items> FieldContext<'items> {
     }
 }
 
+/// Elaborate the structures included at the start of a structure type,
+/// returning copies of their fields.
+///
+/// Included fields are added to the field context, so that any fields that
+/// are later declared with the same names are reported as redeclarations.
+pub fn elaborate_struct_ty_includes(
+    context: &mut FieldContext<'_>,
+    includes: &[(Span, String)],
+    core_items: &[core::Item],
+    report: &mut dyn FnMut(Diagnostic),
+) -> Vec<core::TypeField> {
+    use std::collections::hash_map::Entry;
+
+    let mut core_fields = Vec::new();
+
+    for (span, name) in includes {
+        let core_struct_ty = core_items.iter().find_map(|core_item| match core_item {
+            core::Item::Struct(core_struct_ty) if core_struct_ty.name.0 == *name => {
+                Some(core_struct_ty)
+            }
+            _ => None,
+        });
+        let core_struct_ty = match core_struct_ty {
+            Some(core_struct_ty) => core_struct_ty,
+            None if context.items.contains_key(&core::Label(name.clone())) => {
+                report(diagnostics::error::include_non_struct(
+                    context.file_id,
+                    name,
+                    *span,
+                ));
+                continue;
+            }
+            None => {
+                report(diagnostics::error::var_name_not_found(
+                    context.file_id,
+                    name,
+                    *span,
+                ));
+                continue;
+            }
+        };
+
+        for field in &core_struct_ty.fields {
+            match context.fields.entry(field.name.clone()) {
+                Entry::Vacant(entry) => {
+                    core_fields.push(field.clone());
+                    entry.insert(*span);
+                }
+                Entry::Occupied(entry) => report(diagnostics::field_redeclaration(
+                    Severity::Error,
+                    context.file_id,
+                    entry.key(),
+                    *span,
+                    *entry.get(),
+                )),
+            }
+        }
+    }
+
+    core_fields
+}
+
+/// Elaborate the name of the field that captures the remaining bytes of a
+/// structure type, checking that it does not clash with any other field.
+pub fn elaborate_struct_ty_rest(
+    context: &mut FieldContext<'_>,
+    rest: &Option<(Span, String)>,
+    report: &mut dyn FnMut(Diagnostic),
+) -> Option<core::Label> {
+    use std::collections::hash_map::Entry;
+
+    let (span, name) = rest.as_ref()?;
+
+    match context.fields.entry(core::Label(name.clone())) {
+        Entry::Vacant(entry) => {
+            let label = entry.key().clone();
+            entry.insert(*span);
+            Some(label)
+        }
+        Entry::Occupied(entry) => {
+            report(diagnostics::field_redeclaration(
+                Severity::Error,
+                context.file_id,
+                entry.key(),
+                *span,
+                *entry.get(),
+            ));
+            None
+        }
+    }
+}
+
 /// Elaborate structure type fields in the surface syntax into structure type
 /// fields in the core syntax.
 pub fn elaborate_struct_ty_fields(
-    mut context: FieldContext<'_>,
+    context: &mut FieldContext<'_>,
     surface_fields: &[surface::TypeField],
     report: &mut dyn FnMut(Diagnostic),
 ) -> Vec<core::TypeField> {
@@ -189,11 +418,19 @@ pub fn elaborate_struct_ty_fields(
             &core::Value::Universe(core::Universe::Format),
             report,
         );
+        let attributes =
+            elaborate_field_attributes(&context.term_context(), &field.attributes, &ty, report);
 
         match context.fields.entry(label) {
             Entry::Vacant(entry) => {
                 core_fields.push(core::TypeField {
                     doc: field.doc.clone(),
+                    align: attributes.align,
+                    cfg: attributes.cfg,
+                    default: attributes.default,
+                    magic: attributes.magic,
+                    rename: attributes.rename,
+                    reserved: attributes.reserved,
                     start: field_span.start(),
                     name: entry.key().clone(),
                     term: ty,
@@ -214,6 +451,322 @@ pub fn elaborate_struct_ty_fields(
     core_fields
 }
 
+const STRUCT_ATTRIBUTES: &[&str] = &["doc_hide", "name", "transparent"];
+const FIELD_ATTRIBUTES: &[&str] = &["aligned", "cfg", "default", "magic", "name", "reserved"];
+
+/// Elaborate the attributes attached to a structure type, returning whether
+/// it was marked as `@transparent`, and the name given by a `@name(ident)`
+/// attribute, if present.
+///
+/// Transparent structure types must have exactly one unconditional,
+/// unreserved field.
+/// The `@doc_hide` attribute is only checked here: it is consulted by the
+/// documentation back-end, and has no meaning in the core language.
+pub fn elaborate_struct_attributes(
+    context: &TermContext<'_>,
+    struct_ty: &surface::StructType,
+    core_fields: &[core::TypeField],
+    report: &mut dyn FnMut(Diagnostic),
+) -> (bool, Option<core::Label>) {
+    let mut transparent = false;
+    let mut rename = None;
+    let mut seen_attributes = HashMap::<&str, Span>::new();
+
+    for attribute in &struct_ty.attributes {
+        let (name_span, name) = &attribute.name;
+
+        if !STRUCT_ATTRIBUTES.contains(&name.as_str()) {
+            report(diagnostics::error::unknown_attribute(
+                context.file_id,
+                name,
+                *name_span,
+                STRUCT_ATTRIBUTES,
+            ));
+            continue;
+        }
+
+        if let Some(original) = seen_attributes.insert(name, attribute.span) {
+            report(diagnostics::error::duplicate_attribute(
+                context.file_id,
+                name,
+                attribute.span,
+                original,
+            ));
+            continue;
+        }
+
+        let args = attribute.args.as_ref().map_or(&[][..], Vec::as_slice);
+        let expected_arg_count = match name.as_str() {
+            "name" => 1,
+            _ => 0,
+        };
+        if args.len() != expected_arg_count {
+            report(diagnostics::error::attribute_arg_count_mismatch(
+                context.file_id,
+                name,
+                attribute.span,
+                expected_arg_count,
+                args.len(),
+            ));
+            continue;
+        }
+
+        match (name.as_str(), core_fields) {
+            ("name", _) => rename = elaborate_name_override(context, &args[0], report),
+            ("transparent", [field]) if field.cfg.is_some() => {
+                let span = field.span();
+                report(diagnostics::error::transparent_conditional_field(
+                    context.file_id,
+                    span,
+                ));
+            }
+            ("transparent", [field]) if field.reserved => {
+                let span = field.span();
+                report(diagnostics::error::transparent_reserved_field(
+                    context.file_id,
+                    span,
+                ));
+            }
+            ("transparent", [_]) if struct_ty.rest.is_none() => transparent = true,
+            ("transparent", fields) => report(diagnostics::error::transparent_field_count(
+                context.file_id,
+                struct_ty.name.0,
+                fields.len() + struct_ty.rest.iter().count(),
+            )),
+            _ => {}
+        }
+    }
+
+    (transparent, rename)
+}
+
+/// The attributes attached to a structure type field, once elaborated.
+#[derive(Default)]
+pub struct FieldAttributes {
+    /// The alignment asserted by an `@aligned(n)` attribute.
+    pub align: Option<u64>,
+    /// The feature named by a `@cfg(feature)` attribute.
+    pub cfg: Option<core::Label>,
+    /// The value given by a `@default(value)` attribute.
+    pub default: Option<core::Term>,
+    /// The value given by a `@magic(value)` attribute.
+    pub magic: Option<core::Term>,
+    /// The name given by a `@name(ident)` attribute.
+    pub rename: Option<core::Label>,
+    /// Whether the field was marked as `@reserved`.
+    pub reserved: bool,
+}
+
+/// Elaborate the attributes attached to a structure type field.
+///
+/// Default values and magic numbers are checked against the field's format,
+/// `field_ty`.
+pub fn elaborate_field_attributes(
+    context: &TermContext<'_>,
+    attributes: &[surface::Attribute],
+    field_ty: &core::Term,
+    report: &mut dyn FnMut(Diagnostic),
+) -> FieldAttributes {
+    use num_traits::cast::ToPrimitive;
+
+    let mut field_attributes = FieldAttributes::default();
+    let mut seen_attributes = HashMap::<&str, Span>::new();
+
+    for attribute in attributes {
+        let (name_span, name) = &attribute.name;
+
+        if !FIELD_ATTRIBUTES.contains(&name.as_str()) {
+            report(diagnostics::error::unknown_attribute(
+                context.file_id,
+                name,
+                *name_span,
+                FIELD_ATTRIBUTES,
+            ));
+            continue;
+        }
+
+        if let Some(original) = seen_attributes.insert(name, attribute.span) {
+            report(diagnostics::error::duplicate_attribute(
+                context.file_id,
+                name,
+                attribute.span,
+                original,
+            ));
+            continue;
+        }
+
+        let args = attribute.args.as_ref().map_or(&[][..], Vec::as_slice);
+        let expected_arg_count = match name.as_str() {
+            "reserved" => 0,
+            _ => 1,
+        };
+        if args.len() != expected_arg_count {
+            report(diagnostics::error::attribute_arg_count_mismatch(
+                context.file_id,
+                name,
+                attribute.span,
+                expected_arg_count,
+                args.len(),
+            ));
+            continue;
+        }
+
+        if name == "reserved" {
+            field_attributes.reserved = true;
+            continue;
+        }
+
+        let surface_term = &args[0];
+        match name.as_str() {
+            "aligned" => {
+                let core_term = check_term(context, surface_term, &core::Value::IntType, report);
+                field_attributes.align = match core::semantics::eval(&core_term) {
+                    core::Value::IntConst(value) => match value.to_u64() {
+                        Some(value) if value > 0 => Some(value),
+                        Some(_) | None => {
+                            let span = surface_term.span();
+                            report(diagnostics::error::invalid_alignment(context.file_id, span));
+                            None
+                        }
+                    },
+                    _ => None,
+                };
+            }
+            "cfg" => {
+                field_attributes.cfg = match surface_term {
+                    surface::Term::Name(_, feature) => Some(core::Label(feature.clone())),
+                    surface_term => {
+                        let span = surface_term.span();
+                        report(diagnostics::error::invalid_cfg_feature(
+                            context.file_id,
+                            span,
+                        ));
+                        None
+                    }
+                };
+            }
+            "default" => {
+                field_attributes.default =
+                    elaborate_field_default(context, surface_term, field_ty, report);
+            }
+            "magic" => {
+                field_attributes.magic =
+                    elaborate_field_magic(context, surface_term, field_ty, report);
+            }
+            "name" => {
+                field_attributes.rename = elaborate_name_override(context, surface_term, report);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    field_attributes
+}
+
+/// Elaborate the argument of a `@name(ident)` attribute, which overrides the
+/// identifier used for a definition in generated code. Checking that the
+/// identifier is valid for a particular back-end is left to that back-end.
+fn elaborate_name_override(
+    context: &TermContext<'_>,
+    surface_term: &surface::Term,
+    report: &mut dyn FnMut(Diagnostic),
+) -> Option<core::Label> {
+    match surface_term {
+        surface::Term::Name(_, name) => Some(core::Label(name.clone())),
+        surface_term => {
+            let span = surface_term.span();
+            report(diagnostics::error::invalid_name_override(
+                context.file_id,
+                span,
+            ));
+            None
+        }
+    }
+}
+
+/// Elaborate the default value of a field, checking that it can be read by
+/// the field's format.
+fn elaborate_field_default(
+    context: &TermContext<'_>,
+    surface_term: &surface::Term,
+    field_ty: &core::Term,
+    report: &mut dyn FnMut(Diagnostic),
+) -> Option<core::Term> {
+    let field_ty_value = core::semantics::eval(field_ty);
+    let host_ty = match core::semantics::format_host_ty(&field_ty_value) {
+        Some(host_ty) => host_ty,
+        None => {
+            if field_ty_value != core::Value::Error {
+                report(diagnostics::unsupported_field_default(
+                    Severity::Error,
+                    context.file_id,
+                    surface_term.span(),
+                    field_ty.span(),
+                ));
+            }
+            return None;
+        }
+    };
+
+    let core_term = check_term(context, surface_term, &host_ty, report);
+    match (
+        core::semantics::eval(&core_term),
+        core::semantics::int_format_bounds(&field_ty_value),
+    ) {
+        (core::Value::Error, _) => None,
+        (core::Value::IntConst(value), Some((min, max))) if value < min || value > max => {
+            report(diagnostics::field_default_out_of_range(
+                Severity::Error,
+                context.file_id,
+                surface_term.span(),
+                &field_ty_value,
+            ));
+            None
+        }
+        (_, _) => Some(core_term),
+    }
+}
+
+/// Elaborate the magic number of a field, checking that the field's format
+/// is an unsigned integer format that can hold it.
+fn elaborate_field_magic(
+    context: &TermContext<'_>,
+    surface_term: &surface::Term,
+    field_ty: &core::Term,
+    report: &mut dyn FnMut(Diagnostic),
+) -> Option<core::Term> {
+    let field_ty_value = core::semantics::eval(field_ty);
+    let (min, max) = match core::semantics::int_format_bounds(&field_ty_value) {
+        Some((min, max)) if min == 0.into() && max <= u64::max_value().into() => (min, max),
+        Some(_) | None => {
+            if field_ty_value != core::Value::Error {
+                report(diagnostics::unsupported_field_magic(
+                    Severity::Error,
+                    context.file_id,
+                    surface_term.span(),
+                    field_ty.span(),
+                ));
+            }
+            return None;
+        }
+    };
+
+    let core_term = check_term(context, surface_term, &core::Value::IntType, report);
+    match core::semantics::eval(&core_term) {
+        core::Value::Error => None,
+        core::Value::IntConst(value) if value < min || value > max => {
+            report(diagnostics::field_magic_out_of_range(
+                Severity::Error,
+                context.file_id,
+                surface_term.span(),
+                &field_ty_value,
+            ));
+            None
+        }
+        _ => Some(core_term),
+    }
+}
+
 /// Contextual information to be used when elaborating terms.
 pub struct TermContext<'items> {
     /// The file where this term is located (for error reporting).
@@ -317,6 +870,18 @@ pub fn check_term(
 
             if core::semantics::equal(&synth_ty, expected_ty) {
                 core_term
+            } else if let (
+                core::Value::Universe(core::Universe::Format),
+                core::Value::Universe(core::Universe::Type),
+            ) = (expected_ty, &synth_ty)
+            {
+                // Using a host type in place of a format is an easy mistake
+                // to make, so it deserves a more helpful error.
+                report(diagnostics::error::expected_format(
+                    context.file_id,
+                    surface_term.span(),
+                ));
+                core::Term::Error(surface_term.span())
             } else {
                 report(diagnostics::type_mismatch(
                     Severity::Error,
@@ -337,6 +902,7 @@ pub fn synth_term(
     surface_term: &surface::Term,
     report: &mut dyn FnMut(Diagnostic),
 ) -> (core::Term, core::Value) {
+    use crate::core::builtin::{Builtin, BuiltinKind};
     use crate::core::Universe::{Format, Kind, Type};
 
     match surface_term {
@@ -352,8 +918,8 @@ pub fn synth_term(
                 core::Term::Item(*span, core::Label(name.to_string())),
                 ty.clone(),
             ),
-            None => match name.as_str() {
-                "Kind" => {
+            None => match Builtin::from_name(name) {
+                Some(Builtin::Kind) => {
                     report(diagnostics::kind_has_no_type(
                         Severity::Error,
                         context.file_id,
@@ -361,39 +927,16 @@ pub fn synth_term(
                     ));
                     (core::Term::Error(*span), core::Value::Error)
                 }
-                "Type" => (
-                    core::Term::Universe(*span, Type),
-                    core::Value::Universe(Kind),
-                ),
-                "Format" => (
-                    core::Term::Universe(*span, Format),
-                    core::Value::Universe(Kind),
-                ),
-                "U8" => (core::Term::U8Type(*span), core::Value::Universe(Format)),
-                "U16Le" => (core::Term::U16LeType(*span), core::Value::Universe(Format)),
-                "U16Be" => (core::Term::U16BeType(*span), core::Value::Universe(Format)),
-                "U32Le" => (core::Term::U32LeType(*span), core::Value::Universe(Format)),
-                "U32Be" => (core::Term::U32BeType(*span), core::Value::Universe(Format)),
-                "U64Le" => (core::Term::U64LeType(*span), core::Value::Universe(Format)),
-                "U64Be" => (core::Term::U64BeType(*span), core::Value::Universe(Format)),
-                "S8" => (core::Term::S8Type(*span), core::Value::Universe(Format)),
-                "S16Le" => (core::Term::S16LeType(*span), core::Value::Universe(Format)),
-                "S16Be" => (core::Term::S16BeType(*span), core::Value::Universe(Format)),
-                "S32Le" => (core::Term::S32LeType(*span), core::Value::Universe(Format)),
-                "S32Be" => (core::Term::S32BeType(*span), core::Value::Universe(Format)),
-                "S64Le" => (core::Term::S64LeType(*span), core::Value::Universe(Format)),
-                "S64Be" => (core::Term::S64BeType(*span), core::Value::Universe(Format)),
-                "F32Le" => (core::Term::F32LeType(*span), core::Value::Universe(Format)),
-                "F32Be" => (core::Term::F32BeType(*span), core::Value::Universe(Format)),
-                "F64Le" => (core::Term::F64LeType(*span), core::Value::Universe(Format)),
-                "F64Be" => (core::Term::F64BeType(*span), core::Value::Universe(Format)),
-                "Bool" => (core::Term::BoolType(*span), core::Value::Universe(Type)),
-                "Int" => (core::Term::IntType(*span), core::Value::Universe(Type)),
-                "F32" => (core::Term::F32Type(*span), core::Value::Universe(Type)),
-                "F64" => (core::Term::F64Type(*span), core::Value::Universe(Type)),
-                "true" => (core::Term::BoolConst(*span, true), core::Value::BoolType),
-                "false" => (core::Term::BoolConst(*span, false), core::Value::BoolType),
-                _ => {
+                Some(builtin) => {
+                    let ty = match builtin.kind() {
+                        BuiltinKind::Universe => core::Value::Universe(Kind),
+                        BuiltinKind::Format => core::Value::Universe(Format),
+                        BuiltinKind::Type => core::Value::Universe(Type),
+                        BuiltinKind::BoolConst => core::Value::BoolType,
+                    };
+                    (builtin.term(*span), ty)
+                }
+                None => {
                     report(diagnostics::error::var_name_not_found(
                         context.file_id,
                         name.as_str(),
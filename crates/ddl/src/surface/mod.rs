@@ -84,6 +84,12 @@ pub enum Item {
     /// struct <name> {}
     /// ```
     Struct(StructType),
+    /// Enum definitions.
+    ///
+    /// ```text
+    /// enum <name> : <term> {}
+    /// ```
+    Enum(EnumType),
 }
 
 impl Item {
@@ -95,6 +101,7 @@ impl Item {
         match self {
             Item::Alias(alias) => alias.doc(alloc),
             Item::Struct(struct_ty) => struct_ty.doc(alloc),
+            Item::Enum(enum_ty) => enum_ty.doc(alloc),
         }
     }
 }
@@ -129,17 +136,17 @@ impl Alias {
         (alloc.nil())
             .append(docs)
             .append(&self.name.1)
-            .append(alloc.space())
-            .append("=")
-            .group()
             .append(match &self.ty {
                 None => alloc.nil(),
                 Some(ty) => (alloc.nil())
                     .append(alloc.space())
-                    .append(ty.doc(alloc))
+                    .append(":")
                     .group()
-                    .nest(4),
+                    .append((alloc.space()).append(ty.doc(alloc)).group().nest(4)),
             })
+            .append(alloc.space())
+            .append("=")
+            .group()
             .append(
                 (alloc.nil())
                     .append(alloc.space())
@@ -158,10 +165,19 @@ pub struct StructType {
     pub span: Span,
     /// Doc comment.
     pub doc: Arc<[String]>,
+    /// Attributes attached to the struct.
+    pub attributes: Vec<Attribute>,
     /// Name of this definition.
     pub name: (Span, String),
+    /// Structures whose fields are included at the start of this one.
+    pub includes: Vec<(Span, String)>,
     /// Fields in the struct.
     pub fields: Vec<TypeField>,
+    /// Whether the data must end after the last field.
+    pub eof: bool,
+    /// The name of a field that captures any bytes remaining after the last
+    /// field, up to the end of the data.
+    pub rest: Option<(Span, String)>,
 }
 
 impl StructType {
@@ -176,19 +192,48 @@ impl StructType {
                 .append(alloc.newline())
         }));
 
+        let attributes = alloc.concat(self.attributes.iter().map(|attribute| {
+            (alloc.nil())
+                .append(attribute.doc(alloc))
+                .append(alloc.newline())
+        }));
+
         let struct_prefix = (alloc.nil())
             .append("struct")
             .append(alloc.space())
             .append(&self.name.1)
             .append(alloc.space());
 
-        let struct_ty = if self.fields.is_empty() {
+        let eof = match self.eof {
+            true => (alloc.nil()).append(alloc.newline()).append("eof,").nest(4),
+            false => alloc.nil(),
+        };
+
+        let rest = match &self.rest {
+            None => alloc.nil(),
+            Some((_, rest)) => (alloc.nil())
+                .append(alloc.newline())
+                .append(format!("...{},", rest))
+                .nest(4),
+        };
+
+        let struct_ty = if self.includes.is_empty()
+            && self.fields.is_empty()
+            && !self.eof
+            && self.rest.is_none()
+        {
             (alloc.nil()).append(struct_prefix).append("{}").group()
         } else {
             (alloc.nil())
                 .append(struct_prefix)
                 .append("{")
                 .group()
+                .append(alloc.concat(self.includes.iter().map(|(_, name)| {
+                    (alloc.nil())
+                        .append(alloc.newline())
+                        .append(format!("include {},", name))
+                        .nest(4)
+                })))
                 .append(alloc.concat(self.fields.iter().map(|field| {
                     (alloc.nil())
                         .append(alloc.newline())
@@ -196,11 +241,16 @@ impl StructType {
                         .nest(4)
                         .group()
                 })))
+                .append(eof)
+                .append(rest)
                 .append(alloc.newline())
                 .append("}")
         };
 
-        (alloc.nil()).append(docs).append(struct_ty)
+        (alloc.nil())
+            .append(docs)
+            .append(attributes)
+            .append(struct_ty)
     }
 }
 
@@ -208,6 +258,7 @@ impl StructType {
 #[derive(Debug, Clone)]
 pub struct TypeField {
     pub doc: Arc<[String]>,
+    pub attributes: Vec<Attribute>,
     pub name: (Span, String),
     pub term: Term,
 }
@@ -224,8 +275,15 @@ impl TypeField {
                 .append(alloc.newline())
         }));
 
+        let attributes = alloc.concat(self.attributes.iter().map(|attribute| {
+            (alloc.nil())
+                .append(attribute.doc(alloc))
+                .append(alloc.space())
+        }));
+
         (alloc.nil())
             .append(docs)
+            .append(attributes)
             .append(
                 (alloc.nil())
                     .append(&self.name.1)
@@ -242,6 +300,141 @@ impl TypeField {
     }
 }
 
+/// An enum type definition.
+#[derive(Debug, Clone)]
+pub struct EnumType {
+    /// The full span of this definition.
+    pub span: Span,
+    /// Doc comment.
+    pub doc: Arc<[String]>,
+    /// Name of this definition.
+    pub name: (Span, String),
+    /// The integer format that the variants are read from.
+    pub repr: Term,
+    /// Variants in the enum.
+    pub variants: Vec<EnumVariant>,
+}
+
+impl EnumType {
+    pub fn doc<'core, D>(&'core self, alloc: &'core D) -> DocBuilder<'core, D>
+    where
+        D: DocAllocator<'core>,
+        D::Doc: Clone,
+    {
+        let docs = alloc.concat(self.doc.iter().map(|line| {
+            (alloc.nil())
+                .append(format!("///{}", line))
+                .append(alloc.newline())
+        }));
+
+        let enum_prefix = (alloc.nil())
+            .append("enum")
+            .append(alloc.space())
+            .append(&self.name.1)
+            .append(alloc.space())
+            .append(":")
+            .append(alloc.space())
+            .append(self.repr.doc(alloc))
+            .append(alloc.space());
+
+        let enum_ty = if self.variants.is_empty() {
+            (alloc.nil()).append(enum_prefix).append("{}").group()
+        } else {
+            (alloc.nil())
+                .append(enum_prefix)
+                .append("{")
+                .group()
+                .append(alloc.concat(self.variants.iter().map(|variant| {
+                    (alloc.nil())
+                        .append(alloc.newline())
+                        .append(variant.doc(alloc))
+                        .nest(4)
+                        .group()
+                })))
+                .append(alloc.newline())
+                .append("}")
+        };
+
+        (alloc.nil()).append(docs).append(enum_ty)
+    }
+}
+
+/// A variant in an enum type definition.
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    pub doc: Arc<[String]>,
+    pub name: (Span, String),
+    pub value: Term,
+}
+
+impl EnumVariant {
+    pub fn doc<'core, D>(&'core self, alloc: &'core D) -> DocBuilder<'core, D>
+    where
+        D: DocAllocator<'core>,
+        D::Doc: Clone,
+    {
+        let docs = alloc.concat(self.doc.iter().map(|line| {
+            (alloc.nil())
+                .append(format!("///{}", line))
+                .append(alloc.newline())
+        }));
+
+        (alloc.nil())
+            .append(docs)
+            .append(
+                (alloc.nil())
+                    .append(&self.name.1)
+                    .append(alloc.space())
+                    .append("=")
+                    .group(),
+            )
+            .append(
+                (alloc.nil())
+                    .append(alloc.space())
+                    .append(self.value.doc(alloc))
+                    .append(","),
+            )
+    }
+}
+
+/// An attribute attached to a struct or a field.
+///
+/// ```text
+/// @<name>
+/// @<name>(<args>)
+/// ```
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    /// The full span of this attribute.
+    pub span: Span,
+    /// Name of the attribute.
+    pub name: (Span, String),
+    /// Arguments passed to the attribute, or `None` if the attribute was
+    /// written without parentheses.
+    pub args: Option<Vec<Term>>,
+}
+
+impl Attribute {
+    pub fn doc<'core, D>(&'core self, alloc: &'core D) -> DocBuilder<'core, D>
+    where
+        D: DocAllocator<'core>,
+        D::Doc: Clone,
+    {
+        let args = match &self.args {
+            None => alloc.nil(),
+            Some(args) => (alloc.nil())
+                .append("(")
+                .append(alloc.intersperse(
+                    args.iter().map(|arg| arg.doc(alloc)),
+                    alloc.text(",").append(alloc.space()),
+                ))
+                .append(")"),
+        };
+
+        (alloc.nil()).append("@").append(&self.name.1).append(args)
+    }
+}
+
 /// Terms.
 #[derive(Debug, Clone)]
 pub enum Term {
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-// use ddl_test_util::ddl::binary;
+use ddl_test_util::ddl::binary;
 use ddl_rt::{ReadError, ReadScope, FormatWriter, U8, F64Be};
 
 #[path = "../../snapshots/alias/pass_if_else_format_type_item.rs"]
@@ -32,11 +32,10 @@ fn valid_test() {
     let scope = ReadScope::new(writer.buffer());
     let singleton = scope.read::<fixture::Test>().unwrap();
 
-    // FIXME(#162): Requires us to evaluate items!
-    // let test = binary::read::read_module_item(&FIXTURE, &"Test", &mut scope.reader()).unwrap();
+    let test = binary::read::read_module_item(&FIXTURE, &"Test", &mut scope.reader()).unwrap();
     assert_eq!(singleton.inner(), ddl_rt::Either::Left(23.64e10));
 
-    // assert_eq!(test, binary::Term::F64(singleton.inner().left().unwrap().into()));
+    assert_eq!(test, binary::Term::F64(singleton.inner().left().unwrap().into()));
 
     // TODO: Check remaining
 }
@@ -50,11 +49,10 @@ fn valid_test_trailing() {
     let scope = ReadScope::new(writer.buffer());
     let singleton = scope.read::<fixture::Test>().unwrap();
 
-    // FIXME(#162): Requires us to evaluate items!
-    // let test = binary::read::read_module_item(&FIXTURE, &"Test", &mut scope.reader()).unwrap();
+    let mut reader = scope.reader();
+    let test = binary::read::read_module_item(&FIXTURE, &"Test", &mut reader).unwrap();
     assert_eq!(singleton.inner(), ddl_rt::Either::Left(781.453298));
 
-    // assert_eq!(test, binary::Term::F64(singleton.inner().left().unwrap().into()));
-
-    // TODO: Check remaining
+    assert_eq!(test, binary::Term::F64(singleton.inner().left().unwrap().into()));
+    assert_eq!(reader.read::<U8>().unwrap(), 42);
 }
@@ -0,0 +1,15 @@
+#![cfg(test)]
+
+#[path = "../../snapshots/alias/pass_int_limits.rs"]
+mod fixture;
+
+#[test]
+fn u64_max() {
+    assert_eq!(fixture::TEST_U_64_MAX, u64::max_value());
+}
+
+#[test]
+fn i64_limits() {
+    assert_eq!(fixture::TEST_I_64_MAX, i64::max_value());
+    assert_eq!(fixture::TEST_I_64_MIN, i64::min_value());
+}
@@ -0,0 +1,21 @@
+#![cfg(test)]
+
+#[path = "../../snapshots/alias/pass_int_limits_128.rs"]
+mod fixture;
+
+#[test]
+fn beyond_64_bits() {
+    assert_eq!(fixture::TEST_U_64_MAX_PLUS_ONE, i128::from(u64::max_value()) + 1);
+    assert_eq!(fixture::TEST_I_64_MIN_MINUS_ONE, i128::from(i64::min_value()) - 1);
+}
+
+#[test]
+fn u128_max() {
+    assert_eq!(fixture::TEST_U_128_MAX, u128::max_value());
+}
+
+#[test]
+fn i128_limits() {
+    assert_eq!(fixture::TEST_I_128_MAX, i128::max_value());
+    assert_eq!(fixture::TEST_I_128_MIN, i128::min_value());
+}
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, I16Le, ReadError, ReadScope, U16Be, U8};
+
+#[path = "../../snapshots/enum/pass_simple.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/enum/pass_simple.core.ddl");
+
+#[test]
+fn valid_header() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(0x4D4D); // Header::byte_order
+    writer.write::<U8>(5); // Header::compression
+    writer.write::<I16Le>(-1); // Header::delta
+
+    let scope = ReadScope::new(writer.buffer());
+    let header = scope.read::<fixture::Header>().unwrap();
+
+    assert_eq!(header.byte_order(), fixture::ByteOrder::Big);
+    assert_eq!(header.compression(), fixture::Compression::Lzw);
+    assert_eq!(header.delta(), fixture::Delta::Backward);
+    assert_eq!(fixture::Compression::Lzw as u8, 5);
+    assert_eq!(fixture::Delta::Backward as i16, -1);
+    assert!(binary::read::read_module_item(&FIXTURE, &"Header", &mut scope.reader()).is_ok());
+}
+
+#[test]
+fn unknown_discriminant() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(0x4949); // Header::byte_order
+    writer.write::<U8>(2); // Header::compression
+    writer.write::<I16Le>(1); // Header::delta
+
+    let scope = ReadScope::new(writer.buffer());
+
+    match scope.read::<fixture::Header>() {
+        Err(ReadError::Field { location, error }) => {
+            assert_eq!(location.name, "compression");
+            match *error {
+                ReadError::UnknownDiscriminant { ty, value } => {
+                    assert_eq!(ty, "Compression");
+                    assert_eq!(value, 2);
+                },
+                err => panic!("unknown discriminant error expected, found: {:?}", err),
+            }
+        },
+        Err(err) => panic!("field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+
+    match binary::read::read_module_item(&FIXTURE, &"Header", &mut scope.reader()) {
+        Err(ReadError::UnknownDiscriminant { ty, value }) => {
+            assert_eq!(ty, "Compression");
+            assert_eq!(value, 2);
+        },
+        Err(err) => panic!("unknown discriminant error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+}
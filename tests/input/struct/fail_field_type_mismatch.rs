@@ -0,0 +1,26 @@
+#![cfg(test)]
+
+use ddl_rt::{ReadError, ReadScope};
+
+// The accessors of fields with invalid types are never used, because reading
+// them always fails.
+#[allow(dead_code)]
+#[path = "../../snapshots/struct/fail_field_type_mismatch.rs"]
+mod fixture;
+
+#[test]
+fn invalid_field_type() {
+    let scope = ReadScope::new(&[]);
+
+    match scope.read::<fixture::Foo>() {
+        Err(ReadError::Field { location, error }) => {
+            assert_eq!(location.name, "field_type");
+            match *error {
+                ReadError::InvalidDataDescription => {},
+                err => panic!("invalid data description error expected, found: {:?}", err),
+            }
+        },
+        Err(err) => panic!("field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+}
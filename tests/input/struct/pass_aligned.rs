@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadError, ReadScope, U16Be, U32Be, U8};
+
+#[path = "../../snapshots/struct/pass_aligned.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_aligned.core.ddl");
+
+#[test]
+fn valid_header() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(1); // Header::tag
+    writer.write::<U8>(0); // Header::reserved
+    writer.write::<U16Be>(512); // Header::length
+    writer.write::<U32Be>(8); // Header::offset
+
+    let scope = ReadScope::new(writer.buffer());
+    let header = scope.read::<fixture::Header>().unwrap();
+
+    assert_eq!(header.tag(), 1);
+    assert_eq!(header.reserved(), 0);
+    assert_eq!(header.length(), 512);
+    assert_eq!(header.offset(), 8);
+    assert!(binary::read::read_module_item(&FIXTURE, &"Header", &mut scope.reader()).is_ok());
+}
+
+#[test]
+fn aligned_in_scope() {
+    let mut writer = FormatWriter::new(vec![0, 0, 0]);
+    writer.write::<U8>(1); // Misaligned::tag
+    writer.write::<U32Be>(42); // Misaligned::value
+
+    let scope = ReadScope::new(writer.buffer()).offset(3);
+    let misaligned = scope.read::<fixture::Misaligned>().unwrap();
+
+    assert_eq!(misaligned.tag(), 1);
+    assert_eq!(misaligned.value(), 42);
+}
+
+#[test]
+fn misaligned_field() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(1); // Misaligned::tag
+    writer.write::<U32Be>(42); // Misaligned::value
+
+    let scope = ReadScope::new(writer.buffer());
+
+    match scope.read::<fixture::Misaligned>() {
+        Err(ReadError::MisalignedField { field, offset, align }) => {
+            assert_eq!(field, "value");
+            assert_eq!(offset, 1);
+            assert_eq!(align, 4);
+        },
+        Err(err) => panic!("misaligned field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+
+    match binary::read::read_module_item(&FIXTURE, &"Misaligned", &mut scope.reader()) {
+        Err(ReadError::MisalignedField { field, offset, align }) => {
+            assert_eq!(field, "value");
+            assert_eq!(offset, 1);
+            assert_eq!(align, 4);
+        },
+        Err(err) => panic!("misaligned field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+}
+
+#[test]
+fn aligned_default() {
+    let mut writer = FormatWriter::new(vec![0, 0]);
+    writer.write::<U16Be>(1); // Record::tag
+
+    let scope = ReadScope::new(writer.buffer()).offset(2);
+    let record = scope.read::<fixture::Record>().unwrap();
+
+    assert_eq!(record.tag(), 1);
+    assert_eq!(record.trailer(), 0);
+}
+
+#[test]
+fn misaligned_default() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(1); // Record::tag
+
+    let scope = ReadScope::new(writer.buffer());
+
+    // Alignment is checked before falling back to the default value
+    match scope.read::<fixture::Record>() {
+        Err(ReadError::MisalignedField { field, offset, align }) => {
+            assert_eq!(field, "trailer");
+            assert_eq!(offset, 2);
+            assert_eq!(align, 4);
+        },
+        Err(err) => panic!("misaligned field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+
+    match binary::read::read_module_item(&FIXTURE, &"Record", &mut scope.reader()) {
+        Err(ReadError::MisalignedField { field, offset, align }) => {
+            assert_eq!(field, "trailer");
+            assert_eq!(offset, 2);
+            assert_eq!(align, 4);
+        },
+        Err(err) => panic!("misaligned field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadError, ReadScope, U32Le, U8};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_cstr.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_cstr.core.ddl");
+
+#[test]
+fn valid_symbol() {
+    let mut writer = FormatWriter::new(vec![]);
+    for byte in b"main\0" {
+        writer.write::<U8>(*byte); // Symbol::name
+    }
+    writer.write::<U32Le>(0x1000); // Symbol::address
+    for byte in b".text\0" {
+        writer.write::<U8>(*byte); // Symbol::section
+    }
+
+    let scope = ReadScope::new(writer.buffer());
+    let symbol = scope.read::<fixture::Symbol>().unwrap();
+
+    match binary::read::read_module_item(&FIXTURE, &"Symbol", &mut scope.reader()).unwrap() {
+        binary::Term::Struct(fields) => {
+            assert_eq!(symbol.name().as_bytes(), b"main");
+            assert_eq!(symbol.address(), 0x1000);
+            assert_eq!(symbol.section().as_bytes(), b".text");
+
+            assert_eq!(fields, BTreeMap::from_iter(vec![
+                ("name".to_owned(), binary::Term::Bytes(symbol.name().as_bytes().to_vec())),
+                ("address".to_owned(), binary::Term::Int(symbol.address().into())),
+                ("section".to_owned(), binary::Term::Bytes(symbol.section().as_bytes().to_vec())),
+            ]));
+        },
+        _ => panic!("struct expected"),
+    }
+}
+
+#[test]
+fn eof_before_terminator() {
+    let mut writer = FormatWriter::new(vec![]);
+    for byte in b"main\0" {
+        writer.write::<U8>(*byte); // Symbol::name
+    }
+    writer.write::<U32Le>(0x1000); // Symbol::address
+    for byte in b".text" {
+        writer.write::<U8>(*byte); // Symbol::section
+    }
+
+    let scope = ReadScope::new(writer.buffer());
+    match scope.read::<fixture::Symbol>() {
+        Err(ReadError::Field { error, .. }) => match *error {
+            ReadError::Eof(_) => {}
+            error => panic!("eof error expected, found: {:?}", error),
+        },
+        Err(error) => panic!("field error expected, found: {:?}", error),
+        Ok(_) => panic!("error expected"),
+    }
+}
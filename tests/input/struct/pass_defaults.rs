@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use ddl_rt::{F32Be, FormatWriter, I32Le, ReadError, ReadScope, U128Le, U16Be, U8};
+
+#[path = "../../snapshots/struct/pass_defaults.rs"]
+mod fixture;
+
+#[test]
+fn complete() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(42); // Record::id
+    writer.write::<U8>(3); // Record::flags
+    writer.write::<I32Le>(7); // Record::priority
+    writer.write::<F32Be>(2.0); // Record::scale
+    writer.write::<U128Le>(5); // Record::checksum
+
+    let record = ReadScope::new(writer.buffer()).read::<fixture::Record>().unwrap();
+
+    assert_eq!(record.id(), 42);
+    assert_eq!(record.flags(), 3);
+    assert_eq!(record.priority(), 7);
+    assert_eq!(record.scale(), 2.0);
+    assert_eq!(record.checksum(), 5);
+}
+
+#[test]
+fn truncated() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(42); // Record::id
+    writer.write::<U8>(3); // Record::flags
+
+    let record = ReadScope::new(writer.buffer()).read::<fixture::Record>().unwrap();
+
+    assert_eq!(record.id(), 42);
+    assert_eq!(record.flags(), 3);
+    assert_eq!(record.priority(), -1);
+    assert_eq!(record.scale(), 0.5);
+    assert_eq!(record.checksum(), 1 << 64);
+}
+
+#[test]
+fn truncated_mid_field() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(42); // Record::id
+    writer.write::<U8>(3); // Record::flags
+    writer.write::<U16Be>(7); // Half of Record::priority
+
+    match ReadScope::new(writer.buffer()).read::<fixture::Record>() {
+        Err(ReadError::Field { location, .. }) => assert_eq!(location.name, "priority"),
+        Err(err) => panic!("field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+}
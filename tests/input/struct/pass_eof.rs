@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadError, ReadScope, U8};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_eof.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_eof.core.ddl");
+
+#[test]
+fn valid_exact() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(31); // Exact::first
+    writer.write::<U8>(42); // Exact::second
+
+    let scope = ReadScope::new(writer.buffer());
+    let exact = scope.read::<fixture::Exact>().unwrap();
+
+    match binary::read::read_module_item(&FIXTURE, &"Exact", &mut scope.reader()).unwrap() {
+        binary::Term::Struct(fields) => {
+            assert_eq!(exact.first(), 31);
+            assert_eq!(exact.second(), 42);
+
+            assert_eq!(fields, BTreeMap::from_iter(vec![
+                ("first".to_owned(), binary::Term::Int(exact.first().into())),
+                ("second".to_owned(), binary::Term::Int(exact.second().into())),
+            ]));
+        },
+        _ => panic!("struct expected"),
+    }
+}
+
+#[test]
+fn exact_trailing() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(31); // Exact::first
+    writer.write::<U8>(42); // Exact::second
+    writer.write::<U8>(255);
+
+    let scope = ReadScope::new(writer.buffer());
+
+    match scope.read::<fixture::Exact>() {
+        Err(ReadError::ExpectedEof { remaining }) => assert_eq!(remaining, 1),
+        Err(err) => panic!("expected eof error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+
+    match binary::read::read_module_item(&FIXTURE, &"Exact", &mut scope.reader()) {
+        Err(ReadError::ExpectedEof { remaining }) => assert_eq!(remaining, 1),
+        Err(err) => panic!("expected eof error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+}
@@ -0,0 +1,47 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{F16Be, F16Le, FormatWriter, ReadScope};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_half_floats.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_half_floats.core.ddl");
+
+#[test]
+fn eof_first() {
+    let writer = FormatWriter::new(vec![]);
+
+    let read_scope = ReadScope::new(writer.buffer());
+    let vertex = read_scope.read::<fixture::Vertex>();
+    let vertex_binary =
+        binary::read::read_module_item(&FIXTURE, &"Vertex", &mut read_scope.reader());
+
+    assert!(vertex.is_err());
+    assert!(vertex_binary.is_err());
+}
+
+#[test]
+fn valid_vertex() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<F16Le>(1.5); // Vertex::x
+    writer.write::<F16Be>(-0.25); // Vertex::y
+
+    let read_scope = ReadScope::new(writer.buffer());
+    let vertex = read_scope.read::<fixture::Vertex>().unwrap();
+    let vertex_binary =
+        binary::read::read_module_item(&FIXTURE, &"Vertex", &mut read_scope.reader()).unwrap();
+
+    assert_eq!(vertex.x(), 1.5);
+    assert_eq!(vertex.y(), -0.25);
+
+    assert_eq!(
+        vertex_binary,
+        binary::Term::Struct(BTreeMap::from_iter(vec![
+            ("x".to_owned(), binary::Term::F32(vertex.x())),
+            ("y".to_owned(), binary::Term::F32(vertex.y())),
+        ])),
+    );
+}
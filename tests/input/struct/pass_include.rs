@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadScope, U16Be, U8};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_include.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_include.core.ddl");
+
+#[test]
+fn valid_point_chunk() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(1); // PointChunk::tag
+    writer.write::<U16Be>(2); // PointChunk::len
+    writer.write::<U8>(3); // PointChunk::x
+    writer.write::<U8>(4); // PointChunk::y
+
+    let scope = ReadScope::new(writer.buffer());
+    let point_chunk = scope.read::<fixture::PointChunk>().unwrap();
+
+    match binary::read::read_module_item(&FIXTURE, &"PointChunk", &mut scope.reader()).unwrap() {
+        binary::Term::Struct(fields) => {
+            assert_eq!(point_chunk.tag(), 1);
+            assert_eq!(point_chunk.len(), 2);
+            assert_eq!(point_chunk.x(), 3);
+            assert_eq!(point_chunk.y(), 4);
+
+            assert_eq!(fields, BTreeMap::from_iter(vec![
+                ("tag".to_owned(), binary::Term::Int(point_chunk.tag().into())),
+                ("len".to_owned(), binary::Term::Int(point_chunk.len().into())),
+                ("x".to_owned(), binary::Term::Int(point_chunk.x().into())),
+                ("y".to_owned(), binary::Term::Int(point_chunk.y().into())),
+            ]));
+        },
+        _ => panic!("struct expected"),
+    }
+}
+
+#[test]
+fn valid_empty_chunk() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(5); // EmptyChunk::tag
+    writer.write::<U16Be>(0); // EmptyChunk::len
+
+    let scope = ReadScope::new(writer.buffer());
+    let empty_chunk = scope.read::<fixture::EmptyChunk>().unwrap();
+    let header = scope.read::<fixture::Header>().unwrap();
+
+    assert_eq!(empty_chunk.tag(), header.tag());
+    assert_eq!(empty_chunk.len(), header.len());
+}
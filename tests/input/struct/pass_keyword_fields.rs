@@ -0,0 +1,37 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadScope, U8};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_keyword_fields.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_keyword_fields.core.ddl");
+
+#[test]
+fn valid_keywords() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(1); // Keywords::type
+    writer.write::<U8>(2); // Keywords::match
+    writer.write::<U8>(3); // Keywords::self
+
+    let scope = ReadScope::new(writer.buffer());
+    let keywords = scope.read::<fixture::Keywords>().unwrap();
+
+    match binary::read::read_module_item(&FIXTURE, &"Keywords", &mut scope.reader()).unwrap() {
+        binary::Term::Struct(fields) => {
+            assert_eq!(keywords.r#type(), 1);
+            assert_eq!(keywords.r#match(), 2);
+            assert_eq!(keywords.self_(), 3);
+
+            assert_eq!(fields, BTreeMap::from_iter(vec![
+                ("type".to_owned(), binary::Term::Int(keywords.r#type().into())),
+                ("match".to_owned(), binary::Term::Int(keywords.r#match().into())),
+                ("self".to_owned(), binary::Term::Int(keywords.self_().into())),
+            ]));
+        },
+        _ => panic!("struct expected"),
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadError, ReadScope, U16Be, U32Le};
+
+#[path = "../../snapshots/struct/pass_magic.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_magic.core.ddl");
+
+#[test]
+fn valid_header() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(0x424D); // BitmapHeader::signature
+    writer.write::<U32Le>(70); // BitmapHeader::size
+    writer.write::<U32Le>(0); // BitmapHeader::reserved
+    writer.write::<U32Le>(54); // BitmapHeader::offset
+
+    let scope = ReadScope::new(writer.buffer());
+    let header = scope.read::<fixture::BitmapHeader>().unwrap();
+
+    assert_eq!(header.signature(), 0x424D);
+    assert_eq!(header.size(), 70);
+    assert_eq!(header.offset(), 54);
+    assert!(binary::read::read_module_item(&FIXTURE, &"BitmapHeader", &mut scope.reader()).is_ok());
+}
+
+#[test]
+fn unexpected_magic() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(0x8950); // BitmapHeader::signature
+    writer.write::<U32Le>(70); // BitmapHeader::size
+    writer.write::<U32Le>(0); // BitmapHeader::reserved
+    writer.write::<U32Le>(54); // BitmapHeader::offset
+
+    let scope = ReadScope::new(writer.buffer());
+
+    match scope.read::<fixture::BitmapHeader>() {
+        Err(ReadError::UnexpectedMagic { field, expected, found }) => {
+            assert_eq!(field, "signature");
+            assert_eq!(expected, 0x424D);
+            assert_eq!(found, 0x8950);
+        },
+        Err(err) => panic!("unexpected magic error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+
+    match binary::read::read_module_item(&FIXTURE, &"BitmapHeader", &mut scope.reader()) {
+        Err(ReadError::UnexpectedMagic { field, expected, found }) => {
+            assert_eq!(field, "signature");
+            assert_eq!(expected, 0x424D);
+            assert_eq!(found, 0x8950);
+        },
+        Err(err) => panic!("unexpected magic error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+}
+
+#[test]
+fn unexpected_reserved_magic() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(0x424D); // BitmapHeader::signature
+    writer.write::<U32Le>(70); // BitmapHeader::size
+    writer.write::<U32Le>(1); // BitmapHeader::reserved
+    writer.write::<U32Le>(54); // BitmapHeader::offset
+
+    let scope = ReadScope::new(writer.buffer());
+
+    match scope.read::<fixture::BitmapHeader>() {
+        Err(ReadError::UnexpectedMagic { field, .. }) => assert_eq!(field, "reserved"),
+        Err(err) => panic!("unexpected magic error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+}
+
+#[test]
+fn encode_header() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "BitmapHeader", FormatWriter::new(vec![])).unwrap();
+    encoder.field("signature", &binary::Term::Int(0x424D.into())).unwrap();
+    encoder.field("size", &binary::Term::Int(70.into())).unwrap();
+    encoder.field("offset", &binary::Term::Int(54.into())).unwrap();
+    let writer = encoder.finish().unwrap();
+
+    let scope = ReadScope::new(writer.buffer());
+    let header = scope.read::<fixture::BitmapHeader>().unwrap();
+
+    assert_eq!(header.signature(), 0x424D);
+    assert_eq!(header.size(), 70);
+    assert_eq!(header.offset(), 54);
+}
+
+#[test]
+fn encode_unexpected_magic() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "BitmapHeader", FormatWriter::new(vec![])).unwrap();
+
+    assert_eq!(
+        encoder.field("signature", &binary::Term::Int(0x8950.into())),
+        Err(binary::write::WriteError::UnexpectedMagic {
+            field: "signature".to_owned(),
+            expected: 0x424D.into(),
+            found: 0x8950.into(),
+        }),
+    );
+
+    encoder.field("signature", &binary::Term::Int(0x424D.into())).unwrap();
+    encoder.field("size", &binary::Term::Int(70.into())).unwrap();
+    encoder.field("offset", &binary::Term::Int(54.into())).unwrap();
+    assert_eq!(encoder.finish().unwrap().buffer().len(), 14);
+}
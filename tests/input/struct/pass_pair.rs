@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use ddl_test_util::ddl::binary;
-use ddl_rt::{I8, ReadError, ReadScope, FormatWriter, U8};
+use ddl_rt::{FieldLocation, I8, ReadError, ReadFormat, ReadScope, FormatWriter, U8};
 use std::collections::BTreeMap;
 use std::iter::FromIterator;
 
@@ -18,8 +18,14 @@ fn eof_first() {
     let pair = scope.read::<fixture::Pair>();
 
     match pair {
-        Err(ReadError::Eof(_)) => {},
-        Err(err) => panic!("eof error expected, found: {:?}", err),
+        Err(ReadError::Field { location, error }) => {
+            assert_eq!(location.name, "first");
+            match *error {
+                ReadError::Eof(_) => {},
+                err => panic!("eof error expected, found: {:?}", err),
+            }
+        },
+        Err(err) => panic!("field error expected, found: {:?}", err),
         Ok(_) => panic!("error expected, found: Ok(_)")
     }
 
@@ -34,9 +40,28 @@ fn eof_second() {
     let scope = ReadScope::new(writer.buffer());
     let pair = scope.read::<fixture::Pair>();
 
+    if let Err(err) = &pair {
+        assert_eq!(
+            err.to_string(),
+            "field `second` defined at tests/input/struct/pass_pair.ddl:8:5: \
+             attempted to read beyond the end of the buffer",
+        );
+    }
+
     match pair {
-        Err(ReadError::Eof(_)) => {},
-        Err(err) => panic!("eof error expected, found: {:?}", err),
+        Err(ReadError::Field { location, error }) => {
+            assert_eq!(location, FieldLocation {
+                name: "second",
+                file: "tests/input/struct/pass_pair.ddl",
+                line: 8,
+                column: 5,
+            });
+            match *error {
+                ReadError::Eof(_) => {},
+                err => panic!("eof error expected, found: {:?}", err),
+            }
+        },
+        Err(err) => panic!("field error expected, found: {:?}", err),
         Ok(_) => panic!("error expected, found: Ok(_)")
     }
 
@@ -93,3 +118,118 @@ fn valid_pair_trailing() {
 
     // TODO: Check remaining
 }
+
+#[test]
+fn encode_pair() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "Pair", FormatWriter::new(vec![])).unwrap();
+    encoder.field("first", &binary::Term::Int(31.into())).unwrap();
+    encoder.field("second", &binary::Term::Int((-30).into())).unwrap();
+    let writer = encoder.finish().unwrap();
+
+    let scope = ReadScope::new(writer.buffer());
+    let pair = scope.read::<fixture::Pair>().unwrap();
+
+    assert_eq!(pair.first(), 31);
+    assert_eq!(pair.second(), -30);
+}
+
+#[test]
+fn encode_pair_out_of_order() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "Pair", FormatWriter::new(vec![])).unwrap();
+
+    assert_eq!(
+        encoder.field("second", &binary::Term::Int((-30).into())),
+        Err(binary::write::WriteError::UnexpectedField {
+            expected: Some("first".to_owned()),
+            found: "second".to_owned(),
+        }),
+    );
+}
+
+#[test]
+fn encode_pair_type_mismatch() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "Pair", FormatWriter::new(vec![])).unwrap();
+
+    assert_eq!(
+        encoder.field("first", &binary::Term::F32(1.0)),
+        Err(binary::write::WriteError::TypeMismatch),
+    );
+    assert_eq!(
+        encoder.field("first", &binary::Term::Int(256.into())),
+        Err(binary::write::WriteError::IntOutOfRange(256.into())),
+    );
+}
+
+#[test]
+fn encode_pair_missing_field() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "Pair", FormatWriter::new(vec![])).unwrap();
+    encoder.field("first", &binary::Term::Int(31.into())).unwrap();
+
+    match encoder.finish() {
+        Err(binary::write::WriteError::MissingFields(names)) => assert_eq!(names, vec!["second".to_owned()]),
+        Err(err) => panic!("missing fields error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+}
+
+#[test]
+fn diff_pairs() {
+    use ddl_test_util::ddl::binary::diff::{diff_terms, TermDiff};
+
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(31); // Pair::first
+    writer.write::<I8>(-30); // Pair::second
+    writer.write::<U8>(31); // Pair::first
+    writer.write::<I8>(42); // Pair::second
+
+    let scope = ReadScope::new(writer.buffer());
+    let mut reader = scope.reader();
+    let old = binary::read::read_module_item(&FIXTURE, &"Pair", &mut reader).unwrap();
+    let new = binary::read::read_module_item(&FIXTURE, &"Pair", &mut reader).unwrap();
+
+    assert_eq!(diff_terms(&old, &old), vec![]);
+    assert_eq!(diff_terms(&old, &new), vec![
+        TermDiff::Changed {
+            path: vec!["second".to_owned()],
+            old: binary::Term::Int((-30).into()),
+            new: binary::Term::Int(42.into()),
+        },
+    ]);
+}
+
+#[test]
+fn display_tree_nested() {
+    let pair = binary::Term::Struct(BTreeMap::from_iter(vec![
+        ("first".to_owned(), binary::Term::Int(31.into())),
+        ("second".to_owned(), binary::Term::Int((-30).into())),
+    ]));
+    let term = binary::Term::Struct(BTreeMap::from_iter(vec![
+        ("empty".to_owned(), binary::Term::Struct(BTreeMap::new())),
+        ("pair".to_owned(), pair),
+        ("scale".to_owned(), binary::Term::F64(1.0)),
+    ]));
+
+    assert_eq!(
+        term.display_tree().to_string(),
+        "empty: {}\npair:\n    first: 31\n    second: -30\nscale: 1.0",
+    );
+}
+
+#[test]
+fn from_bytes_with_remainder() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(31); // Pair::first
+    writer.write::<I8>(-30); // Pair::second
+    writer.write::<U8>(255); // Pair::first
+    writer.write::<I8>(42); // Pair::second
+
+    let (pair, remainder) = fixture::Pair::from_bytes_with_remainder(writer.buffer()).unwrap();
+    assert_eq!(pair.first(), 31);
+    assert_eq!(pair.second(), -30);
+    assert_eq!(remainder.len(), 2);
+
+    let (pair, remainder) = fixture::Pair::from_bytes_with_remainder(remainder).unwrap();
+    assert_eq!(pair.first(), 255);
+    assert_eq!(pair.second(), 42);
+    assert!(remainder.is_empty());
+}
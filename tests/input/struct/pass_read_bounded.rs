@@ -0,0 +1,42 @@
+#![cfg(test)]
+
+use ddl_rt::{FormatWriter, ReadError, ReadScope, U16Be, U32Be};
+
+#[path = "../../snapshots/struct/pass_read_bounded.rs"]
+mod fixture;
+
+#[test]
+fn within_limit() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U32Be>(6); // Header::length
+    writer.write::<U16Be>(1); // Header::version
+
+    let scope = ReadScope::new(writer.buffer());
+    let mut reader = scope.reader();
+    let header = fixture::Header::read_bounded(&mut reader, 6).unwrap();
+
+    assert_eq!(header.length(), 6);
+    assert_eq!(header.version(), 1);
+    assert_eq!(reader.offset(), 6);
+}
+
+#[test]
+fn exceeds_limit_mid_struct() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U32Be>(6); // Header::length
+    writer.write::<U16Be>(1); // Header::version
+
+    let scope = ReadScope::new(writer.buffer());
+
+    match fixture::Header::read_bounded(&mut scope.reader(), 5) {
+        Err(ReadError::Field { location, error }) => {
+            assert_eq!(location.name, "version");
+            match *error {
+                ReadError::SizeLimitExceeded { limit } => assert_eq!(limit, 5),
+                err => panic!("size limit error expected, found: {:?}", err),
+            }
+        },
+        Err(err) => panic!("field error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)")
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadScope, U32Be};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_rename.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_rename.core.ddl");
+
+#[test]
+fn valid_chunk_header() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U32Be>(640); // IHDR::w
+    writer.write::<U32Be>(480); // IHDR::h
+
+    let scope = ReadScope::new(writer.buffer());
+    let header = scope.read::<fixture::ChunkHeader>().unwrap();
+
+    match binary::read::read_module_item(&FIXTURE, &"IHDR", &mut scope.reader()).unwrap() {
+        binary::Term::Struct(fields) => {
+            assert_eq!(header.width(), 640);
+            assert_eq!(header.height(), 480);
+
+            assert_eq!(fields, BTreeMap::from_iter(vec![
+                ("w".to_owned(), binary::Term::Int(header.width().into())),
+                ("h".to_owned(), binary::Term::Int(header.height().into())),
+            ]));
+        },
+        _ => panic!("struct expected"),
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadError, ReadScope, U16Be, U32Be, U8};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_reserved.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_reserved.core.ddl");
+
+#[test]
+fn valid_header() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U8>(1); // Header::tag
+    writer.write::<U8>(0xFF); // Header::padding
+    writer.write::<U16Be>(512); // Header::length
+    writer.write::<U32Be>(0xDEAD_BEEF); // Header::unused
+
+    let scope = ReadScope::new(writer.buffer());
+    let header = scope.read::<fixture::Header>().unwrap();
+
+    assert_eq!(header.tag(), 1);
+    assert_eq!(header.length(), 512);
+
+    let mut reader = scope.reader();
+    match binary::read::read_module_item(&FIXTURE, &"Header", &mut reader).unwrap() {
+        binary::Term::Struct(fields) => assert_eq!(fields, BTreeMap::from_iter(vec![
+            ("tag".to_owned(), binary::Term::Int(1.into())),
+            ("length".to_owned(), binary::Term::Int(512.into())),
+        ])),
+        _ => panic!("struct expected"),
+    }
+    assert!(reader.read::<U8>().is_err());
+
+    match binary::read::read_module_item_field(&FIXTURE, &"Header", &"padding", &mut scope.reader()) {
+        Err(ReadError::InvalidDataDescription) => {}
+        Err(err) => panic!("invalid data description error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+}
+
+#[test]
+fn encode_header() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "Header", FormatWriter::new(vec![])).unwrap();
+    encoder.field("tag", &binary::Term::Int(1.into())).unwrap();
+    encoder.field("length", &binary::Term::Int(512.into())).unwrap();
+    let writer = encoder.finish().unwrap();
+
+    assert_eq!(writer.buffer(), &[1, 0, 2, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn write_header() {
+    let term = binary::Term::Struct(BTreeMap::from_iter(vec![
+        ("tag".to_owned(), binary::Term::Int(1.into())),
+        ("length".to_owned(), binary::Term::Int(512.into())),
+    ]));
+    let mut writer = FormatWriter::new(vec![]);
+    binary::write::write_module_item(&FIXTURE, "Header", &term, &mut writer).unwrap();
+
+    assert_eq!(writer.buffer(), &[1, 0, 2, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn encode_header_missing_field() {
+    let mut encoder = binary::write::Encoder::new(&FIXTURE, "Header", FormatWriter::new(vec![])).unwrap();
+    encoder.field("tag", &binary::Term::Int(1.into())).unwrap();
+
+    match encoder.finish() {
+        Err(binary::write::WriteError::MissingFields(names)) => assert_eq!(names, vec!["length".to_owned()]),
+        Err(err) => panic!("missing fields error expected, found: {:?}", err),
+        Ok(_) => panic!("error expected, found: Ok(_)"),
+    }
+}
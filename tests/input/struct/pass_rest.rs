@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadScope, U16Be, U8};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_rest.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_rest.core.ddl");
+
+#[test]
+fn valid_chunk() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(7); // Chunk::tag
+    for byte in &[1, 2, 3, 4, 5] {
+        writer.write::<U8>(*byte); // Chunk::payload
+    }
+
+    let scope = ReadScope::new(writer.buffer());
+    let chunk = scope.read::<fixture::Chunk>().unwrap();
+
+    match binary::read::read_module_item(&FIXTURE, &"Chunk", &mut scope.reader()).unwrap() {
+        binary::Term::Struct(fields) => {
+            assert_eq!(chunk.tag(), 7);
+            assert_eq!(chunk.payload(), &[1, 2, 3, 4, 5]);
+
+            assert_eq!(fields, BTreeMap::from_iter(vec![
+                ("tag".to_owned(), binary::Term::Int(chunk.tag().into())),
+                ("payload".to_owned(), binary::Term::Bytes(chunk.payload().to_vec())),
+            ]));
+        },
+        _ => panic!("struct expected"),
+    }
+}
+
+#[test]
+fn valid_chunk_empty_payload() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(7); // Chunk::tag
+
+    let scope = ReadScope::new(writer.buffer());
+    let chunk = scope.read::<fixture::Chunk>().unwrap();
+
+    assert_eq!(chunk.tag(), 7);
+    assert!(chunk.payload().is_empty());
+}
+
+#[test]
+fn valid_opaque() {
+    let scope = ReadScope::new(&[0xDE, 0xAD]);
+    let opaque = scope.read::<fixture::Opaque>().unwrap();
+
+    assert_eq!(opaque.bytes(), &[0xDE, 0xAD]);
+}
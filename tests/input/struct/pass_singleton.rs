@@ -18,8 +18,14 @@ fn eof_inner() {
     let singleton = scope.read::<fixture::Byte>();
 
     match singleton {
-        Err(ReadError::Eof(_)) => {},
-        Err(err) => panic!("eof error expected, found: {:?}", err),
+        Err(ReadError::Field { location, error }) => {
+            assert_eq!(location.name, "inner");
+            match *error {
+                ReadError::Eof(_) => {},
+                err => panic!("eof error expected, found: {:?}", err),
+            }
+        },
+        Err(err) => panic!("field error expected, found: {:?}", err),
         Ok(_) => panic!("error expected, found: Ok(_)")
     }
 
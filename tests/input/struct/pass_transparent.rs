@@ -0,0 +1,38 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, ReadScope, U32Be};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_transparent.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_transparent.core.ddl");
+
+#[test]
+fn valid_header() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U32Be>(7); // Header::id
+    writer.write::<U32Be>(3); // Header::parent
+
+    let scope = ReadScope::new(writer.buffer());
+    let header = scope.read::<fixture::Header>().unwrap();
+
+    assert_eq!(header.id().0, 7);
+    assert_eq!(*header.parent(), 3);
+
+    match binary::read::read_module_item(&FIXTURE, &"Header", &mut scope.reader()).unwrap() {
+        binary::Term::Struct(fields) => {
+            let object_id = |value: u32| binary::Term::Struct(BTreeMap::from_iter(vec![
+                ("value".to_owned(), binary::Term::Int(value.into())),
+            ]));
+
+            assert_eq!(fields, BTreeMap::from_iter(vec![
+                ("id".to_owned(), object_id(7)),
+                ("parent".to_owned(), object_id(3)),
+            ]));
+        },
+        _ => panic!("struct expected"),
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use ddl_rt::{FormatWriter, ReadScope, F32Be, U16Be};
+
+#[path = "../../snapshots/struct/pass_visitor.rs"]
+mod fixture;
+
+#[derive(Default)]
+struct PointCollector {
+    lines: usize,
+    points: Vec<(f32, f32)>,
+}
+
+impl fixture::Visit for PointCollector {
+    fn visit_line(&mut self, value: &fixture::Line) {
+        self.lines += 1;
+        value.walk(self);
+    }
+
+    fn visit_point(&mut self, value: &fixture::Point) {
+        self.points.push((value.x(), value.y()));
+    }
+}
+
+#[test]
+fn visit_nested() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(7); // Shape::id
+    writer.write::<F32Be>(1.0); // Line::start.x
+    writer.write::<F32Be>(2.0); // Line::start.y
+    writer.write::<F32Be>(3.0); // Line::end.x
+    writer.write::<F32Be>(4.0); // Line::end.y
+
+    let shape = ReadScope::new(writer.buffer()).read::<fixture::Shape>().unwrap();
+    let mut collector = PointCollector::default();
+    shape.accept(&mut collector);
+
+    assert_eq!(collector.lines, 1);
+    assert_eq!(collector.points, vec![(1.0, 2.0), (3.0, 4.0)]);
+}
+
+#[test]
+fn accessors() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U16Be>(7); // Shape::id
+    writer.write::<F32Be>(1.0); // Line::start.x
+    writer.write::<F32Be>(2.0); // Line::start.y
+    writer.write::<F32Be>(3.0); // Line::end.x
+    writer.write::<F32Be>(4.0); // Line::end.y
+
+    let shape = ReadScope::new(writer.buffer()).read::<fixture::Shape>().unwrap();
+
+    assert_eq!(shape.id(), 7);
+    assert_eq!(shape.outline().start().x(), 1.0);
+    assert_eq!(shape.outline().end().y(), 4.0);
+}
@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use ddl_test_util::ddl::binary;
+use ddl_rt::{FormatWriter, I128Be, I128Le, ReadScope, U128Be, U128Le};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+#[path = "../../snapshots/struct/pass_wide_ints.rs"]
+mod fixture;
+
+ddl_test_util::core_module!(FIXTURE, "../../snapshots/struct/pass_wide_ints.core.ddl");
+
+#[test]
+fn eof_first() {
+    let writer = FormatWriter::new(vec![]);
+
+    let read_scope = ReadScope::new(writer.buffer());
+    let wide_ints = read_scope.read::<fixture::WideInts>();
+    let wide_ints_binary =
+        binary::read::read_module_item(&FIXTURE, &"WideInts", &mut read_scope.reader());
+
+    assert!(wide_ints.is_err());
+    assert!(wide_ints_binary.is_err());
+}
+
+#[test]
+fn valid_wide_ints() {
+    let mut writer = FormatWriter::new(vec![]);
+    writer.write::<U128Be>(0x0011_2233_4455_6677_8899_aabb_ccdd_eeff); // WideInts::uuid
+    writer.write::<U128Le>(u128::max_value()); // WideInts::checksum
+    writer.write::<I128Be>(i128::min_value()); // WideInts::offset
+    writer.write::<I128Le>(-1); // WideInts::delta
+
+    let read_scope = ReadScope::new(writer.buffer());
+    let wide_ints = read_scope.read::<fixture::WideInts>().unwrap();
+    let wide_ints_binary =
+        binary::read::read_module_item(&FIXTURE, &"WideInts", &mut read_scope.reader()).unwrap();
+
+    assert_eq!(wide_ints.uuid(), 0x0011_2233_4455_6677_8899_aabb_ccdd_eeff);
+    assert_eq!(wide_ints.checksum(), u128::max_value());
+    assert_eq!(wide_ints.offset(), i128::min_value());
+    assert_eq!(wide_ints.delta(), -1);
+
+    assert_eq!(
+        wide_ints_binary,
+        binary::Term::Struct(BTreeMap::from_iter(vec![
+            ("uuid".to_owned(), binary::Term::Int(wide_ints.uuid().into())),
+            ("checksum".to_owned(), binary::Term::Int(wide_ints.checksum().into())),
+            ("offset".to_owned(), binary::Term::Int(wide_ints.offset().into())),
+            ("delta".to_owned(), binary::Term::Int(wide_ints.delta().into())),
+        ])),
+    );
+}
@@ -1,4 +1,6 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
 
+#![allow(clippy::all)]
+
 pub type TestInt = ddl_rt::InvalidDataDescription;
@@ -1,6 +1,8 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
 
+#![allow(clippy::all)]
+
 /// Test that one can refer to local type aliases in aliases.
 pub type Foo = ddl_rt::U32Be;
 
@@ -3,4 +3,6 @@
 
 //! Test annotated annotations.
 
+#![allow(clippy::all)]
+
 pub type Test = ddl_rt::U8;
@@ -3,6 +3,8 @@
 
 //! Test sugared annotation style.
 
+#![allow(clippy::all)]
+
 pub type TestU8 = ddl_rt::U8;
 
 pub type TestU16Le = ddl_rt::U16Le;
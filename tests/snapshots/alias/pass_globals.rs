@@ -3,6 +3,8 @@
 
 //! Test globals.
 
+#![allow(clippy::all)]
+
 pub type TestU8 = ddl_rt::U8;
 
 pub type TestU16Le = ddl_rt::U16Le;
@@ -17,6 +19,10 @@ pub type TestU64Le = ddl_rt::U64Le;
 
 pub type TestU64Be = ddl_rt::U64Be;
 
+pub type TestU128Le = ddl_rt::U128Le;
+
+pub type TestU128Be = ddl_rt::U128Be;
+
 pub type TestS8 = ddl_rt::I8;
 
 pub type TestS16Le = ddl_rt::I16Le;
@@ -31,6 +37,14 @@ pub type TestS64Le = ddl_rt::I64Le;
 
 pub type TestS64Be = ddl_rt::I64Be;
 
+pub type TestS128Le = ddl_rt::I128Le;
+
+pub type TestS128Be = ddl_rt::I128Be;
+
+pub type TestF16Le = ddl_rt::F16Le;
+
+pub type TestF16Be = ddl_rt::F16Be;
+
 pub type TestF32Le = ddl_rt::F32Le;
 
 pub type TestF32Be = ddl_rt::F32Be;
@@ -1,6 +1,8 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
 
+#![allow(clippy::all)]
+
 #[derive(Copy, Clone)]
 pub struct Test {
     inner: ddl_rt::Either<f64, f32>,
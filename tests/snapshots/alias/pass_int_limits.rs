@@ -0,0 +1,12 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test integer literals at the limits of the 64-bit host types.
+
+#![allow(clippy::all)]
+
+pub const TEST_U_64_MAX: u64 = 18446744073709551615u64;
+
+pub const TEST_I_64_MAX: i64 = 9223372036854775807i64;
+
+pub const TEST_I_64_MIN: i64 = -9223372036854775808i64;
@@ -0,0 +1,16 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test integer literals that only fit in the 128-bit host types.
+
+#![allow(clippy::all)]
+
+pub const TEST_U_64_MAX_PLUS_ONE: i128 = 18446744073709551616i128;
+
+pub const TEST_I_64_MIN_MINUS_ONE: i128 = -9223372036854775809i128;
+
+pub const TEST_U_128_MAX: u128 = 340282366920938463463374607431768211455u128;
+
+pub const TEST_I_128_MAX: i128 = 170141183460469231731687303715884105727i128;
+
+pub const TEST_I_128_MIN: i128 = -170141183460469231731687303715884105728i128;
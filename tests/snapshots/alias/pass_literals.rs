@@ -3,6 +3,8 @@
 
 //! Test literals.
 
+#![allow(clippy::all)]
+
 pub const TEST_INT_0: i64 = 0i64;
 
 pub const TEST_INT_1: i64 = 1i64;
@@ -65,7 +67,7 @@ pub const TEST_F_32_0_9U: f32 = 9f32;
 
 pub const TEST_F_32_POS_0: f32 = 0f32;
 
-pub const TEST_F_32_NEG_0: f32 = 0f32;
+pub const TEST_F_32_NEG_0: f32 = -0f32;
 
 pub const TEST_F_32_POS_1: f32 = 1f32;
 
@@ -79,7 +81,7 @@ pub const TEST_F_32_0_P_0: f32 = 0f32;
 
 pub const TEST_F_32_POS_0_P_0: f32 = 0f32;
 
-pub const TEST_F_32_NEG_0_P_0: f32 = 0f32;
+pub const TEST_F_32_NEG_0_P_0: f32 = -0f32;
 
 pub const TEST_F_32_1_P_1: f32 = 1.1f32;
 
@@ -113,7 +115,7 @@ pub const TEST_F_64_0_9U: f64 = 9f64;
 
 pub const TEST_F_64_POS_0: f64 = 0f64;
 
-pub const TEST_F_64_NEG_0: f64 = 0f64;
+pub const TEST_F_64_NEG_0: f64 = -0f64;
 
 pub const TEST_F_64_POS_1: f64 = 1f64;
 
@@ -127,7 +129,7 @@ pub const TEST_F_64_0_P_0: f64 = 0f64;
 
 pub const TEST_F_64_POS_0_P_0: f64 = 0f64;
 
-pub const TEST_F_64_NEG_0_P_0: f64 = 0f64;
+pub const TEST_F_64_NEG_0_P_0: f64 = -0f64;
 
 pub const TEST_F_64_1_P_1: f64 = 1.1f64;
 
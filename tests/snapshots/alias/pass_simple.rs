@@ -3,4 +3,6 @@
 
 //! Test simple aliases.
 
+#![allow(clippy::all)]
+
 pub type Byte = ddl_rt::U8;
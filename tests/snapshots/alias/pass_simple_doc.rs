@@ -3,5 +3,7 @@
 
 //! Test aliases with doc comments.
 
+#![allow(clippy::all)]
+
 /// A 8-bit long unit of information.
 pub type Byte = ddl_rt::U8;
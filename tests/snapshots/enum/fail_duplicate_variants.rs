@@ -0,0 +1,28 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Compression {
+    None = 0u8,
+    Rle = 1u8,
+}
+
+impl ddl_rt::Format for Compression {
+    type Host = Compression;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Compression {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Compression, ddl_rt::ReadError> {
+        match reader.read::<ddl_rt::U8>()? {
+            0u8 => Ok(Compression::None),
+            1u8 => Ok(Compression::Rle),
+            value => Err(ddl_rt::ReadError::UnknownDiscriminant {
+                ty: "Compression".to_owned(),
+                value: value.into(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Small {}
+
+impl ddl_rt::Format for Small {
+    type Host = Small;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Small {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Small, ddl_rt::ReadError> {
+        match reader.read::<ddl_rt::U8>()? {
+            value => Err(ddl_rt::ReadError::UnknownDiscriminant {
+                ty: "Small".to_owned(),
+                value: value.into(),
+            }),
+        }
+    }
+}
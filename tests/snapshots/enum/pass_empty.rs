@@ -0,0 +1,22 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Empty {}
+
+impl ddl_rt::Format for Empty {
+    type Host = Empty;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Empty {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Empty, ddl_rt::ReadError> {
+        match reader.read::<ddl_rt::U32Le>()? {
+            value => Err(ddl_rt::ReadError::UnknownDiscriminant {
+                ty: "Empty".to_owned(),
+                value: value.into(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test enums that map named variants to discriminants.
+
+#![allow(clippy::all)]
+
+/// The compression method used by an image.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Compression {
+    /// No compression.
+    None = 0u8,
+    /// Run length encoding.
+    Rle = 1u8,
+    Lzw = 5u8,
+}
+
+impl ddl_rt::Format for Compression {
+    type Host = Compression;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Compression {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Compression, ddl_rt::ReadError> {
+        match reader.read::<ddl_rt::U8>()? {
+            0u8 => Ok(Compression::None),
+            1u8 => Ok(Compression::Rle),
+            5u8 => Ok(Compression::Lzw),
+            value => Err(ddl_rt::ReadError::UnknownDiscriminant {
+                ty: "Compression".to_owned(),
+                value: value.into(),
+            }),
+        }
+    }
+}
+
+/// The byte order of a file, read from its first two bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum ByteOrder {
+    Little = 18761u16,
+    Big = 19789u16,
+}
+
+impl ddl_rt::Format for ByteOrder {
+    type Host = ByteOrder;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for ByteOrder {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<ByteOrder, ddl_rt::ReadError> {
+        match reader.read::<ddl_rt::U16Be>()? {
+            18761u16 => Ok(ByteOrder::Little),
+            19789u16 => Ok(ByteOrder::Big),
+            value => Err(ddl_rt::ReadError::UnknownDiscriminant {
+                ty: "ByteOrder".to_owned(),
+                value: value.into(),
+            }),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(i16)]
+pub enum Delta {
+    Backward = -1i16,
+    Forward = 1i16,
+}
+
+impl ddl_rt::Format for Delta {
+    type Host = Delta;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Delta {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Delta, ddl_rt::ReadError> {
+        match reader.read::<ddl_rt::I16Le>()? {
+            -1i16 => Ok(Delta::Backward),
+            1i16 => Ok(Delta::Forward),
+            value => Err(ddl_rt::ReadError::UnknownDiscriminant {
+                ty: "Delta".to_owned(),
+                value: value.into(),
+            }),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Header {
+    byte_order: ByteOrder,
+    compression: Compression,
+    delta: Delta,
+}
+
+impl Header {
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    pub fn delta(&self) -> Delta {
+        self.delta
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "byte_order",
+            file: "tests/input/enum/pass_simple.ddl",
+            line: 24,
+            column: 5,
+        };
+        let byte_order = reader.read_field::<ByteOrder>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "compression",
+            file: "tests/input/enum/pass_simple.ddl",
+            line: 25,
+            column: 5,
+        };
+        let compression = reader.read_field::<Compression>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "delta",
+            file: "tests/input/enum/pass_simple.ddl",
+            line: 26,
+            column: 5,
+        };
+        let delta = reader.read_field::<Delta>(&FIELD_2_LOCATION)?;
+
+        Ok(Header {
+            byte_order,
+            compression,
+            delta,
+        })
+    }
+}
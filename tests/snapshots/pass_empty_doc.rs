@@ -4,3 +4,5 @@
 //! This is an empty module.
 //!
 //! It is quite empty, yes.
+
+#![allow(clippy::all)]
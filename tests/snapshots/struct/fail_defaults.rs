@@ -0,0 +1,105 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Inner {
+    value: u8,
+}
+
+impl Inner {
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl ddl_rt::Format for Inner {
+    type Host = Inner;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Inner {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Inner, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/fail_defaults.ddl",
+            line: 2,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+
+        Ok(Inner {
+            value,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Outer {
+    small: u8,
+    whole: u16,
+    inner: Inner,
+    empty: u8,
+}
+
+impl Outer {
+    pub fn small(&self) -> u8 {
+        self.small
+    }
+
+    pub fn whole(&self) -> u16 {
+        self.whole
+    }
+
+    pub fn inner(&self) -> Inner {
+        self.inner
+    }
+
+    pub fn empty(&self) -> u8 {
+        self.empty
+    }
+}
+
+impl ddl_rt::Format for Outer {
+    type Host = Outer;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Outer {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Outer, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "small",
+            file: "tests/input/struct/fail_defaults.ddl",
+            line: 6,
+            column: 19,
+        };
+        let small = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "whole",
+            file: "tests/input/struct/fail_defaults.ddl",
+            line: 7,
+            column: 19,
+        };
+        let whole = reader.read_field::<ddl_rt::U16Be>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "inner",
+            file: "tests/input/struct/fail_defaults.ddl",
+            line: 8,
+            column: 17,
+        };
+        let inner = reader.read_field::<Inner>(&FIELD_2_LOCATION)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "empty",
+            file: "tests/input/struct/fail_defaults.ddl",
+            line: 9,
+            column: 16,
+        };
+        let empty = reader.read_field::<ddl_rt::U8>(&FIELD_3_LOCATION)?;
+
+        Ok(Outer {
+            small,
+            whole,
+            inner,
+            empty,
+        })
+    }
+}
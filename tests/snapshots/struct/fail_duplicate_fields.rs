@@ -1,6 +1,8 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
 
+#![allow(clippy::all)]
+
 #[derive(Copy, Clone)]
 pub struct Pair {
     first: u8,
@@ -23,8 +25,20 @@ impl ddl_rt::Format for Pair {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Pair {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Pair, ddl_rt::ReadError> {
-        let first = reader.read::<ddl_rt::U8>()?;
-        let second = reader.read::<ddl_rt::U8>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "first",
+            file: "tests/input/struct/fail_duplicate_fields.ddl",
+            line: 2,
+            column: 5,
+        };
+        let first = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "second",
+            file: "tests/input/struct/fail_duplicate_fields.ddl",
+            line: 3,
+            column: 5,
+        };
+        let second = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
 
         Ok(Pair {
             first,
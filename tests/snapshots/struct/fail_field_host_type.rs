@@ -0,0 +1,91 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+pub type Byte = ddl_rt::U8;
+
+pub type Flag = bool;
+
+#[derive(Copy, Clone)]
+pub struct Foo {
+    byte: u8,
+    count: ddl_rt::InvalidDataDescription,
+    ratio: ddl_rt::InvalidDataDescription,
+    enabled: ddl_rt::InvalidDataDescription,
+    flag: ddl_rt::InvalidDataDescription,
+}
+
+impl Foo {
+    pub fn byte(&self) -> u8 {
+        self.byte
+    }
+
+    pub fn count(&self) -> ddl_rt::InvalidDataDescription {
+        self.count
+    }
+
+    pub fn ratio(&self) -> ddl_rt::InvalidDataDescription {
+        self.ratio
+    }
+
+    pub fn enabled(&self) -> ddl_rt::InvalidDataDescription {
+        self.enabled
+    }
+
+    pub fn flag(&self) -> ddl_rt::InvalidDataDescription {
+        self.flag
+    }
+}
+
+impl ddl_rt::Format for Foo {
+    type Host = Foo;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Foo {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Foo, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "byte",
+            file: "tests/input/struct/fail_field_host_type.ddl",
+            line: 5,
+            column: 5,
+        };
+        let byte = reader.read_field::<Byte>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "count",
+            file: "tests/input/struct/fail_field_host_type.ddl",
+            line: 6,
+            column: 5,
+        };
+        let count = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "ratio",
+            file: "tests/input/struct/fail_field_host_type.ddl",
+            line: 7,
+            column: 5,
+        };
+        let ratio = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_2_LOCATION)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "enabled",
+            file: "tests/input/struct/fail_field_host_type.ddl",
+            line: 8,
+            column: 5,
+        };
+        let enabled = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_3_LOCATION)?;
+        const FIELD_4_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "flag",
+            file: "tests/input/struct/fail_field_host_type.ddl",
+            line: 9,
+            column: 5,
+        };
+        let flag = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_4_LOCATION)?;
+
+        Ok(Foo {
+            byte,
+            count,
+            ratio,
+            enabled,
+            flag,
+        })
+    }
+}
@@ -1,6 +1,8 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
 
+#![allow(clippy::all)]
+
 #[derive(Copy, Clone)]
 pub struct Foo {
     field_type: ddl_rt::InvalidDataDescription,
@@ -28,9 +30,27 @@ impl ddl_rt::Format for Foo {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Foo {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Foo, ddl_rt::ReadError> {
-        let field_type = reader.read::<ddl_rt::InvalidDataDescription>()?;
-        let field_true = reader.read::<ddl_rt::InvalidDataDescription>()?;
-        let field_false = reader.read::<ddl_rt::InvalidDataDescription>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "field_type",
+            file: "tests/input/struct/fail_field_type_mismatch.ddl",
+            line: 2,
+            column: 5,
+        };
+        let field_type = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "field_true",
+            file: "tests/input/struct/fail_field_type_mismatch.ddl",
+            line: 3,
+            column: 5,
+        };
+        let field_true = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "field_false",
+            file: "tests/input/struct/fail_field_type_mismatch.ddl",
+            line: 4,
+            column: 5,
+        };
+        let field_false = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_2_LOCATION)?;
 
         Ok(Foo {
             field_type,
@@ -0,0 +1,68 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Header {
+    tag: u16,
+}
+
+impl Header {
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/fail_include.ddl",
+            line: 2,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+
+        Ok(Header {
+            tag,
+        })
+    }
+}
+
+pub type Byte = ddl_rt::U8;
+
+#[derive(Copy, Clone)]
+pub struct Chunk {
+    tag: u16,
+}
+
+impl Chunk {
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+}
+
+impl ddl_rt::Format for Chunk {
+    type Host = Chunk;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Chunk {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Chunk, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/fail_include.ddl",
+            line: 2,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+
+        Ok(Chunk {
+            tag,
+        })
+    }
+}
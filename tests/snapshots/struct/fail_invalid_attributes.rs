@@ -0,0 +1,158 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Attributes {
+    first: u8,
+    second: u32,
+    third: u16,
+    fourth: u16,
+    fifth: u16,
+    sixth: u8,
+    #[cfg(feature = "foo")]
+    seventh: u8,
+    eighth: u8,
+    ninth: u8,
+    tenth: u8,
+}
+
+impl Attributes {
+    pub fn first(&self) -> u8 {
+        self.first
+    }
+
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    pub fn third(&self) -> u16 {
+        self.third
+    }
+
+    pub fn fourth(&self) -> u16 {
+        self.fourth
+    }
+
+    pub fn fifth(&self) -> u16 {
+        self.fifth
+    }
+
+    pub fn sixth(&self) -> u8 {
+        self.sixth
+    }
+
+    #[cfg(feature = "foo")]
+    pub fn seventh(&self) -> u8 {
+        self.seventh
+    }
+
+    pub fn eighth(&self) -> u8 {
+        self.eighth
+    }
+
+    pub fn ninth(&self) -> u8 {
+        self.ninth
+    }
+
+    pub fn tenth(&self) -> u8 {
+        self.tenth
+    }
+}
+
+impl ddl_rt::Format for Attributes {
+    type Host = Attributes;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Attributes {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Attributes, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "first",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 2,
+            column: 17,
+        };
+        let first = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        reader.check_aligned("second", 4)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "second",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 3,
+            column: 29,
+        };
+        let second = reader.read_field::<ddl_rt::U32Be>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "third",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 4,
+            column: 20,
+        };
+        let third = reader.read_field::<ddl_rt::U16Be>(&FIELD_2_LOCATION)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "fourth",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 5,
+            column: 17,
+        };
+        let fourth = reader.read_field::<ddl_rt::U16Be>(&FIELD_3_LOCATION)?;
+        const FIELD_4_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "fifth",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 6,
+            column: 16,
+        };
+        let fifth = reader.read_field::<ddl_rt::U16Be>(&FIELD_4_LOCATION)?;
+        const FIELD_5_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "sixth",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 7,
+            column: 13,
+        };
+        let sixth = reader.read_field::<ddl_rt::U8>(&FIELD_5_LOCATION)?;
+        #[cfg(feature = "foo")]
+        const FIELD_6_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "seventh",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 8,
+            column: 25,
+        };
+        #[cfg(feature = "foo")]
+        let seventh = reader.read_field::<ddl_rt::U8>(&FIELD_6_LOCATION)?;
+        const FIELD_7_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "eighth",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 9,
+            column: 14,
+        };
+        let eighth = reader.read_field::<ddl_rt::U8>(&FIELD_7_LOCATION)?;
+        const FIELD_8_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "ninth",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 10,
+            column: 17,
+        };
+        let ninth = reader.read_field::<ddl_rt::U8>(&FIELD_8_LOCATION)?;
+        const FIELD_9_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tenth",
+            file: "tests/input/struct/fail_invalid_attributes.ddl",
+            line: 11,
+            column: 18,
+        };
+        let tenth = reader.read_field::<ddl_rt::U8>(&FIELD_9_LOCATION)?;
+
+        Ok(Attributes {
+            first,
+            second,
+            third,
+            fourth,
+            fifth,
+            sixth,
+            #[cfg(feature = "foo")]
+            seventh,
+            eighth,
+            ninth,
+            tenth,
+        })
+    }
+}
@@ -0,0 +1,131 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Inner {
+    value: u8,
+}
+
+impl Inner {
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl ddl_rt::Format for Inner {
+    type Host = Inner;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Inner {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Inner, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/fail_magic.ddl",
+            line: 2,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+
+        Ok(Inner {
+            value,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Outer {
+    small: u8,
+    signed: i32,
+    wide: u128,
+    float: f32,
+    inner: Inner,
+    empty: u8,
+}
+
+impl Outer {
+    pub fn small(&self) -> u8 {
+        self.small
+    }
+
+    pub fn signed(&self) -> i32 {
+        self.signed
+    }
+
+    pub fn wide(&self) -> u128 {
+        self.wide
+    }
+
+    pub fn float(&self) -> f32 {
+        self.float
+    }
+
+    pub fn inner(&self) -> Inner {
+        self.inner
+    }
+
+    pub fn empty(&self) -> u8 {
+        self.empty
+    }
+}
+
+impl ddl_rt::Format for Outer {
+    type Host = Outer;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Outer {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Outer, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "small",
+            file: "tests/input/struct/fail_magic.ddl",
+            line: 6,
+            column: 17,
+        };
+        let small = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "signed",
+            file: "tests/input/struct/fail_magic.ddl",
+            line: 7,
+            column: 15,
+        };
+        let signed = reader.read_field::<ddl_rt::I32Be>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "wide",
+            file: "tests/input/struct/fail_magic.ddl",
+            line: 8,
+            column: 15,
+        };
+        let wide = reader.read_field::<ddl_rt::U128Be>(&FIELD_2_LOCATION)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "float",
+            file: "tests/input/struct/fail_magic.ddl",
+            line: 9,
+            column: 15,
+        };
+        let float = reader.read_field::<ddl_rt::F32Be>(&FIELD_3_LOCATION)?;
+        const FIELD_4_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "inner",
+            file: "tests/input/struct/fail_magic.ddl",
+            line: 10,
+            column: 15,
+        };
+        let inner = reader.read_field::<Inner>(&FIELD_4_LOCATION)?;
+        const FIELD_5_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "empty",
+            file: "tests/input/struct/fail_magic.ddl",
+            line: 11,
+            column: 14,
+        };
+        let empty = reader.read_field::<ddl_rt::U8>(&FIELD_5_LOCATION)?;
+
+        Ok(Outer {
+            small,
+            signed,
+            wide,
+            float,
+            inner,
+            empty,
+        })
+    }
+}
@@ -1,2 +1,4 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
+
+#![allow(clippy::all)]
@@ -0,0 +1,72 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Chunk {
+    tag: u16,
+}
+
+impl Chunk {
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+}
+
+impl ddl_rt::Format for Chunk {
+    type Host = Chunk;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Chunk {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Chunk, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/fail_rest.ddl",
+            line: 2,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+
+        Ok(Chunk {
+            tag,
+        })
+    }
+}
+
+pub struct Wrapper {
+    inner: u8,
+    rest: Vec<u8>,
+}
+
+impl Wrapper {
+    pub fn inner(&self) -> u8 {
+        self.inner
+    }
+
+    pub fn rest(&self) -> &[u8] {
+        &self.rest
+    }
+}
+
+impl ddl_rt::Format for Wrapper {
+    type Host = Wrapper;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Wrapper {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Wrapper, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "inner",
+            file: "tests/input/struct/fail_rest.ddl",
+            line: 8,
+            column: 5,
+        };
+        let inner = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        let rest = reader.read_remaining().to_vec();
+
+        Ok(Wrapper {
+            inner,
+            rest,
+        })
+    }
+}
@@ -0,0 +1,212 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Empty {}
+
+impl ddl_rt::Format for Empty {
+    type Host = Empty;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Empty {
+    fn read(_: &mut ddl_rt::FormatReader<'data>) -> Result<Empty, ddl_rt::ReadError> {
+        Ok(Empty {})
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Pair {
+    first: u8,
+    second: u8,
+}
+
+impl Pair {
+    pub fn first(&self) -> u8 {
+        self.first
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl ddl_rt::Format for Pair {
+    type Host = Pair;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Pair {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Pair, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "first",
+            file: "tests/input/struct/fail_transparent.ddl",
+            line: 6,
+            column: 5,
+        };
+        let first = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "second",
+            file: "tests/input/struct/fail_transparent.ddl",
+            line: 7,
+            column: 5,
+        };
+        let second = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+
+        Ok(Pair {
+            first,
+            second,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Conditional {
+    #[cfg(feature = "foo")]
+    value: u8,
+}
+
+impl Conditional {
+    #[cfg(feature = "foo")]
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl ddl_rt::Format for Conditional {
+    type Host = Conditional;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Conditional {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Conditional, ddl_rt::ReadError> {
+        #[cfg(feature = "foo")]
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/fail_transparent.ddl",
+            line: 12,
+            column: 15,
+        };
+        #[cfg(feature = "foo")]
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        reader.check_eof()?;
+
+        Ok(Conditional {
+            #[cfg(feature = "foo")]
+            value,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Reserved {}
+
+impl ddl_rt::Format for Reserved {
+    type Host = Reserved;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Reserved {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Reserved, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/fail_transparent.ddl",
+            line: 18,
+            column: 15,
+        };
+        reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+
+        Ok(Reserved {
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct WithArgs {
+    value: u8,
+}
+
+impl WithArgs {
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl ddl_rt::Format for WithArgs {
+    type Host = WithArgs;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for WithArgs {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<WithArgs, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/fail_transparent.ddl",
+            line: 23,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+
+        Ok(WithArgs {
+            value,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Duplicate(pub u8);
+
+impl std::ops::Deref for Duplicate {
+    type Target = u8;
+
+    fn deref(&self) -> &u8 {
+        &self.0
+    }
+}
+
+impl ddl_rt::Format for Duplicate {
+    type Host = Duplicate;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Duplicate {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Duplicate, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/fail_transparent.ddl",
+            line: 28,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+
+        Ok(Duplicate(value))
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Unknown {
+    value: u8,
+}
+
+impl Unknown {
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl ddl_rt::Format for Unknown {
+    type Host = Unknown;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Unknown {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Unknown, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/fail_transparent.ddl",
+            line: 33,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+
+        Ok(Unknown {
+            value,
+        })
+    }
+}
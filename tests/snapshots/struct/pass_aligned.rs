@@ -0,0 +1,175 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields with alignment assertions.
+
+#![allow(clippy::all)]
+
+/// A header with naturally aligned fields.
+#[derive(Copy, Clone)]
+pub struct Header {
+    tag: u8,
+    reserved: u8,
+    length: u16,
+    offset: u32,
+}
+
+impl Header {
+    /// The kind of record.
+    pub fn tag(&self) -> u8 {
+        self.tag
+    }
+
+    /// Reserved for future use.
+    pub fn reserved(&self) -> u8 {
+        self.reserved
+    }
+
+    /// The length of the record.
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// The offset of the record.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 6,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "reserved",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 8,
+            column: 5,
+        };
+        let reserved = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+        reader.check_aligned("length", 2)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "length",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 10,
+            column: 17,
+        };
+        let length = reader.read_field::<ddl_rt::U16Be>(&FIELD_2_LOCATION)?;
+        reader.check_aligned("offset", 4)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "offset",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 12,
+            column: 17,
+        };
+        let offset = reader.read_field::<ddl_rt::U32Be>(&FIELD_3_LOCATION)?;
+
+        Ok(Header {
+            tag,
+            reserved,
+            length,
+            offset,
+        })
+    }
+}
+
+/// A structure whose final field does not start at an aligned offset.
+#[derive(Copy, Clone)]
+pub struct Misaligned {
+    tag: u8,
+    value: u32,
+}
+
+impl Misaligned {
+    pub fn tag(&self) -> u8 {
+        self.tag
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl ddl_rt::Format for Misaligned {
+    type Host = Misaligned;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Misaligned {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Misaligned, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 17,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        reader.check_aligned("value", 4)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 18,
+            column: 17,
+        };
+        let value = reader.read_field::<ddl_rt::U32Be>(&FIELD_1_LOCATION)?;
+
+        Ok(Misaligned {
+            tag,
+            value,
+        })
+    }
+}
+
+/// A record with an optional aligned trailer.
+#[derive(Copy, Clone)]
+pub struct Record {
+    tag: u16,
+    trailer: u32,
+}
+
+impl Record {
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+
+    pub fn trailer(&self) -> u32 {
+        self.trailer
+    }
+}
+
+impl ddl_rt::Format for Record {
+    type Host = Record;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Record {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Record, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 23,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        reader.check_aligned("trailer", 4)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "trailer",
+            file: "tests/input/struct/pass_aligned.ddl",
+            line: 24,
+            column: 29,
+        };
+        let trailer = reader.read_field_or::<ddl_rt::U32Be>(&FIELD_1_LOCATION, 0u32)?;
+
+        Ok(Record {
+            tag,
+            trailer,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields that are gated behind features.
+
+#![allow(clippy::all)]
+
+/// A record with an optional checksum.
+#[derive(Copy, Clone)]
+pub struct Record {
+    length: u32,
+    #[cfg(feature = "checksum")]
+    checksum: u32,
+    #[cfg(feature = "reserved")]
+    reserved: u32,
+}
+
+impl Record {
+    /// The length of the record.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    /// A checksum of the record, only present in some builds.
+    #[cfg(feature = "checksum")]
+    pub fn checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    /// Reserved for future use.
+    #[cfg(feature = "reserved")]
+    pub fn reserved(&self) -> u32 {
+        self.reserved
+    }
+}
+
+impl ddl_rt::Format for Record {
+    type Host = Record;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Record {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Record, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "length",
+            file: "tests/input/struct/pass_cfg.ddl",
+            line: 6,
+            column: 5,
+        };
+        let length = reader.read_field::<ddl_rt::U32Be>(&FIELD_0_LOCATION)?;
+        #[cfg(feature = "checksum")]
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "checksum",
+            file: "tests/input/struct/pass_cfg.ddl",
+            line: 8,
+            column: 20,
+        };
+        #[cfg(feature = "checksum")]
+        let checksum = reader.read_field::<ddl_rt::U32Be>(&FIELD_1_LOCATION)?;
+        #[cfg(feature = "reserved")]
+        reader.check_aligned("reserved", 4)?;
+        #[cfg(feature = "reserved")]
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "reserved",
+            file: "tests/input/struct/pass_cfg.ddl",
+            line: 10,
+            column: 32,
+        };
+        #[cfg(feature = "reserved")]
+        let reserved = reader.read_field::<ddl_rt::U32Be>(&FIELD_2_LOCATION)?;
+
+        Ok(Record {
+            length,
+            #[cfg(feature = "checksum")]
+            checksum,
+            #[cfg(feature = "reserved")]
+            reserved,
+        })
+    }
+}
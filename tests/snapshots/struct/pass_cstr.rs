@@ -0,0 +1,66 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test a struct containing null-terminated strings.
+
+#![allow(clippy::all)]
+
+/// An entry in a symbol table.
+pub struct Symbol {
+    name: std::ffi::CString,
+    address: u32,
+    section: std::ffi::CString,
+}
+
+impl Symbol {
+    /// The name of the symbol.
+    pub fn name(&self) -> &std::ffi::CString {
+        &self.name
+    }
+
+    /// The address of the symbol.
+    pub fn address(&self) -> u32 {
+        self.address
+    }
+
+    /// The section that the symbol is defined in.
+    pub fn section(&self) -> &std::ffi::CString {
+        &self.section
+    }
+}
+
+impl ddl_rt::Format for Symbol {
+    type Host = Symbol;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Symbol {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Symbol, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "name",
+            file: "tests/input/struct/pass_cstr.ddl",
+            line: 6,
+            column: 5,
+        };
+        let name = reader.read_field::<ddl_rt::CStr>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "address",
+            file: "tests/input/struct/pass_cstr.ddl",
+            line: 8,
+            column: 5,
+        };
+        let address = reader.read_field::<ddl_rt::U32Le>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "section",
+            file: "tests/input/struct/pass_cstr.ddl",
+            line: 10,
+            column: 5,
+        };
+        let section = reader.read_field::<ddl_rt::CStr>(&FIELD_2_LOCATION)?;
+
+        Ok(Symbol {
+            name,
+            address,
+            section,
+        })
+    }
+}
@@ -0,0 +1,93 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields with default values, for data that may be truncated.
+
+#![allow(clippy::all)]
+
+/// A record that gained fields in later versions of the format.
+#[derive(Copy, Clone)]
+pub struct Record {
+    id: u16,
+    flags: u8,
+    priority: i32,
+    scale: f32,
+    checksum: u128,
+}
+
+impl Record {
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Added in version 2.
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Added in version 3.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Added in version 4.
+    pub fn checksum(&self) -> u128 {
+        self.checksum
+    }
+}
+
+impl ddl_rt::Format for Record {
+    type Host = Record;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Record {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Record, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "id",
+            file: "tests/input/struct/pass_defaults.ddl",
+            line: 5,
+            column: 5,
+        };
+        let id = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "flags",
+            file: "tests/input/struct/pass_defaults.ddl",
+            line: 7,
+            column: 17,
+        };
+        let flags = reader.read_field_or::<ddl_rt::U8>(&FIELD_1_LOCATION, 1u8)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "priority",
+            file: "tests/input/struct/pass_defaults.ddl",
+            line: 9,
+            column: 18,
+        };
+        let priority = reader.read_field_or::<ddl_rt::I32Le>(&FIELD_2_LOCATION, -1i32)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "scale",
+            file: "tests/input/struct/pass_defaults.ddl",
+            line: 10,
+            column: 19,
+        };
+        let scale = reader.read_field_or::<ddl_rt::F32Be>(&FIELD_3_LOCATION, 0.5f32)?;
+        const FIELD_4_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "checksum",
+            file: "tests/input/struct/pass_defaults.ddl",
+            line: 12,
+            column: 36,
+        };
+        let checksum = reader.read_field_or::<ddl_rt::U128Le>(&FIELD_4_LOCATION, 18446744073709551616u128)?;
+
+        Ok(Record {
+            id,
+            flags,
+            priority,
+            scale,
+            checksum,
+        })
+    }
+}
@@ -0,0 +1,96 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test hiding structures from the generated documentation.
+
+#![allow(clippy::all)]
+
+/// A helper that is only used by `Header`.
+#[derive(Copy, Clone)]
+pub struct Version {
+    major: u8,
+    minor: u8,
+}
+
+impl Version {
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+}
+
+impl ddl_rt::Format for Version {
+    type Host = Version;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Version {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Version, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "major",
+            file: "tests/input/struct/pass_doc_hide.ddl",
+            line: 6,
+            column: 5,
+        };
+        let major = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "minor",
+            file: "tests/input/struct/pass_doc_hide.ddl",
+            line: 7,
+            column: 5,
+        };
+        let minor = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+
+        Ok(Version {
+            major,
+            minor,
+        })
+    }
+}
+
+/// The header of a file.
+#[derive(Copy, Clone)]
+pub struct Header {
+    magic: u32,
+    version: Version,
+}
+
+impl Header {
+    pub fn magic(&self) -> u32 {
+        self.magic
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "magic",
+            file: "tests/input/struct/pass_doc_hide.ddl",
+            line: 12,
+            column: 5,
+        };
+        let magic = reader.read_field::<ddl_rt::U32Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "version",
+            file: "tests/input/struct/pass_doc_hide.ddl",
+            line: 13,
+            column: 5,
+        };
+        let version = reader.read_field::<Version>(&FIELD_1_LOCATION)?;
+
+        Ok(Header {
+            magic,
+            version,
+        })
+    }
+}
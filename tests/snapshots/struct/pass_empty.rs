@@ -3,6 +3,8 @@
 
 //! Test an empty struct.
 
+#![allow(clippy::all)]
+
 #[derive(Copy, Clone)]
 pub struct Empty {}
 
@@ -3,6 +3,8 @@
 
 //! Test an empty struct with documentation.
 
+#![allow(clippy::all)]
+
 /// This is an empty struct.
 ///
 /// It will not consume any input.
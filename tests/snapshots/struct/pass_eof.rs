@@ -0,0 +1,52 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test a struct that must be followed by the end of the data.
+
+#![allow(clippy::all)]
+
+/// A pair of bytes, with nothing after them.
+#[derive(Copy, Clone)]
+pub struct Exact {
+    first: u8,
+    second: u8,
+}
+
+impl Exact {
+    pub fn first(&self) -> u8 {
+        self.first
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl ddl_rt::Format for Exact {
+    type Host = Exact;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Exact {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Exact, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "first",
+            file: "tests/input/struct/pass_eof.ddl",
+            line: 5,
+            column: 5,
+        };
+        let first = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "second",
+            file: "tests/input/struct/pass_eof.ddl",
+            line: 6,
+            column: 5,
+        };
+        let second = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+        reader.check_eof()?;
+
+        Ok(Exact {
+            first,
+            second,
+        })
+    }
+}
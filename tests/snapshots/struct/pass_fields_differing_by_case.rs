@@ -0,0 +1,50 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields whose labels only differ by case.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Flags {
+    flag: u8,
+    flag_upper: u8,
+}
+
+impl Flags {
+    pub fn flag(&self) -> u8 {
+        self.flag
+    }
+
+    pub fn flag_upper(&self) -> u8 {
+        self.flag_upper
+    }
+}
+
+impl ddl_rt::Format for Flags {
+    type Host = Flags;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Flags {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Flags, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "flag",
+            file: "tests/input/struct/pass_fields_differing_by_case.ddl",
+            line: 4,
+            column: 5,
+        };
+        let flag = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "Flag",
+            file: "tests/input/struct/pass_fields_differing_by_case.ddl",
+            line: 5,
+            column: 23,
+        };
+        let flag_upper = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+
+        Ok(Flags {
+            flag,
+            flag_upper,
+        })
+    }
+}
@@ -0,0 +1,50 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test half-precision float formats.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Vertex {
+    x: f32,
+    y: f32,
+}
+
+impl Vertex {
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+}
+
+impl ddl_rt::Format for Vertex {
+    type Host = Vertex;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Vertex {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Vertex, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "x",
+            file: "tests/input/struct/pass_half_floats.ddl",
+            line: 4,
+            column: 5,
+        };
+        let x = reader.read_field::<ddl_rt::F16Le>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "y",
+            file: "tests/input/struct/pass_half_floats.ddl",
+            line: 5,
+            column: 5,
+        };
+        let y = reader.read_field::<ddl_rt::F16Be>(&FIELD_1_LOCATION)?;
+
+        Ok(Vertex {
+            x,
+            y,
+        })
+    }
+}
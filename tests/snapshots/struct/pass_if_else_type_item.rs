@@ -1,6 +1,8 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
 
+#![allow(clippy::all)]
+
 pub const IS_BE: bool = true;
 
 #[derive(Copy, Clone)]
@@ -20,7 +22,13 @@ impl ddl_rt::Format for Test {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Test {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Test, ddl_rt::ReadError> {
-        let bar = if IS_BE { ddl_rt::Either::Left(reader.read::<ddl_rt::F32Be>()?) } else { ddl_rt::Either::Right(reader.read::<ddl_rt::F32Le>()?) };
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "bar",
+            file: "tests/input/struct/pass_if_else_type_item.ddl",
+            line: 5,
+            column: 5,
+        };
+        let bar = if IS_BE { ddl_rt::Either::Left(reader.read_field::<ddl_rt::F32Be>(&FIELD_0_LOCATION)?) } else { ddl_rt::Either::Right(reader.read_field::<ddl_rt::F32Le>(&FIELD_0_LOCATION)?) };
 
         Ok(Test {
             bar,
@@ -1,6 +1,8 @@
 // This file is automatically @generated by ddl 0.1.0
 // It is not intended for manual editing.
 
+#![allow(clippy::all)]
+
 pub const IS_BE: bool = true;
 
 #[derive(Copy, Clone)]
@@ -45,7 +47,13 @@ impl ddl_rt::Format for Test {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Test {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Test, ddl_rt::ReadError> {
-        let bar = reader.read::<Bar>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "bar",
+            file: "tests/input/struct/pass_if_else_type_item_item.ddl",
+            line: 8,
+            column: 5,
+        };
+        let bar = reader.read_field::<Bar>(&FIELD_0_LOCATION)?;
 
         Ok(Test {
             bar,
@@ -0,0 +1,173 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test structs that include the fields of other structs.
+
+#![allow(clippy::all)]
+
+/// A common chunk header.
+#[derive(Copy, Clone)]
+pub struct Header {
+    tag: u16,
+    len: u16,
+}
+
+impl Header {
+    /// The type of the chunk.
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+
+    /// The length of the chunk.
+    pub fn len(&self) -> u16 {
+        self.len
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 6,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "len",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 8,
+            column: 5,
+        };
+        let len = reader.read_field::<ddl_rt::U16Be>(&FIELD_1_LOCATION)?;
+
+        Ok(Header {
+            tag,
+            len,
+        })
+    }
+}
+
+/// A chunk containing a single point.
+#[derive(Copy, Clone)]
+pub struct PointChunk {
+    tag: u16,
+    len: u16,
+    x: u8,
+    y: u8,
+}
+
+impl PointChunk {
+    /// The type of the chunk.
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+
+    /// The length of the chunk.
+    pub fn len(&self) -> u16 {
+        self.len
+    }
+
+    pub fn x(&self) -> u8 {
+        self.x
+    }
+
+    pub fn y(&self) -> u8 {
+        self.y
+    }
+}
+
+impl ddl_rt::Format for PointChunk {
+    type Host = PointChunk;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for PointChunk {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<PointChunk, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 6,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "len",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 8,
+            column: 5,
+        };
+        let len = reader.read_field::<ddl_rt::U16Be>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "x",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 14,
+            column: 5,
+        };
+        let x = reader.read_field::<ddl_rt::U8>(&FIELD_2_LOCATION)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "y",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 15,
+            column: 5,
+        };
+        let y = reader.read_field::<ddl_rt::U8>(&FIELD_3_LOCATION)?;
+
+        Ok(PointChunk {
+            tag,
+            len,
+            x,
+            y,
+        })
+    }
+}
+
+/// A chunk containing only a header.
+#[derive(Copy, Clone)]
+pub struct EmptyChunk {
+    tag: u16,
+    len: u16,
+}
+
+impl EmptyChunk {
+    /// The type of the chunk.
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+
+    /// The length of the chunk.
+    pub fn len(&self) -> u16 {
+        self.len
+    }
+}
+
+impl ddl_rt::Format for EmptyChunk {
+    type Host = EmptyChunk;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for EmptyChunk {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<EmptyChunk, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 6,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "len",
+            file: "tests/input/struct/pass_include.ddl",
+            line: 8,
+            column: 5,
+        };
+        let len = reader.read_field::<ddl_rt::U16Be>(&FIELD_1_LOCATION)?;
+
+        Ok(EmptyChunk {
+            tag,
+            len,
+        })
+    }
+}
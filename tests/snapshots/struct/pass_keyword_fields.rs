@@ -0,0 +1,64 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields that are named after Rust keywords.
+
+#![allow(clippy::all)]
+
+/// A struct with keyword field names.
+#[derive(Copy, Clone)]
+pub struct Keywords {
+    r#type: u8,
+    r#match: u8,
+    self_: u8,
+}
+
+impl Keywords {
+    pub fn r#type(&self) -> u8 {
+        self.r#type
+    }
+
+    pub fn r#match(&self) -> u8 {
+        self.r#match
+    }
+
+    pub fn self_(&self) -> u8 {
+        self.self_
+    }
+}
+
+impl ddl_rt::Format for Keywords {
+    type Host = Keywords;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Keywords {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Keywords, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "type",
+            file: "tests/input/struct/pass_keyword_fields.ddl",
+            line: 5,
+            column: 5,
+        };
+        let r#type = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "match",
+            file: "tests/input/struct/pass_keyword_fields.ddl",
+            line: 6,
+            column: 5,
+        };
+        let r#match = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "self",
+            file: "tests/input/struct/pass_keyword_fields.ddl",
+            line: 7,
+            column: 5,
+        };
+        let self_ = reader.read_field::<ddl_rt::U8>(&FIELD_2_LOCATION)?;
+
+        Ok(Keywords {
+            r#type,
+            r#match,
+            self_,
+        })
+    }
+}
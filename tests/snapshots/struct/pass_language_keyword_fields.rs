@@ -0,0 +1,139 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields that are named after keywords of the data description language.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Keywords {
+    r#enum: u8,
+    eof: u8,
+    include: u8,
+}
+
+impl Keywords {
+    pub fn r#enum(&self) -> u8 {
+        self.r#enum
+    }
+
+    pub fn eof(&self) -> u8 {
+        self.eof
+    }
+
+    pub fn include(&self) -> u8 {
+        self.include
+    }
+}
+
+impl ddl_rt::Format for Keywords {
+    type Host = Keywords;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Keywords {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Keywords, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "enum",
+            file: "tests/input/struct/pass_language_keyword_fields.ddl",
+            line: 4,
+            column: 5,
+        };
+        let r#enum = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "eof",
+            file: "tests/input/struct/pass_language_keyword_fields.ddl",
+            line: 5,
+            column: 5,
+        };
+        let eof = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "include",
+            file: "tests/input/struct/pass_language_keyword_fields.ddl",
+            line: 6,
+            column: 5,
+        };
+        let include = reader.read_field::<ddl_rt::U8>(&FIELD_2_LOCATION)?;
+        reader.check_eof()?;
+
+        Ok(Keywords {
+            r#enum,
+            eof,
+            include,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Inner {
+    value: u8,
+}
+
+impl Inner {
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl ddl_rt::Format for Inner {
+    type Host = Inner;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Inner {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Inner, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/pass_language_keyword_fields.ddl",
+            line: 11,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+
+        Ok(Inner {
+            value,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Outer {
+    value: u8,
+    include: u8,
+}
+
+impl Outer {
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    pub fn include(&self) -> u8 {
+        self.include
+    }
+}
+
+impl ddl_rt::Format for Outer {
+    type Host = Outer;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Outer {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Outer, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/pass_language_keyword_fields.ddl",
+            line: 11,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "include",
+            file: "tests/input/struct/pass_language_keyword_fields.ddl",
+            line: 16,
+            column: 5,
+        };
+        let include = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+
+        Ok(Outer {
+            value,
+            include,
+        })
+    }
+}
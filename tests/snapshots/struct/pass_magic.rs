@@ -0,0 +1,76 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields that must contain a magic number.
+
+#![allow(clippy::all)]
+
+/// The header of a bitmap image.
+#[derive(Copy, Clone)]
+pub struct BitmapHeader {
+    signature: u16,
+    size: u32,
+    offset: u32,
+}
+
+impl BitmapHeader {
+    /// The signature of the file, `BM`.
+    pub fn signature(&self) -> u16 {
+        self.signature
+    }
+
+    /// The size of the file in bytes.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// The offset of the pixel data.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+}
+
+impl ddl_rt::Format for BitmapHeader {
+    type Host = BitmapHeader;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for BitmapHeader {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<BitmapHeader, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "signature",
+            file: "tests/input/struct/pass_magic.ddl",
+            line: 6,
+            column: 20,
+        };
+        let signature = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        reader.check_magic("signature", 16973u16, signature)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "size",
+            file: "tests/input/struct/pass_magic.ddl",
+            line: 8,
+            column: 5,
+        };
+        let size = reader.read_field::<ddl_rt::U32Le>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "reserved",
+            file: "tests/input/struct/pass_magic.ddl",
+            line: 9,
+            column: 25,
+        };
+        let reserved = reader.read_field::<ddl_rt::U32Le>(&FIELD_2_LOCATION)?;
+        reader.check_magic("reserved", 0u32, reserved)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "offset",
+            file: "tests/input/struct/pass_magic.ddl",
+            line: 11,
+            column: 5,
+        };
+        let offset = reader.read_field::<ddl_rt::U32Le>(&FIELD_3_LOCATION)?;
+
+        Ok(BitmapHeader {
+            signature,
+            size,
+            offset,
+        })
+    }
+}
@@ -3,6 +3,8 @@
 
 //! Test that a struct with a host type field produces a warning.
 
+#![allow(clippy::all)]
+
 #[derive(Copy, Clone)]
 pub struct Test {
     format: u32,
@@ -25,8 +27,20 @@ impl ddl_rt::Format for Test {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Test {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Test, ddl_rt::ReadError> {
-        let format = reader.read::<ddl_rt::U32Be>()?;
-        let host = reader.read::<ddl_rt::InvalidDataDescription>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "format",
+            file: "tests/input/struct/pass_mixed_format_host.ddl",
+            line: 4,
+            column: 5,
+        };
+        let format = reader.read_field::<ddl_rt::U32Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "host",
+            file: "tests/input/struct/pass_mixed_format_host.ddl",
+            line: 5,
+            column: 5,
+        };
+        let host = reader.read_field::<ddl_rt::InvalidDataDescription>(&FIELD_1_LOCATION)?;
 
         Ok(Test {
             format,
@@ -3,6 +3,8 @@
 
 //! Test a pair struct.
 
+#![allow(clippy::all)]
+
 /// A pair of bytes.
 #[derive(Copy, Clone)]
 pub struct Pair {
@@ -28,8 +30,20 @@ impl ddl_rt::Format for Pair {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Pair {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Pair, ddl_rt::ReadError> {
-        let first = reader.read::<ddl_rt::U8>()?;
-        let second = reader.read::<ddl_rt::I8>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "first",
+            file: "tests/input/struct/pass_pair.ddl",
+            line: 6,
+            column: 5,
+        };
+        let first = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "second",
+            file: "tests/input/struct/pass_pair.ddl",
+            line: 8,
+            column: 5,
+        };
+        let second = reader.read_field::<ddl_rt::I8>(&FIELD_1_LOCATION)?;
 
         Ok(Pair {
             first,
@@ -0,0 +1,55 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test generating readers that are limited to a maximum number of bytes.
+
+#![allow(clippy::all)]
+
+/// A header with a length and a version.
+#[derive(Copy, Clone)]
+pub struct Header {
+    length: u32,
+    version: u16,
+}
+
+impl Header {
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    pub fn read_bounded(reader: &mut ddl_rt::FormatReader<'_>, max_bytes: usize) -> Result<Header, ddl_rt::ReadError> {
+        reader.read_bounded::<Header>(max_bytes)
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "length",
+            file: "tests/input/struct/pass_read_bounded.ddl",
+            line: 7,
+            column: 5,
+        };
+        let length = reader.read_field::<ddl_rt::U32Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "version",
+            file: "tests/input/struct/pass_read_bounded.ddl",
+            line: 8,
+            column: 5,
+        };
+        let version = reader.read_field::<ddl_rt::U16Be>(&FIELD_1_LOCATION)?;
+
+        Ok(Header {
+            length,
+            version,
+        })
+    }
+}
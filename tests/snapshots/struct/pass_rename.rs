@@ -0,0 +1,53 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test structs and fields with overridden Rust names.
+
+#![allow(clippy::all)]
+
+/// A chunk header, named after the tag used in the data.
+#[derive(Copy, Clone)]
+pub struct ChunkHeader {
+    width: u32,
+    height: u32,
+}
+
+impl ChunkHeader {
+    /// The width of the image.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the image.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+impl ddl_rt::Format for ChunkHeader {
+    type Host = ChunkHeader;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for ChunkHeader {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<ChunkHeader, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "w",
+            file: "tests/input/struct/pass_rename.ddl",
+            line: 7,
+            column: 18,
+        };
+        let width = reader.read_field::<ddl_rt::U32Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "h",
+            file: "tests/input/struct/pass_rename.ddl",
+            line: 9,
+            column: 19,
+        };
+        let height = reader.read_field::<ddl_rt::U32Be>(&FIELD_1_LOCATION)?;
+
+        Ok(ChunkHeader {
+            width,
+            height,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test fields that are read but not exposed.
+
+#![allow(clippy::all)]
+
+/// A header with space set aside for future use.
+#[derive(Copy, Clone)]
+pub struct Header {
+    tag: u8,
+    length: u16,
+}
+
+impl Header {
+    /// The kind of record.
+    pub fn tag(&self) -> u8 {
+        self.tag
+    }
+
+    /// The length of the record.
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_reserved.ddl",
+            line: 6,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "padding",
+            file: "tests/input/struct/pass_reserved.ddl",
+            line: 7,
+            column: 15,
+        };
+        reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "length",
+            file: "tests/input/struct/pass_reserved.ddl",
+            line: 9,
+            column: 5,
+        };
+        let length = reader.read_field::<ddl_rt::U16Be>(&FIELD_2_LOCATION)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "unused",
+            file: "tests/input/struct/pass_reserved.ddl",
+            line: 10,
+            column: 15,
+        };
+        reader.read_field::<ddl_rt::U32Be>(&FIELD_3_LOCATION)?;
+
+        Ok(Header {
+            tag,
+            length,
+        })
+    }
+}
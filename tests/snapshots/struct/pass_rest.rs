@@ -0,0 +1,70 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test a struct that captures the bytes remaining after its fields.
+
+#![allow(clippy::all)]
+
+/// A tagged chunk of data.
+pub struct Chunk {
+    tag: u16,
+    payload: Vec<u8>,
+}
+
+impl Chunk {
+    /// The type of the chunk.
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
+impl ddl_rt::Format for Chunk {
+    type Host = Chunk;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Chunk {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Chunk, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "tag",
+            file: "tests/input/struct/pass_rest.ddl",
+            line: 6,
+            column: 5,
+        };
+        let tag = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        let payload = reader.read_remaining().to_vec();
+
+        Ok(Chunk {
+            tag,
+            payload,
+        })
+    }
+}
+
+/// A struct made up of only the remaining bytes.
+pub struct Opaque {
+    bytes: Vec<u8>,
+}
+
+impl Opaque {
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl ddl_rt::Format for Opaque {
+    type Host = Opaque;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Opaque {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Opaque, ddl_rt::ReadError> {
+        let bytes = reader.read_remaining().to_vec();
+
+        Ok(Opaque {
+            bytes,
+        })
+    }
+}
@@ -3,6 +3,8 @@
 
 //! Test a singleton struct.
 
+#![allow(clippy::all)]
+
 #[derive(Copy, Clone)]
 pub struct Byte {
     inner: u8,
@@ -20,7 +22,13 @@ impl ddl_rt::Format for Byte {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Byte {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Byte, ddl_rt::ReadError> {
-        let inner = reader.read::<ddl_rt::U8>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "inner",
+            file: "tests/input/struct/pass_singleton.ddl",
+            line: 4,
+            column: 5,
+        };
+        let inner = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
 
         Ok(Byte {
             inner,
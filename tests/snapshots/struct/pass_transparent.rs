@@ -0,0 +1,81 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test structs that are represented as newtypes.
+
+#![allow(clippy::all)]
+
+/// A typed identifier.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct ObjectId(pub u32);
+
+impl std::ops::Deref for ObjectId {
+    type Target = u32;
+
+    fn deref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+impl ddl_rt::Format for ObjectId {
+    type Host = ObjectId;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for ObjectId {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<ObjectId, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "value",
+            file: "tests/input/struct/pass_transparent.ddl",
+            line: 7,
+            column: 5,
+        };
+        let value = reader.read_field::<ddl_rt::U32Be>(&FIELD_0_LOCATION)?;
+
+        Ok(ObjectId(value))
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Header {
+    id: ObjectId,
+    parent: ObjectId,
+}
+
+impl Header {
+    pub fn id(&self) -> ObjectId {
+        self.id
+    }
+
+    pub fn parent(&self) -> ObjectId {
+        self.parent
+    }
+}
+
+impl ddl_rt::Format for Header {
+    type Host = Header;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Header {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Header, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "id",
+            file: "tests/input/struct/pass_transparent.ddl",
+            line: 11,
+            column: 5,
+        };
+        let id = reader.read_field::<ObjectId>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "parent",
+            file: "tests/input/struct/pass_transparent.ddl",
+            line: 12,
+            column: 5,
+        };
+        let parent = reader.read_field::<ObjectId>(&FIELD_1_LOCATION)?;
+
+        Ok(Header {
+            id,
+            parent,
+        })
+    }
+}
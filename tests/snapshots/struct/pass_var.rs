@@ -3,6 +3,8 @@
 
 //! Test referring to aliases in struct fields.
 
+#![allow(clippy::all)]
+
 #[derive(Copy, Clone)]
 pub struct Pair {
     first: u8,
@@ -25,8 +27,20 @@ impl ddl_rt::Format for Pair {
 
 impl<'data> ddl_rt::ReadFormat<'data> for Pair {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Pair, ddl_rt::ReadError> {
-        let first = reader.read::<ddl_rt::U8>()?;
-        let second = reader.read::<ddl_rt::U8>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "first",
+            file: "tests/input/struct/pass_var.ddl",
+            line: 4,
+            column: 5,
+        };
+        let first = reader.read_field::<ddl_rt::U8>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "second",
+            file: "tests/input/struct/pass_var.ddl",
+            line: 5,
+            column: 5,
+        };
+        let second = reader.read_field::<ddl_rt::U8>(&FIELD_1_LOCATION)?;
 
         Ok(Pair {
             first,
@@ -59,8 +73,20 @@ impl ddl_rt::Format for PairPair {
 
 impl<'data> ddl_rt::ReadFormat<'data> for PairPair {
     fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<PairPair, ddl_rt::ReadError> {
-        let first = reader.read::<Pair>()?;
-        let second = reader.read::<MyPair>()?;
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "first",
+            file: "tests/input/struct/pass_var.ddl",
+            line: 11,
+            column: 5,
+        };
+        let first = reader.read_field::<Pair>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "second",
+            file: "tests/input/struct/pass_var.ddl",
+            line: 12,
+            column: 5,
+        };
+        let second = reader.read_field::<MyPair>(&FIELD_1_LOCATION)?;
 
         Ok(PairPair {
             first,
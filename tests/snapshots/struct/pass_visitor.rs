@@ -0,0 +1,176 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test generating visitors for nested structures.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+impl Point {
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    pub fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_point(self);
+    }
+
+    pub fn walk<V: Visit + ?Sized>(&self, _visitor: &mut V) {}
+}
+
+impl ddl_rt::Format for Point {
+    type Host = Point;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Point {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Point, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "x",
+            file: "tests/input/struct/pass_visitor.ddl",
+            line: 6,
+            column: 5,
+        };
+        let x = reader.read_field::<ddl_rt::F32Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "y",
+            file: "tests/input/struct/pass_visitor.ddl",
+            line: 7,
+            column: 5,
+        };
+        let y = reader.read_field::<ddl_rt::F32Be>(&FIELD_1_LOCATION)?;
+
+        Ok(Point {
+            x,
+            y,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    pub fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_line(self);
+    }
+
+    pub fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        self.start.accept(visitor);
+        self.end.accept(visitor);
+    }
+}
+
+impl ddl_rt::Format for Line {
+    type Host = Line;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Line {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Line, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "start",
+            file: "tests/input/struct/pass_visitor.ddl",
+            line: 11,
+            column: 5,
+        };
+        let start = reader.read_field::<Point>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "end",
+            file: "tests/input/struct/pass_visitor.ddl",
+            line: 12,
+            column: 5,
+        };
+        let end = reader.read_field::<Point>(&FIELD_1_LOCATION)?;
+
+        Ok(Line {
+            start,
+            end,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Shape {
+    id: u16,
+    outline: Line,
+}
+
+impl Shape {
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    pub fn outline(&self) -> Line {
+        self.outline
+    }
+
+    pub fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_shape(self);
+    }
+
+    pub fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        self.outline.accept(visitor);
+    }
+}
+
+impl ddl_rt::Format for Shape {
+    type Host = Shape;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for Shape {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<Shape, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "id",
+            file: "tests/input/struct/pass_visitor.ddl",
+            line: 16,
+            column: 5,
+        };
+        let id = reader.read_field::<ddl_rt::U16Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "outline",
+            file: "tests/input/struct/pass_visitor.ddl",
+            line: 17,
+            column: 5,
+        };
+        let outline = reader.read_field::<Line>(&FIELD_1_LOCATION)?;
+
+        Ok(Shape {
+            id,
+            outline,
+        })
+    }
+}
+
+/// Visitors over the structures in this module.
+pub trait Visit {
+    fn visit_point(&mut self, value: &Point) {
+        value.walk(self);
+    }
+
+    fn visit_line(&mut self, value: &Line) {
+        value.walk(self);
+    }
+
+    fn visit_shape(&mut self, value: &Shape) {
+        value.walk(self);
+    }
+}
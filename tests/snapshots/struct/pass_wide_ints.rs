@@ -0,0 +1,76 @@
+// This file is automatically @generated by ddl 0.1.0
+// It is not intended for manual editing.
+
+//! Test 128-bit integer formats.
+
+#![allow(clippy::all)]
+
+#[derive(Copy, Clone)]
+pub struct WideInts {
+    uuid: u128,
+    checksum: u128,
+    offset: i128,
+    delta: i128,
+}
+
+impl WideInts {
+    pub fn uuid(&self) -> u128 {
+        self.uuid
+    }
+
+    pub fn checksum(&self) -> u128 {
+        self.checksum
+    }
+
+    pub fn offset(&self) -> i128 {
+        self.offset
+    }
+
+    pub fn delta(&self) -> i128 {
+        self.delta
+    }
+}
+
+impl ddl_rt::Format for WideInts {
+    type Host = WideInts;
+}
+
+impl<'data> ddl_rt::ReadFormat<'data> for WideInts {
+    fn read(reader: &mut ddl_rt::FormatReader<'data>) -> Result<WideInts, ddl_rt::ReadError> {
+        const FIELD_0_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "uuid",
+            file: "tests/input/struct/pass_wide_ints.ddl",
+            line: 4,
+            column: 5,
+        };
+        let uuid = reader.read_field::<ddl_rt::U128Be>(&FIELD_0_LOCATION)?;
+        const FIELD_1_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "checksum",
+            file: "tests/input/struct/pass_wide_ints.ddl",
+            line: 5,
+            column: 5,
+        };
+        let checksum = reader.read_field::<ddl_rt::U128Le>(&FIELD_1_LOCATION)?;
+        const FIELD_2_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "offset",
+            file: "tests/input/struct/pass_wide_ints.ddl",
+            line: 6,
+            column: 5,
+        };
+        let offset = reader.read_field::<ddl_rt::I128Be>(&FIELD_2_LOCATION)?;
+        const FIELD_3_LOCATION: ddl_rt::FieldLocation = ddl_rt::FieldLocation {
+            name: "delta",
+            file: "tests/input/struct/pass_wide_ints.ddl",
+            line: 7,
+            column: 5,
+        };
+        let delta = reader.read_field::<ddl_rt::I128Le>(&FIELD_3_LOCATION)?;
+
+        Ok(WideInts {
+            uuid,
+            checksum,
+            offset,
+            delta,
+        })
+    }
+}